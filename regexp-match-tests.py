@@ -20,14 +20,24 @@ def print_tests(tests):
     print('\n'.join([test_tostr(t) for t in tests]))
 
 
-def read_tests(f):
+def read_tests(f, posix=False):
     basename, _ = path.splitext(path.basename(f))
     tests = []
+    last = None  # the pattern that `SAME` refers to
+    pending = None  # a commented out test, waiting for its RE2/Go result
     for lineno, line in enumerate(open(f), 1):
         fields = filter(None, map(str.strip, line.split('\t')))
-        if not (4 <= len(fields) <= 5) \
-           or 'E' not in fields[0] or fields[0][0] == '#':
+        # Commented out tests are skipped. Most of them are POSIX results
+        # that depend on how POSIX picks submatches (e.g., `(a*)*` matching
+        # `-` sets group 1 to (0,0)), which isn't supported in either mode
+        # (leftmost-first or leftmost-longest), so they're followed by the
+        # results for RE2/Go's submatch rules, which are used instead.
+        # With `posix`, only those POSIX results are read. Their overall
+        # match is still the leftmost-longest one, so it's checked along
+        # with the submatches that agree with the RE2/Go result.
+        if not (4 <= len(fields) <= 5) or 'E' not in fields[0]:
             continue
+        commented = fields[0][0] == '#'
 
         opts, pat, text, sgroups = fields[0:4]
         groups = []  # groups as integer ranges
@@ -48,23 +58,40 @@ def read_tests(f):
             continue
 
         if pat == 'SAME':
-            pat = tests[-1][1]
+            pat = last
         if '$' in opts:
             pat = pat.decode('string_escape')
             text = text.decode('string_escape')
         if 'i' in opts:
             pat = '(?i)%s' % pat
+        last = pat
 
         name = '%s_%d' % (basename, lineno)
-        tests.append((name, pat, text, groups))
+        if not posix:
+            if not commented:
+                tests.append((name, pat, text, groups))
+        elif commented:
+            pending = (name, pat, text, groups)
+        else:
+            if pending is not None and pending[1:3] == (pat, text) \
+               and 'RE2/Go' in fields[4:]:
+                checked = [i for i, g in enumerate(pending[3])
+                           if i < len(groups) and groups[i] == g]
+                tests.append(pending + (checked,))
+            pending = None
     return tests
 
 
 def test_tostr(t):
-    lineno, pat, text, groups = t
+    lineno, pat, text, groups = t[0:4]
     options = map(group_tostr, groups)
+    text = '' if text == "NULL" else text
+    if len(t) > 4:
+        checked = ', '.join(map(str, t[4]))
+        return 'posix!(match_%s, r"%s", r"%s", &[%s], %s)' \
+               % (lineno, pat, text, checked, ', '.join(options))
     return 'mat!(match_%s, r"%s", r"%s", %s)' \
-           % (lineno, pat, '' if text == "NULL" else text, ', '.join(options))
+           % (lineno, pat, text, ', '.join(options))
 
 
 def group_tostr(g):
@@ -80,6 +107,9 @@ if __name__ == '__main__':
     aa = parser.add_argument
     aa('files', nargs='+',
        help='A list of dat AT&T POSIX test files. See src/libregexp/testdata')
+    aa('--posix', action='store_true',
+       help='Generate the commented out POSIX results for leftmost-longest '
+            'matching instead.')
     args = parser.parse_args()
 
     tests = []
@@ -105,5 +135,5 @@ if __name__ == '__main__':
 
     for f in args.files:
         print('// Tests from %s' % path.basename(f))
-        print_tests(read_tests(f))
+        print_tests(read_tests(f, args.posix))
         print('')
@@ -20,6 +20,8 @@ import urllib2
 BASE_URL = 'http://www.unicode.org/Public/6.3.0/ucd/'
 DATA = 'UnicodeData.txt'
 SCRIPTS = 'Scripts.txt'
+GRAPHEME = 'auxiliary/GraphemeBreakProperty.txt'
+WORD = 'auxiliary/WordBreakProperty.txt'
 
 # Mapping taken from Table 12 from:
 # http://www.unicode.org/reports/tr44/#General_Category_Values
@@ -60,7 +62,7 @@ def read_cats(f):
     return assigned
 
 
-def read_scripts(f):
+def read_props(f):
     assigned = defaultdict(list)
     for line in f:
         line = line.strip()
@@ -98,6 +100,20 @@ def group(letters):
     return grouped
 
 
+def props_to_rust(prefix, props):
+    # Converts a property mapping (e.g., from GraphemeBreakProperty.txt) to
+    # a single table of ranges sorted by start so that the property value of
+    # a character can be found with one binary search.
+    rs = []
+    for name, letters in props.items():
+        for s, e in group(letters):
+            rs.append((s, e, prefix + name.replace('_', '')))
+    rs.sort()
+    rs = ("('%s', '%s', %s)" % (as_4byte_uni(s), as_4byte_uni(e), v)
+          for s, e, v in rs)
+    return ',\n    '.join(rs)
+
+
 def ranges_to_rust(rs):
     rs = ("('%s', '%s')" % (as_4byte_uni(s), as_4byte_uni(e)) for s, e in rs)
     return ',\n    '.join(rs)
@@ -116,18 +132,23 @@ if __name__ == '__main__':
         description='Generate Unicode character class tables.')
     aa = parser.add_argument
     aa('--local', action='store_true',
-       help='When set, Scripts.txt and UnicodeData.txt will be read from '
-            'the CWD.')
+       help='When set, Scripts.txt, UnicodeData.txt, '
+            'auxiliary/GraphemeBreakProperty.txt and '
+            'auxiliary/WordBreakProperty.txt will be read from the CWD.')
     aa('--base-url', type=str, default=BASE_URL,
        help='The base URL to use for downloading Unicode data files.')
     args = parser.parse_args()
 
     if args.local:
         cats = read_cats(open(DATA))
-        scripts = read_scripts(open(SCRIPTS))
+        scripts = read_props(open(SCRIPTS))
+        graphemes = read_props(open(GRAPHEME))
+        words = read_props(open(WORD))
     else:
         cats = read_cats(urllib2.urlopen(args.base_url + '/' + DATA))
-        scripts = read_scripts(urllib2.urlopen(args.base_url + '/' + SCRIPTS))
+        scripts = read_props(urllib2.urlopen(args.base_url + '/' + SCRIPTS))
+        graphemes = read_props(urllib2.urlopen(args.base_url + '/' + GRAPHEME))
+        words = read_props(urllib2.urlopen(args.base_url + '/' + WORD))
 
     # Get Rust code for all Unicode general categories and scripts.
     combined = dict(cats, **scripts)
@@ -145,6 +166,12 @@ if __name__ == '__main__':
     perlw = [ord('_')] + perld + low + up
     wgroups = ranges_to_rust(group(perlw + cats['L'][:]))
 
+    # Grapheme cluster break property values (used by \X).
+    ggroups = props_to_rust('GC_', graphemes)
+
+    # Word break property values (used by \b{w}).
+    wbgroups = props_to_rust('WC_', words)
+
     tpl = '''// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
 // file at the top-level directory of this distribution and at
 // http://rust-lang.org/COPYRIGHT.
@@ -177,7 +204,90 @@ pub static PERLS: Class = &[
 pub static PERLW: Class = &[
     {wgroups}
 ];
+
+/// The values of the Grapheme_Cluster_Break property from UAX#29.
+#[allow(non_camel_case_types)]
+#[deriving(Show, Eq)]
+pub enum GraphemeCat {{
+    GC_CR,
+    GC_LF,
+    GC_Control,
+    GC_Extend,
+    GC_RegionalIndicator,
+    GC_Prepend,
+    GC_SpacingMark,
+    GC_L,
+    GC_V,
+    GC_T,
+    GC_LV,
+    GC_LVT,
+    GC_Any,
+}}
+
+/// Returns the Grapheme_Cluster_Break property value of `c`.
+pub fn grapheme_category(c: char) -> GraphemeCat {{
+    match GRAPHEME_CAT_TABLE.bsearch(|&(s, e, _)| range_cmp(c, s, e)) {{
+        None => GC_Any,
+        Some(i) => {{
+            let (_, _, cat) = GRAPHEME_CAT_TABLE[i];
+            cat
+        }}
+    }}
+}}
+
+fn range_cmp(c: char, start: char, end: char) -> Ordering {{
+    if c >= start && c <= end {{
+        Equal
+    }} else if start > c {{
+        Greater
+    }} else {{
+        Less
+    }}
+}}
+
+static GRAPHEME_CAT_TABLE: &'static [(char, char, GraphemeCat)] = &[
+    {ggroups}
+];
+
+/// The values of the Word_Break property from UAX#29.
+#[allow(non_camel_case_types)]
+#[deriving(Show, Eq)]
+pub enum WordCat {{
+    WC_CR,
+    WC_LF,
+    WC_Newline,
+    WC_Extend,
+    WC_RegionalIndicator,
+    WC_Format,
+    WC_Katakana,
+    WC_HebrewLetter,
+    WC_ALetter,
+    WC_SingleQuote,
+    WC_DoubleQuote,
+    WC_MidNumLet,
+    WC_MidLetter,
+    WC_MidNum,
+    WC_Numeric,
+    WC_ExtendNumLet,
+    WC_Any,
+}}
+
+/// Returns the Word_Break property value of `c`.
+pub fn word_category(c: char) -> WordCat {{
+    match WORD_CAT_TABLE.bsearch(|&(s, e, _)| range_cmp(c, s, e)) {{
+        None => WC_Any,
+        Some(i) => {{
+            let (_, _, cat) = WORD_CAT_TABLE[i];
+            cat
+        }}
+    }}
+}}
+
+static WORD_CAT_TABLE: &'static [(char, char, WordCat)] = &[
+    {wbgroups}
+];
 '''
     now = datetime.datetime.now()
     print(tpl.format(date=str(now), groups=unigroups,
-                     dgroups=dgroups, sgroups=sgroups, wgroups=wgroups))
+                     dgroups=dgroups, sgroups=sgroups, wgroups=wgroups,
+                     ggroups=ggroups, wbgroups=wbgroups))
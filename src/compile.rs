@@ -14,17 +14,22 @@
 
 use std::cmp;
 use std::iter;
+use std::str;
+use collections::HashMap;
+use sync::Arc;
+use literals::Literals;
 use parse;
 use parse::{
-    Flags, FLAG_EMPTY,
-    Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Capture, Cat, Alt,
-    Rep,
+    Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+    Nothing, Dot, Class, Begin, End, WordBoundary, Grapheme, Capture,
+    Cat, Alt, Rep,
     ZeroOne, ZeroMore, OneMore,
 };
+use search::Searcher;
 
 type InstIdx = uint;
 
-#[deriving(Show, Clone)]
+#[deriving(Show, Clone, Eq)]
 pub enum Inst {
     // When a Match instruction is executed, the current thread is successful.
     Match,
@@ -33,12 +38,30 @@ pub enum Inst {
     // The flags indicate whether to do a case insensitive match.
     OneChar(char, Flags),
 
+    // The Literal instruction matches a string of at least two characters,
+    // one character per step. Each thread at this instruction keeps the
+    // byte offset of the next character it has to match, so the whole
+    // string takes up a single instruction (see `fuse_literals`).
+    // The flags indicate whether to do a case insensitive match.
+    Literal(~str, Flags),
+
     // The CharClass instruction tries to match one input character against
     // the range of characters given.
     // The flags indicate whether to do a case insentivie match and whether
     // the character class is negated or not.
     CharClass(Vec<(char, char)>, Flags),
 
+    // The Branch instruction matches one input character against a list of
+    // characters sorted in ascending order, and jumps to the instruction
+    // paired with the character found.
+    // If the flags are FLAG_NOCASE, the characters are upper case and the
+    // input character is converted to upper case before it's looked up.
+    // It's used for alternations of expressions that start with literals
+    // (like a list of words), since only the ones starting with the input
+    // character can continue after it. This keeps the number of threads
+    // and instructions from growing with the number of alternatives.
+    Branch(Vec<(char, InstIdx)>, Flags),
+
     // Matches any character except new lines.
     // The flags indicate whether to include the '\n' character.
     Any(Flags),
@@ -59,6 +82,12 @@ pub enum Inst {
     // that isn't a word boundary.
     EmptyWordBoundary(Flags),
 
+    // Matches a boundary between two extended grapheme clusters as defined
+    // by UAX#29, and consumes no character.
+    // The flags indicate whether this matches a grapheme cluster boundary or
+    // something that isn't a grapheme cluster boundary.
+    EmptyGraphemeBoundary(Flags),
+
     // Saves the current position in the input string to the Nth save slot.
     Save(uint),
 
@@ -81,44 +110,88 @@ pub enum Inst {
 pub struct Program {
     /// A sequence of instructions.
     pub insts: Vec<Inst>,
-    /// If the regular expression requires a literal prefix in order to have a
-    /// match, that prefix is stored here. (It's used in the VM to implement
-    /// an optimization.)
-    pub prefix: ~str,
+    /// Every match starts with one of these strings. (They're used by the VM
+    /// to skip ahead to positions where a match could start.)
+    /// If this is empty, then nothing is known about how a match starts.
+    pub prefixes: Vec<~str>,
+    /// A searcher for the prefix, if there is exactly one.
+    pub prefix_searcher: Option<Searcher>,
+    /// Every match ends with one of these strings, if there are any.
+    pub suffixes: Vec<~str>,
+    /// A searcher for the suffix, if there is exactly one.
+    pub suffix_searcher: Option<Searcher>,
+    /// Every match contains this string, if it isn't empty.
+    pub required: ~str,
+    /// A searcher for the required string, if it isn't empty.
+    pub required_searcher: Option<Searcher>,
+    /// The program for the reversed expression, which reads the input from
+    /// right to left. It's only compiled with the program if the VM needs it
+    /// (see `Program::new`). (Reversed programs don't have one of their own,
+    /// and they don't have any literals.)
+    pub reversed: Option<Arc<Program>>,
+    /// When true, the VM finds the leftmost-longest match (as in POSIX)
+    /// instead of the leftmost-first one.
+    pub longest: bool,
 }
 
 impl Program {
     /// Compiles a Regexp given its AST.
+    ///
+    /// If every match must end at the end of the input (i.e., the expression
+    /// ends with a `$` that isn't multi-line), then its reversed program is
+    /// compiled too, since the VM finds where those matches start by running
+    /// it backwards from the end of the input. Otherwise, it's left to
+    /// `new_rev` to compile the reversed program when it's needed.
+    ///
+    /// The names of the capture groups are returned along with the program.
+    /// There is exactly one for each capture group (including the zeroth
+    /// one, which is never named).
     pub fn new(ast: ~parse::Ast) -> (Program, ~[Option<~str>]) {
-        let mut c = Compiler {
-            insts: Vec::with_capacity(100),
-            names: Vec::with_capacity(10),
-        };
-
-        c.insts.push(Save(0));
-        c.compile(ast);
-        c.insts.push(Save(1));
-        c.insts.push(Match);
-
-        // Try to discover a literal string prefix.
-        // This is a bit hacky since we have to skip over the initial
-        // 'Save' instruction.
-        let mut pre = StrBuf::with_capacity(5);
-        for i in iter::range(1, c.insts.len()) {
-            match *c.insts.get(i) {
-                OneChar(c, FLAG_EMPTY) => pre.push_char(c),
-                _ => break
-            }
-        }
+        // Find the literals before the AST is consumed by the compiler.
+        let lits = Literals::new(&*ast);
+        let reversed =
+            if suffix_anchored(&*ast) {
+                Some(Arc::new(Program::new_rev(ast.clone())))
+            } else {
+                None
+            };
 
-        let names = c.names.as_slice().into_owned();
+        let (insts, names) = Compiler::run(ast, false);
         let prog = Program {
-            insts: c.insts,
-            prefix: pre.into_owned(),
+            insts: insts,
+            prefix_searcher: searcher(lits.prefixes.as_slice()),
+            prefixes: lits.prefixes,
+            suffix_searcher: searcher(lits.suffixes.as_slice()),
+            suffixes: lits.suffixes,
+            required_searcher: searcher([lits.required.as_slice()]),
+            required: lits.required,
+            reversed: reversed,
+            longest: false,
         };
         (prog, names)
     }
 
+    /// Compiles the reversed program of a Regexp given its AST. It matches
+    /// the same strings as the Regexp, but it reads them from right to left.
+    /// Its capture groups are the same, too.
+    ///
+    /// Assertions (like `^` or `\b`) still refer to positions in the input,
+    /// so they are left as they are.
+    pub fn new_rev(ast: ~parse::Ast) -> Program {
+        let (insts, _) = Compiler::run(ast, true);
+        Program {
+            insts: insts,
+            prefixes: vec!(),
+            prefix_searcher: None,
+            suffixes: vec!(),
+            suffix_searcher: None,
+            required: ~"",
+            required_searcher: None,
+            reversed: None,
+            longest: false,
+        }
+    }
+
     /// Returns the total number of capture groups in the regular expression.
     /// This includes the zeroth capture.
     pub fn num_captures(&self) -> uint {
@@ -132,57 +205,150 @@ impl Program {
         // There's exactly 2 Save slots for every capture.
         n / 2
     }
+
+    /// Returns the number of threads that can be waiting to run this program
+    /// at once: one for each instruction, plus one for each character of a
+    /// `Literal` instruction after the first.
+    pub fn num_threads(&self) -> uint {
+        self.insts.iter().fold(0, |n, inst| {
+            match *inst {
+                Literal(ref lit, _) => n + lit.char_len(),
+                _ => n + 1,
+            }
+        })
+    }
+}
+
+// Returns true if every match of `ast` must end at the end of the input,
+// i.e., if it ends with a `$` that isn't multi-line. Since it's the last
+// thing matched, nothing in the program can jump past it.
+fn suffix_anchored(ast: &parse::Ast) -> bool {
+    let last = match ast.expr {
+        Cat(ref xs) => match xs.last() {
+            None => return false,
+            Some(x) => &**x,
+        },
+        _ => ast,
+    };
+    match last.expr {
+        End(flags) => flags & FLAG_MULTI == 0,
+        _ => false,
+    }
+}
+
+// Builds a searcher for a set of literals if it has exactly one (non-empty)
+// string.
+fn searcher<S: Str>(lits: &[S]) -> Option<Searcher> {
+    if lits.len() != 1 || lits[0].as_slice().len() == 0 {
+        return None
+    }
+    Some(Searcher::new(lits[0].as_slice().as_bytes()))
 }
 
 struct Compiler<'r> {
     insts: Vec<Inst>,
     names: Vec<Option<~str>>,
+    reverse: bool,
 }
 
 // The compiler implemented here is extremely simple. Most of the complexity
 // in this crate is in the parser or the VM.
 // The only tricky thing here is patching jump/split instructions to point to
 // the right instruction.
+//
+// When compiling a reversed program, the only differences are that
+// concatenations are compiled back to front and that the end of a capture
+// group is saved before its start.
 impl<'r> Compiler<'r> {
+    // Compiles a whole program, returning its optimized instructions and the
+    // names of its capture groups (one for each group).
+    fn run(ast: ~parse::Ast, reverse: bool) -> (Vec<Inst>, ~[Option<~str>]) {
+        let mut c = Compiler {
+            insts: Vec::with_capacity(100),
+            names: vec!(None),
+            reverse: reverse,
+        };
+        let (first, last) = c.save_slots(0);
+        c.push(Save(first));
+        c.compile(ast);
+        c.push(Save(last));
+        c.push(Match);
+        let names = c.names.as_slice().into_owned();
+        (optimize(c.insts), names)
+    }
+
     fn compile(&mut self, ast: ~parse::Ast) {
-        match ast {
-            ~Nothing => {},
-            ~Literal(c, flags) => self.push(OneChar(c, flags)),
-            ~Dot(nl) => self.push(Any(nl)),
-            ~Class(ranges, flags) =>
+        match ast.expr {
+            Nothing => {},
+            parse::Literal(c, flags) => self.push(OneChar(c, flags)),
+            Dot(nl) => self.push(Any(nl)),
+            Class(ranges, flags) =>
                 self.push(CharClass(ranges, flags)),
-            ~Begin(flags) => self.push(EmptyBegin(flags)),
-            ~End(flags) => self.push(EmptyEnd(flags)),
-            ~WordBoundary(flags) => self.push(EmptyWordBoundary(flags)),
-            ~Capture(cap, name, x) => {
+            Begin(flags) => self.push(EmptyBegin(flags)),
+            End(flags) => self.push(EmptyEnd(flags)),
+            WordBoundary(flags) => self.push(EmptyWordBoundary(flags)),
+            Grapheme => {
+                // Every grapheme cluster has at least one character. After
+                // that, keep consuming characters until a grapheme cluster
+                // boundary is reached. Since the boundary assertions are
+                // exact opposites, only one thread survives each step.
+                self.push(Any(FLAG_DOTNL));
+                let j1 = self.insts.len();
+                let split = self.empty_split();
+                let j2 = self.insts.len();
+                self.push(EmptyGraphemeBoundary(FLAG_NEGATED));
+                self.push(Any(FLAG_DOTNL));
+                let jmp = self.empty_jump();
+                let j3 = self.insts.len();
+                self.push(EmptyGraphemeBoundary(FLAG_EMPTY));
+
+                self.set_split(split, j2, j3);
+                self.set_jump(jmp, j1);
+            }
+            Capture(cap, name, x) => {
                 let len = self.names.len();
                 if cap >= len {
-                    self.names.grow(10 + cap - len, &None)
+                    self.names.grow(cap + 1 - len, &None)
                 }
                 *self.names.get_mut(cap) = name;
 
-                self.push(Save(2 * cap));
+                let (first, last) = self.save_slots(cap);
+                self.push(Save(first));
                 self.compile(x);
-                self.push(Save(2 * cap + 1));
+                self.push(Save(last));
             }
-            ~Cat(xs) => {
-                for x in xs.move_iter() {
-                    self.compile(x)
+            Cat(xs) => {
+                if self.reverse {
+                    for x in xs.move_iter().rev() {
+                        self.compile(x)
+                    }
+                } else {
+                    for x in xs.move_iter() {
+                        self.compile(x)
+                    }
                 }
             }
-            ~Alt(x, y) => {
-                let split = self.empty_split(); // push: split 0, 0
-                let j1 = self.insts.len();
-                self.compile(x);                // push: insts for x
-                let jmp = self.empty_jump();    // push: jmp 0
-                let j2 = self.insts.len();
-                self.compile(y);                // push: insts for y
-                let j3 = self.insts.len();
-
-                self.set_split(split, j1, j2);  // split 0, 0 -> split j1, j2
-                self.set_jump(jmp, j3);         // jmp 0      -> jmp j3
+            Alt(x, y) => {
+                // Alternations nest to the right, and long ones (e.g., from
+                // a list of words) would blow the stack if they were
+                // compiled recursively.
+                let mut alts = vec!(x);
+                let mut rest = y;
+                loop {
+                    match rest {
+                        ~parse::Ast { expr: Alt(x, y), .. } => {
+                            alts.push(x);
+                            rest = y;
+                        }
+                        ast => {
+                            alts.push(ast);
+                            break
+                        }
+                    }
+                }
+                self.compile_alts(alts)
             }
-            ~Rep(x, ZeroOne, g) => {
+            Rep(x, ZeroOne, g) => {
                 let split = self.empty_split();
                 let j1 = self.insts.len();
                 self.compile(x);
@@ -194,7 +360,7 @@ impl<'r> Compiler<'r> {
                     self.set_split(split, j2, j1);
                 }
             }
-            ~Rep(x, ZeroMore, g) => {
+            Rep(x, ZeroMore, g) => {
                 let j1 = self.insts.len();
                 let split = self.empty_split();
                 let j2 = self.insts.len();
@@ -209,7 +375,7 @@ impl<'r> Compiler<'r> {
                     self.set_split(split, j3, j2);
                 }
             }
-            ~Rep(x, OneMore, g) => {
+            Rep(x, OneMore, g) => {
                 let j1 = self.insts.len();
                 self.compile(x);
                 let split = self.empty_split();
@@ -224,6 +390,150 @@ impl<'r> Compiler<'r> {
         }
     }
 
+    /// Compiles an alternation given as a list of its alternatives.
+    /// Runs of alternatives that start with a literal (the first character
+    /// read, which is the last one in a reversed program) are compiled to a
+    /// single `Branch` instruction, and the alternatives that share a
+    /// literal continue as an alternation of what follows it. Every other
+    /// alternative gets a `Split` instruction.
+    fn compile_alts(&mut self, alts: Vec<~parse::Ast>) {
+        let groups = self.branch_groups(alts);
+        let last = groups.len() - 1;
+        let mut jumps = Vec::with_capacity(groups.len());
+        for (i, group) in groups.move_iter().enumerate() {
+            if i == last {
+                self.compile_group(group, &mut jumps)
+            } else {
+                let split = self.empty_split(); // push: split 0, 0
+                let j1 = self.insts.len();
+                self.compile_group(group, &mut jumps);
+                let jmp = self.empty_jump();    // push: jmp 0
+                jumps.push(jmp);
+                let j2 = self.insts.len();
+                self.set_split(split, j1, j2);  // split 0, 0 -> split j1, j2
+            }
+        }
+
+        // Every alternative continues after the alternation.
+        let end = self.insts.len();
+        for &jmp in jumps.iter() {
+            self.set_jump(jmp, end);
+        }
+    }
+
+    /// Compiles one group of an alternation. The jumps to the end of the
+    /// alternation that it needs are appended to `jumps`.
+    fn compile_group(&mut self, group: AltGroup, jumps: &mut Vec<InstIdx>) {
+        let (flags, keys) = match group {
+            Single(alt) => return self.compile(alt),
+            Branched(flags, keys) => (flags, keys),
+        };
+        let n = keys.len();
+        let pc = self.insts.len();
+        self.push(Branch(vec!(), flags));
+        let mut targets = Vec::with_capacity(n);
+        for (i, (c, alts)) in keys.move_iter().enumerate() {
+            targets.push((c, self.insts.len()));
+            let mut rests: Vec<~parse::Ast> =
+                alts.move_iter().map(|alt| self.split_key(alt)).collect();
+            if rests.len() == 1 {
+                self.compile(rests.pop().unwrap())
+            } else {
+                self.compile_alts(rests)
+            }
+            if i + 1 < n {
+                let jmp = self.empty_jump();
+                jumps.push(jmp);
+            }
+        }
+        targets.sort_by(|&(c1, _), &(c2, _)| c1.cmp(&c2));
+        *self.insts.get_mut(pc) = Branch(targets, flags);
+    }
+
+    /// Splits an alternation into groups that are tried in order.
+    /// A group is either a single alternative or a run of alternatives that
+    /// start with literals of the same case sensitivity, grouped by literal.
+    /// Two alternatives starting with different literals can never match at
+    /// the same position, so only the order of the alternatives that share
+    /// a literal has to be kept.
+    fn branch_groups(&self, alts: Vec<~parse::Ast>) -> Vec<AltGroup> {
+        let mut groups = Vec::with_capacity(alts.len());
+        let mut run = Run::new(FLAG_EMPTY);
+        for alt in alts.move_iter() {
+            match self.branch_key(&*alt) {
+                Some((c, flags)) => {
+                    if flags != run.flags {
+                        run.flush(&mut groups);
+                        run = Run::new(flags);
+                    }
+                    run.push(c, alt)
+                }
+                None => {
+                    run.flush(&mut groups);
+                    run = Run::new(FLAG_EMPTY);
+                    groups.push(Single(alt))
+                }
+            }
+        }
+        run.flush(&mut groups);
+        groups
+    }
+
+    /// Returns the literal that an alternative is branched on, if it starts
+    /// with one. Case insensitive literals are branched on their upper case
+    /// form, like the VM compares them.
+    fn branch_key(&self, ast: &parse::Ast) -> Option<(char, Flags)> {
+        let first = match ast.expr {
+            Cat(ref xs) => {
+                let x = if self.reverse {
+                    xs.as_slice().last()
+                } else {
+                    xs.as_slice().get(0)
+                };
+                match x {
+                    None => return None,
+                    Some(x) => &**x,
+                }
+            }
+            _ => ast,
+        };
+        match first.expr {
+            parse::Literal(c, flags) if flags & FLAG_NOCASE > 0 =>
+                Some((c.to_uppercase(), FLAG_NOCASE)),
+            parse::Literal(c, _) => Some((c, FLAG_EMPTY)),
+            _ => None,
+        }
+    }
+
+    /// Removes the literal returned by `branch_key` from an alternative.
+    fn split_key(&self, ast: ~parse::Ast) -> ~parse::Ast {
+        let ~parse::Ast { expr, span } = ast;
+        match expr {
+            parse::Literal(_, _) =>
+                ~parse::Ast { expr: Nothing, span: span },
+            Cat(mut xs) => {
+                if self.reverse {
+                    xs.pop();
+                } else {
+                    xs.shift();
+                }
+                ~parse::Ast { expr: Cat(xs), span: span }
+            }
+            expr => fail!("BUG: Expected a literal in {}", expr),
+        }
+    }
+
+    /// Returns the `Save` slots for the capture group `cap`, in the order
+    /// that they are reached.
+    #[inline(always)]
+    fn save_slots(&self, cap: uint) -> (uint, uint) {
+        if self.reverse {
+            (2 * cap + 1, 2 * cap)
+        } else {
+            (2 * cap, 2 * cap + 1)
+        }
+    }
+
     /// Appends the given instruction to the program.
     #[inline(always)]
     fn push(&mut self, x: Inst) {
@@ -272,3 +582,237 @@ impl<'r> Compiler<'r> {
         }
     }
 }
+
+// The peephole optimizer rewrites a compiled program into an equivalent one
+// that is smaller or cheaper to run. It threads chains of jumps, removes the
+// instructions that can no longer be reached and then fuses runs of single
+// characters into `Literal` instructions.
+fn optimize(mut insts: Vec<Inst>) -> Vec<Inst> {
+    thread_jumps(&mut insts);
+    fuse_literals(remove_unreachable(insts))
+}
+
+// Makes every instruction that jumps to a `Jump` instruction jump to where
+// the chain of jumps ends instead.
+fn thread_jumps(insts: &mut Vec<Inst>) {
+    for pc in iter::range(0, insts.len()) {
+        let inst = match *insts.get(pc) {
+            Jump(to) => Jump(jump_target(insts.as_slice(), to)),
+            Split(x, y) => Split(jump_target(insts.as_slice(), x),
+                                 jump_target(insts.as_slice(), y)),
+            Branch(ref targets, flags) => {
+                let targets = targets.iter().map(|&(c, to)| {
+                    (c, jump_target(insts.as_slice(), to))
+                }).collect();
+                Branch(targets, flags)
+            }
+            _ => continue,
+        };
+        *insts.get_mut(pc) = inst;
+    }
+}
+
+// Returns the first instruction that isn't a `Jump` in the chain of jumps
+// starting at `pc`. (A chain that loops ends where it started looping.)
+fn jump_target(insts: &[Inst], mut pc: InstIdx) -> InstIdx {
+    let mut seen = 0;
+    loop {
+        match insts[pc] {
+            Jump(to) if seen < insts.len() => { pc = to; seen += 1 }
+            _ => return pc,
+        }
+    }
+}
+
+// Removes the instructions that can't be reached from the start of the
+// program, and renumbers the rest.
+fn remove_unreachable(insts: Vec<Inst>) -> Vec<Inst> {
+    let mut reachable = Vec::from_elem(insts.len(), false);
+    let mut stack = vec!(0u);
+    loop {
+        let pc = match stack.pop() {
+            None => break,
+            Some(pc) => pc,
+        };
+        if *reachable.get(pc) {
+            continue
+        }
+        *reachable.get_mut(pc) = true;
+        match *insts.get(pc) {
+            Match => {}
+            Jump(to) => stack.push(to),
+            Split(x, y) => {
+                stack.push(y);
+                stack.push(x);
+            }
+            Branch(ref targets, _) => {
+                for &(_, to) in targets.iter() {
+                    stack.push(to)
+                }
+            }
+            _ => stack.push(pc + 1),
+        }
+    }
+
+    let mut renumbered = Vec::with_capacity(insts.len());
+    let mut next = 0u;
+    for &yes in reachable.iter() {
+        renumbered.push(next);
+        if yes {
+            next += 1
+        }
+    }
+
+    let mut optimized = Vec::with_capacity(next);
+    for (pc, inst) in insts.move_iter().enumerate() {
+        if !*reachable.get(pc) {
+            continue
+        }
+        optimized.push(match inst {
+            Jump(to) => Jump(*renumbered.get(to)),
+            Split(x, y) => Split(*renumbered.get(x), *renumbered.get(y)),
+            Branch(targets, flags) => {
+                let targets = targets.move_iter().map(|(c, to)| {
+                    (c, *renumbered.get(to))
+                }).collect();
+                Branch(targets, flags)
+            }
+            inst => inst,
+        });
+    }
+    optimized
+}
+
+// Replaces each run of `OneChar` instructions with the same flags by a
+// `Literal` instruction, and renumbers the rest. A run is cut short where
+// something jumps into the middle of it.
+fn fuse_literals(insts: Vec<Inst>) -> Vec<Inst> {
+    let mut targeted = Vec::from_elem(insts.len(), false);
+    for inst in insts.iter() {
+        match *inst {
+            Jump(to) => *targeted.get_mut(to) = true,
+            Split(x, y) => {
+                *targeted.get_mut(x) = true;
+                *targeted.get_mut(y) = true;
+            }
+            Branch(ref targets, _) => {
+                for &(_, to) in targets.iter() {
+                    *targeted.get_mut(to) = true
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // An instruction is fused with the one before it if both are `OneChar`
+    // instructions with the same flags, so it's only reached from there.
+    let mut fused = Vec::from_elem(insts.len(), false);
+    for pc in iter::range(1, insts.len()) {
+        *fused.get_mut(pc) = !*targeted.get(pc) &&
+            match (insts.get(pc - 1), insts.get(pc)) {
+                (&OneChar(_, f1), &OneChar(_, f2)) => f1 == f2,
+                _ => false,
+            };
+    }
+
+    let mut renumbered = Vec::with_capacity(insts.len());
+    let mut next = 0u;
+    for &yes in fused.iter() {
+        if !yes {
+            next += 1
+        }
+        renumbered.push(next - 1);
+    }
+
+    let mut optimized: Vec<Inst> = Vec::with_capacity(next);
+    let mut chars = vec!();
+    for (pc, inst) in insts.move_iter().enumerate() {
+        let inst = match inst {
+            Jump(to) => Jump(*renumbered.get(to)),
+            Split(x, y) => Split(*renumbered.get(x), *renumbered.get(y)),
+            Branch(targets, flags) => {
+                let targets = targets.move_iter().map(|(c, to)| {
+                    (c, *renumbered.get(to))
+                }).collect();
+                Branch(targets, flags)
+            }
+            inst => inst,
+        };
+        match inst {
+            OneChar(c, _) if *fused.get(pc) => chars.push(c),
+            inst => {
+                fuse_chars(&mut optimized, &mut chars);
+                optimized.push(inst)
+            }
+        }
+    }
+    fuse_chars(&mut optimized, &mut chars);
+    optimized
+}
+
+// Fuses the `OneChar` instruction at the end of `insts` with the characters
+// in `chars` that follow it.
+fn fuse_chars(insts: &mut Vec<Inst>, chars: &mut Vec<char>) {
+    if chars.len() == 0 {
+        return
+    }
+    match insts.pop() {
+        Some(OneChar(c, flags)) => {
+            chars.unshift(c);
+            insts.push(Literal(str::from_chars(chars.as_slice()), flags));
+        }
+        _ => fail!("BUG: Expected a OneChar instruction before {}",
+                  chars.as_slice()),
+    }
+    chars.clear();
+}
+
+// A group of alternatives compiled by `compile_alts`.
+enum AltGroup {
+    Single(~parse::Ast),
+    // Alternatives grouped by the literal they start with, which is upper
+    // case if the flags are `FLAG_NOCASE`.
+    Branched(Flags, Vec<(char, Vec<~parse::Ast>)>),
+}
+
+// A run of alternatives being grouped by `branch_groups`.
+struct Run {
+    flags: Flags,
+    keys: Vec<(char, Vec<~parse::Ast>)>,
+    index: HashMap<char, uint>,
+    len: uint,
+}
+
+impl Run {
+    fn new(flags: Flags) -> Run {
+        Run { flags: flags, keys: vec!(), index: HashMap::new(), len: 0 }
+    }
+
+    fn push(&mut self, c: char, alt: ~parse::Ast) {
+        let i = match self.index.find_copy(&c) {
+            Some(i) => i,
+            None => {
+                let i = self.keys.len();
+                self.keys.push((c, vec!()));
+                self.index.insert(c, i);
+                i
+            }
+        };
+        match *self.keys.get_mut(i) {
+            (_, ref mut alts) => alts.push(alt),
+        }
+        self.len += 1;
+    }
+
+    // A single alternative gains nothing from a `Branch` instruction.
+    fn flush(self, groups: &mut Vec<AltGroup>) {
+        let Run { flags, keys, len, .. } = self;
+        if len == 1 {
+            for (_, mut alts) in keys.move_iter() {
+                groups.push(Single(alts.pop().unwrap()))
+            }
+        } else if len > 1 {
+            groups.push(Branched(flags, keys))
+        }
+    }
+}
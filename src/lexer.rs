@@ -0,0 +1,179 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+use compile::Program;
+use parse;
+use parse::{Ast, Span, Nothing, Capture, Cat, Alt, Rep};
+use simplify::simplify;
+use vm;
+use vm::{Submatches, AnchorStart};
+
+/// What a `Lexer` found at some span of the text it splits into tokens.
+#[deriving(Show, Clone, Eq)]
+pub enum Lexeme<T> {
+    /// A token matched by the pattern with the given id.
+    Token(T),
+    /// A character where none of the patterns match (or where they only
+    /// match the empty string). The lexer carries on after it.
+    NoToken,
+}
+
+/// Lexer splits text into tokens, given a pattern for each kind of token.
+///
+/// At each position, the token is the longest text matched by any of the
+/// patterns (i.e., "maximal munch"). If more than one pattern matches the
+/// longest text, then the one given first wins. The patterns are compiled
+/// into a single program, so each token is found with one search no matter
+/// how many patterns there are.
+///
+/// # Example
+///
+/// ```rust
+/// # use regexp::{Lexer, Lexeme, Token, NoToken};
+/// let lexer = Lexer::new([("if", "if"), ("ident", "[a-z]+"),
+///                         ("space", " +")]).unwrap();
+/// let tokens: Vec<(Lexeme<&str>, (uint, uint))> =
+///     lexer.tokens("if iffy!").collect();
+/// assert_eq!(tokens, vec!((Token("if"), (0, 2)), (Token("space"), (2, 3)),
+///                         (Token("ident"), (3, 7)), (NoToken, (7, 8))));
+/// ```
+pub struct Lexer<T> {
+    ids: Vec<T>,
+    // The capture group that each pattern is wrapped in (in `prog`).
+    groups: Vec<uint>,
+    prog: Program,
+}
+
+impl<T: Clone> Lexer<T> {
+    /// Compiles a lexer from a list of token ids and their patterns.
+    ///
+    /// If one of the patterns is invalid, then an error is returned. Its
+    /// position refers to the pattern that it's in.
+    pub fn new(tokens: &[(T, &str)]) -> Result<Lexer<T>, parse::Error> {
+        let mut ids = Vec::with_capacity(tokens.len());
+        let mut groups = Vec::with_capacity(tokens.len());
+        let mut alts = Vec::with_capacity(tokens.len());
+        let mut ncaps = 0;
+        for &(ref id, pattern) in tokens.iter() {
+            // Each pattern is parsed on its own, so that errors refer to it.
+            // Its capture groups are numbered after the ones before it, and
+            // their names are dropped since they only need to be unique
+            // within one pattern.
+            let mut ast = try!(parse::parse(pattern));
+            let n = renumber_captures(&mut *ast, ncaps + 1);
+
+            ids.push(id.clone());
+            groups.push(ncaps + 1);
+            let span = ast.span;
+            let group = Capture(ncaps + 1, None, ast);
+            alts.push(~Ast { expr: group, span: span });
+            ncaps += 1 + n;
+        }
+        // Alternations nest to the right. (The spans of the alternations
+        // don't mean anything, but they're never looked at.)
+        let mut ast = match alts.pop() {
+            Some(ast) => ast,
+            None => ~Ast { expr: Nothing, span: Span { start: 0, end: 0 } },
+        };
+        for x in alts.move_iter().rev() {
+            let span = Span { start: x.span.start, end: ast.span.end };
+            ast = ~Ast { expr: Alt(x, ast), span: span };
+        }
+        let (mut prog, _) = Program::new(simplify(ast));
+        prog.longest = true;
+        Ok(Lexer { ids: ids, groups: groups, prog: prog })
+    }
+
+    /// Returns an iterator over the tokens in `text`, along with their start
+    /// and end byte indices.
+    pub fn tokens<'l, 't>(&'l self, text: &'t str) -> Tokens<'l, 't, T> {
+        Tokens {
+            lexer: self,
+            scratch: vm::Scratch::for_kind(&self.prog, Submatches),
+            text: text,
+            pos: 0,
+        }
+    }
+}
+
+/// An iterator over the tokens found by a `Lexer`, with their start and end
+/// byte indices.
+///
+/// `'l` is the lifetime of the lexer and `'t` is the lifetime of the text
+/// being split into tokens.
+pub struct Tokens<'l, 't, T> {
+    lexer: &'l Lexer<T>,
+    scratch: vm::Scratch,
+    text: &'t str,
+    pos: uint,
+}
+
+impl<'l, 't, T: Clone> Iterator<(Lexeme<T>, (uint, uint))>
+        for Tokens<'l, 't, T> {
+    fn next(&mut self) -> Option<(Lexeme<T>, (uint, uint))> {
+        let start = self.pos;
+        if start >= self.text.len() {
+            return None
+        }
+        let locs = vm::run_with(Submatches, AnchorStart, &self.lexer.prog,
+                                &mut self.scratch, self.text,
+                                start, self.text.len());
+        match *locs.get(1) {
+            Some(end) if end > start => {
+                self.pos = end;
+                // The group of the pattern that matched is the only one set.
+                let groups = self.lexer.groups.as_slice();
+                let i = groups.iter().position(|&g| locs.get(2 * g).is_some());
+                let id = self.lexer.ids.get(i.unwrap()).clone();
+                Some((Token(id), (start, end)))
+            }
+            _ => {
+                let end = self.text.char_range_at(start).next;
+                self.pos = end;
+                Some((NoToken, (start, end)))
+            }
+        }
+    }
+}
+
+// Adds `offset` to the index of every capture group in `ast` and drops their
+// names. Returns the number of capture groups in `ast`, which is the largest
+// index among them. (Counted repetitions copy groups, so there may be more
+// `Capture` expressions than that.)
+fn renumber_captures(ast: &mut Ast, offset: uint) -> uint {
+    let mut ncaps = 0;
+    let mut stack = vec!(ast);
+    loop {
+        let ast = match stack.pop() {
+            None => return ncaps,
+            Some(ast) => ast,
+        };
+        match ast.expr {
+            Capture(ref mut i, ref mut name, ref mut x) => {
+                ncaps = cmp::max(ncaps, *i);
+                *i += offset;
+                *name = None;
+                stack.push(&mut **x);
+            }
+            Rep(ref mut x, _, _) => stack.push(&mut **x),
+            Cat(ref mut xs) => {
+                for x in xs.mut_iter() {
+                    stack.push(&mut **x)
+                }
+            }
+            Alt(ref mut x, ref mut y) => {
+                stack.push(&mut **x);
+                stack.push(&mut **y);
+            }
+            _ => {}
+        }
+    }
+}
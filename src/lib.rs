@@ -170,7 +170,7 @@
 //! # extern crate regexp; #[phase(syntax)] extern crate regexp_macros;
 //! # fn main() {
 //! let re = regexp!(r"(?i)Δ+");
-//! assert_eq!(re.find("ΔδΔ"), Some((0, 6)));
+//! assert_eq!(re.find("ΔδΔ").unwrap().range(), (0, 6));
 //! # }
 //! ```
 //!
@@ -183,7 +183,38 @@
 //! # extern crate regexp; #[phase(syntax)] extern crate regexp_macros;
 //! # fn main() {
 //! let re = regexp!(r"[\pN\p{Greek}\p{Cherokee}]+");
-//! assert_eq!(re.find("abcΔᎠβⅠᏴγδⅡxyz"), Some((3, 23)));
+//! assert_eq!(re.find("abcΔᎠβⅠᏴγδⅡxyz").unwrap().range(), (3, 23));
+//! # }
+//! ```
+//!
+//! What a user thinks of as a single character may be made up of several
+//! codepoints (e.g., a letter followed by a combining accent). `\X` matches
+//! one such *extended grapheme cluster*, as defined by
+//! [UAX#29](http://www.unicode.org/reports/tr29/):
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regexp; #[phase(syntax)] extern crate regexp_macros;
+//! # fn main() {
+//! let re = regexp!(r"^\X$");
+//! assert!(re.is_match("e\u0301"));
+//! assert!(!re.is_match("ab"));
+//! # }
+//! ```
+//!
+//! Similarly, `\b{w}` uses the word boundary rules from UAX#29 instead of
+//! the simple `\w`/`\W` transition used by `\b`. For example, it does not
+//! split contractions or decimal numbers:
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regexp; #[phase(syntax)] extern crate regexp_macros;
+//! # fn main() {
+//! let re = regexp!(r"\b{w}.+?\b{w}");
+//! let words: Vec<&str> = re.find_iter("can't 3.14")
+//!                          .map(|m| m.as_str())
+//!                          .collect();
+//! assert_eq!(words, vec!("can't", " ", "3.14"));
 //! # }
 //! ```
 //!
@@ -207,6 +238,7 @@
 //! \p{Greek}   Unicode character class (general category or script)
 //! \PN         Negated one letter name Unicode character class
 //! \P{Greek}   negated Unicode character class (general category or script)
+//! \X          extended grapheme cluster (may be more than one codepoint)
 //! </pre>
 //!
 //! Any named character class may appear inside a bracketed `[...]` character
@@ -246,6 +278,8 @@
 //! \z    only the end of text (even with multi-line mode enabled)
 //! \b    a Unicode word boundary (\w on one side and \W, \A, or \z on other)
 //! \B    not a Unicode word boundary
+//! \b{w} a word boundary as defined by UAX#29
+//! \B{w} not a word boundary as defined by UAX#29
 //! </pre>
 //!
 //! ## Grouping and flags
@@ -256,6 +290,7 @@
 //! (?:exp)        non-capturing group
 //! (?flags)       set flags within current group
 //! (?flags:exp)   set flags for exp (non-capturing)
+//! (?#comment)    comment (ignored)
 //! </pre>
 //!
 //! Flags are each a single character. For example, `(?x)` sets the flag `x`
@@ -365,6 +400,7 @@
 #![deny(missing_doc)]
 
 extern crate collections;
+extern crate sync;
 #[cfg(test)]
 extern crate stdtest = "test";
 #[cfg(test)]
@@ -376,16 +412,25 @@ extern crate rand;
 extern crate regexp;
 
 pub use parse::Error;
-pub use re::{Regexp, Captures, SubCaptures, SubCapturesPos};
-pub use re::{FindCaptures, FindMatches};
+pub use re::{Regexp, RegexpBuilder, Matcher, Scanner, CaptureNames};
+pub use re::{Match, Captures, SubCaptures, SubCapturesPos, SubMatches};
+pub use re::{SubCapturesNamed, CaptureLocations};
+pub use re::{FindCaptures, FindMatches, FindMatchesPos, RFindMatches};
 pub use re::{Replacer, NoExpand, RegexpSplits, RegexpSplitsN};
 pub use re::{quote, is_match};
+pub use lexer::{Lexer, Lexeme, Token, NoToken, Tokens};
 
 mod compile;
+mod lexer;
+mod literals;
 mod parse;
 mod re;
+mod search;
+mod simplify;
 mod vm;
 
+pub mod syntax;
+
 #[cfg(test)]
 mod test;
 
@@ -409,17 +454,19 @@ pub mod native {
     // documentation.
     pub use compile::{
         Program,
-        OneChar, CharClass, Any, Save, Jump, Split,
-        Match, EmptyBegin, EmptyEnd, EmptyWordBoundary,
+        OneChar, Literal, CharClass, Any, Branch, Save, Jump, Split,
+        Match, EmptyBegin, EmptyEnd, EmptyWordBoundary, EmptyGraphemeBoundary,
     };
     pub use parse::{
         FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL,
-        FLAG_SWAP_GREED, FLAG_NEGATED,
+        FLAG_SWAP_GREED, FLAG_NEGATED, FLAG_UNICODE_WORD,
     };
-    pub use re::{Dynamic, Native};
+    pub use re::{Dynamic, Native, NamedStatic, reversed_program};
+    pub use search::Searcher;
     pub use vm::{
-        MatchKind, Exists, Location, Submatches,
+        MatchKind, Exists, Shortest, Location, Submatches,
+        Anchor, Unanchored, AnchorStart, AnchorBoth,
         StepState, StepMatchEarlyReturn, StepMatch, StepContinue,
-        CharReader, find_prefix,
+        CharReader, find_any, may_match,
     };
 }
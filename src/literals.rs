@@ -0,0 +1,288 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Literal analysis finds strings that must occur in every match of an
+// expression. The VM uses them as a prefilter: it can skip straight to the
+// places where a match could start, or give up without running at all if the
+// strings don't occur in the input.
+//
+// Everything found here is a necessary condition for a match, so the sets of
+// strings have to cover *every* match. When in doubt (or when a set would
+// grow too big), the analysis gives up and finds nothing.
+
+use std::char;
+use std::iter;
+use std::str;
+use parse::{
+    Ast, Flags, FLAG_NOCASE, FLAG_NEGATED,
+    Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Grapheme, Capture,
+    Cat, Alt, Rep,
+    ZeroOne, ZeroMore, OneMore,
+};
+
+// The most strings a set may have before the analysis gives up on it.
+static MAX_LITERALS: uint = 64;
+
+// The most characters a class may have before it's treated like `.`.
+static MAX_CLASS_CHARS: uint = 16;
+
+/// The strings that must occur in every match of an expression.
+/// Any of these may be empty, which means nothing is known.
+pub struct Literals {
+    /// Every match starts with one of these strings.
+    pub prefixes: Vec<~str>,
+    /// Every match ends with one of these strings.
+    pub suffixes: Vec<~str>,
+    /// Every match contains this string.
+    pub required: ~str,
+}
+
+impl Literals {
+    /// Finds the literals of the expression given.
+    pub fn new(ast: &Ast) -> Literals {
+        Literals {
+            prefixes: finish(lits(ast, false), false),
+            suffixes: finish(lits(ast, true), true),
+            required: str::from_chars(required(ast).as_slice()),
+        }
+    }
+}
+
+// A string that a match (of some expression) starts with.
+// If it's cut, then the match may continue after it, so nothing can be
+// appended to it. Otherwise, it is the whole match.
+// (When looking for suffixes, the characters are kept in reverse.)
+#[deriving(Clone)]
+struct Lit {
+    chars: Vec<char>,
+    cut: bool,
+}
+
+impl Lit {
+    // The empty match.
+    fn empty() -> Lit {
+        Lit { chars: vec!(), cut: false }
+    }
+
+    // Any match at all.
+    fn any() -> Lit {
+        Lit { chars: vec!(), cut: true }
+    }
+
+    fn cut(mut self) -> Lit {
+        self.cut = true;
+        self
+    }
+}
+
+// Returns the set of strings that every match of `ast` starts with (or ends
+// with, if `rev` is true).
+fn lits(ast: &Ast, rev: bool) -> Vec<Lit> {
+    match ast.expr {
+        Nothing | Begin(_) | End(_) | WordBoundary(_) => vec!(Lit::empty()),
+        Literal(c, flags) => match variants(c, flags) {
+            None => vec!(Lit::any()),
+            Some(cs) => cs.move_iter().map(|c| {
+                Lit { chars: vec!(c), cut: false }
+            }).collect(),
+        },
+        Class(ref ranges, flags) => {
+            if flags & (FLAG_NOCASE | FLAG_NEGATED) > 0
+               || class_chars(ranges.as_slice()) > MAX_CLASS_CHARS {
+                return vec!(Lit::any())
+            }
+            let mut set = vec!();
+            for &(s, e) in ranges.iter() {
+                for c in iter::range_inclusive(s as u32, e as u32) {
+                    match char::from_u32(c) {
+                        None => {}
+                        Some(c) => {
+                            set.push(Lit { chars: vec!(c), cut: false })
+                        }
+                    }
+                }
+            }
+            set
+        }
+        Dot(_) | Grapheme => vec!(Lit::any()),
+        Capture(_, _, ref x) => lits(&**x, rev),
+        Cat(ref xs) => {
+            let mut items: Vec<&~Ast> = xs.iter().collect();
+            if rev {
+                items.reverse()
+            }
+            let mut set = vec!(Lit::empty());
+            for x in items.move_iter() {
+                if set.iter().all(|lit| lit.cut) {
+                    break
+                }
+                set = match cross(set, lits(&**x, rev)) {
+                    Ok(set) => set,
+                    Err(set) => return set,
+                };
+            }
+            set
+        }
+        Alt(_, _) => {
+            // Alternations can be very long, so don't recurse on them.
+            let mut set = vec!();
+            let mut alt = ast;
+            loop {
+                let (x, next) = match alt.expr {
+                    Alt(ref x, ref y) => (&**x, Some(&**y)),
+                    _ => (alt, None),
+                };
+                set.push_all_move(lits(x, rev));
+                if set.len() > MAX_LITERALS {
+                    return vec!(Lit::any())
+                }
+                match next {
+                    None => return set,
+                    Some(y) => alt = y,
+                }
+            }
+        }
+        Rep(ref x, rep, _) => {
+            let mut set = lits(&**x, rev);
+            match rep {
+                ZeroOne => set.push(Lit::empty()),
+                ZeroMore => {
+                    set = set.move_iter().map(|lit| lit.cut()).collect();
+                    set.push(Lit::empty());
+                }
+                OneMore => {
+                    set = set.move_iter().map(|lit| lit.cut()).collect();
+                }
+            }
+            set
+        }
+    }
+}
+
+// Appends every string in `next` to every string in `set` that isn't cut.
+// If there would be too many strings, then all of the strings in `set` are
+// cut instead and returned as an error.
+fn cross(set: Vec<Lit>, next: Vec<Lit>) -> Result<Vec<Lit>, Vec<Lit>> {
+    let uncut = set.iter().filter(|lit| !lit.cut).count();
+    if set.len() - uncut + uncut * next.len() > MAX_LITERALS {
+        return Err(set.move_iter().map(|lit| lit.cut()).collect())
+    }
+    let mut crossed = Vec::with_capacity(set.len() * next.len());
+    for lit in set.move_iter() {
+        if lit.cut {
+            crossed.push(lit);
+            continue
+        }
+        for n in next.iter() {
+            let mut chars = lit.chars.clone();
+            chars.push_all(n.chars.as_slice());
+            crossed.push(Lit { chars: chars, cut: n.cut });
+        }
+    }
+    Ok(crossed)
+}
+
+// Turns a set of literals into strings. If any of them is empty, then
+// nothing is known and no strings are returned.
+// Strings that start (or end, if `rev` is true) with another string in the
+// set are redundant and are dropped.
+fn finish(set: Vec<Lit>, rev: bool) -> Vec<~str> {
+    if set.len() == 0 || set.iter().any(|lit| lit.chars.len() == 0) {
+        return vec!()
+    }
+    let mut strs: Vec<Vec<char>> =
+        set.move_iter().map(|lit| lit.chars).collect();
+    strs.sort_by(|s1, s2| s1.as_slice().cmp(&s2.as_slice()));
+    let mut kept: Vec<Vec<char>> = Vec::with_capacity(strs.len());
+    for s in strs.move_iter() {
+        let redundant = match kept.last() {
+            None => false,
+            Some(last) => s.as_slice().starts_with(last.as_slice()),
+        };
+        if !redundant {
+            kept.push(s)
+        }
+    }
+    kept.move_iter().map(|mut s| {
+        if rev {
+            s.reverse()
+        }
+        str::from_chars(s.as_slice())
+    }).collect()
+}
+
+// Returns the longest string that every match of `ast` contains.
+fn required(ast: &Ast) -> Vec<char> {
+    match ast.expr {
+        Literal(c, flags) if flags & FLAG_NOCASE == 0 => vec!(c),
+        Capture(_, _, ref x) | Rep(ref x, OneMore, _) => required(&**x),
+        Cat(ref xs) => {
+            let mut best = vec!();
+            let mut run = vec!();
+            for x in xs.iter() {
+                match exact_char(&**x) {
+                    Some(c) => { run.push(c); continue }
+                    None => {}
+                }
+                longest(&mut best, run);
+                run = vec!();
+                longest(&mut best, required(&**x));
+            }
+            longest(&mut best, run);
+            best
+        }
+        _ => vec!(),
+    }
+}
+
+fn longest(best: &mut Vec<char>, s: Vec<char>) {
+    if s.len() > best.len() {
+        *best = s
+    }
+}
+
+// Returns the character matched by `ast` if it always matches exactly that
+// one character.
+fn exact_char(ast: &Ast) -> Option<char> {
+    match ast.expr {
+        Literal(c, flags) if flags & FLAG_NOCASE == 0 => Some(c),
+        Capture(_, _, ref x) => exact_char(&**x),
+        _ => None,
+    }
+}
+
+// Returns every character that matches the literal `c`, or `None` if they
+// aren't known.
+// The VM compares characters case insensitively by comparing their upper
+// case forms. For ASCII, the only characters outside of ASCII with an upper
+// case form in ASCII are the dotless i and the long s.
+fn variants(c: char, flags: Flags) -> Option<Vec<char>> {
+    if flags & FLAG_NOCASE == 0 {
+        return Some(vec!(c))
+    }
+    if c as u32 >= 128 {
+        return None
+    }
+    let (lower, upper) = (c.to_lowercase(), c.to_uppercase());
+    if lower == upper {
+        return Some(vec!(c))
+    }
+    let mut cs = vec!(lower, upper);
+    match lower {
+        'i' => cs.push('\u0131'),
+        's' => cs.push('\u017f'),
+        _ => {}
+    }
+    Some(cs)
+}
+
+fn class_chars(ranges: &[(char, char)]) -> uint {
+    ranges.iter().fold(0, |n, &(s, e)| n + (e as uint) - (s as uint) + 1)
+}
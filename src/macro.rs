@@ -25,6 +25,8 @@
 extern crate regexp;
 extern crate syntax;
 
+use std::str::CharRange;
+
 use syntax::ast;
 use syntax::codemap;
 use syntax::ext::base::{
@@ -37,10 +39,10 @@ use syntax::print::pprust;
 
 use regexp::Regexp;
 use regexp::native::{
-    OneChar, CharClass, Any, Save, Jump, Split,
-    Match, EmptyBegin, EmptyEnd, EmptyWordBoundary,
-    Program, Dynamic, Native,
-    FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+    OneChar, Literal, CharClass, Any, Branch, Save, Jump, Split,
+    Match, EmptyBegin, EmptyEnd, EmptyWordBoundary, EmptyGraphemeBoundary,
+    Program, Dynamic, Native, Searcher, reversed_program,
+    FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED, FLAG_UNICODE_WORD,
 };
 
 /// For the `regexp!` syntax extension. Do not use.
@@ -87,12 +89,13 @@ fn native(cx: &mut ExtCtxt, sp: codemap::Span, tts: &[ast::TokenTree])
         }
     };
     let prog = match re.p {
-        Dynamic(ref prog) => prog.clone(),
-        Native(_) => unreachable!(),
+        Dynamic(ref prog, _) => prog.clone(),
+        Native(..) => unreachable!(),
     };
 
     let mut gen = NfaGen {
-        cx: &*cx, sp: sp, prog: prog,
+        cx: &*cx, sp: sp,
+        prog: prog, rev_prog: reversed_program(regex.as_slice()),
         names: re.names.clone(), original: re.original.clone(),
     };
     MacExpr::new(gen.code())
@@ -102,16 +105,14 @@ struct NfaGen<'a> {
     cx: &'a ExtCtxt<'a>,
     sp: codemap::Span,
     prog: Program,
+    // The reversed program, for searching backwards (see `Regexp::rfind`).
+    rev_prog: Program,
     names: ~[Option<~str>],
     original: ~str,
 }
 
 impl<'a> NfaGen<'a> {
     fn code(&mut self) -> @ast::Expr {
-        // Most or all of the following things are used in the quasiquoted
-        // expression returned.
-        let num_cap_locs = 2 * self.prog.num_captures();
-        let num_insts = self.prog.insts.len();
         let cap_names = self.vec_expr(self.names,
             |cx, name| match name {
                 &Some(ref name) => {
@@ -121,100 +122,228 @@ impl<'a> NfaGen<'a> {
                 &None => quote_expr!(cx, None),
             }
         );
-        let prefix_anchor =
-            match self.prog.insts.as_slice()[1] {
-                EmptyBegin(flags) if flags & FLAG_MULTI == 0 => true,
-                _ => false,
+        // The named groups are sorted by name now and generated as static
+        // data, so that looking them up doesn't cost anything at run time.
+        let mut named_groups: Vec<(&str, uint)> =
+            self.names.iter().enumerate().filter_map(|(i, name)| {
+                name.as_ref().map(|name| (name.as_slice(), i))
+            }).collect();
+        named_groups.as_mut_slice().sort_by(|&(x, _), &(y, _)| x.cmp(&y));
+        let named =
+            if named_groups.len() == 0 {
+                quote_expr!(self.cx, None)
+            } else {
+                let named_groups = self.vec_expr(named_groups.as_slice(),
+                    |cx, &(name, i)| quote_expr!(cx, ($name, $i)));
+                quote_expr!(self.cx, {
+                    static NAMED: &'static [(&'static str, uint)] =
+                        &$named_groups;
+                    Some(::regexp::native::NamedStatic(NAMED))
+                })
             };
+        let exec = self.exec_expr(&self.prog, false);
+        let exec_rev = self.exec_expr(&self.rev_prog, true);
+        let regex = self.original.as_slice();
+
+        quote_expr!(self.cx, ::regexp::Regexp {
+            original: ~$regex,
+            names: ~$cap_names,
+            named: $named,
+            p: ::regexp::native::Native($exec, $exec_rev),
+        })
+    }
+
+    // Generates the function that runs `prog`. If `rev` is true, then
+    // `prog` is a reversed program and the function reads the input
+    // backwards (like `vm::run_rev`).
+    fn exec_expr(&self, prog: &Program, rev: bool) -> @ast::Expr {
+        // Most or all of the following things are used in the quasiquoted
+        // expression returned.
+        let num_cap_locs = 2 * prog.num_captures();
+        let num_insts = prog.insts.len();
+        let num_threads = prog.num_threads();
         let init_groups = self.vec_from_fn(num_cap_locs,
                                            |cx| quote_expr!(cx, None));
-        let prefix_bytes = self.vec_expr(self.prog.prefix.as_slice().as_bytes(),
-                                         |cx, b| quote_expr!(cx, $b));
-        let check_prefix = self.check_prefix();
-        let step_insts = self.step_insts();
-        let add_insts = self.add_insts();
-        let regex = self.original.as_slice();
+        let step_insts = self.step_insts(prog);
+        let add_insts = self.add_insts(prog);
+        let chars =
+            if rev {
+                quote_expr!(self.cx, CharReader::new_rev(input))
+            } else {
+                quote_expr!(self.cx, CharReader::new(input))
+            };
+        let step_threads = quote_expr!(self.cx, {
+            let mut i = 0;
+            while i < clist.size {
+                let (pc, off) = (clist.pc(i), clist.off(i));
+                let step_state = self.step(&mut groups, nlist,
+                                           clist.groups(i), pc, off);
+                match step_state {
+                    StepMatchEarlyReturn => {
+                        match self.which {
+                            Shortest => {
+                                locs[0] = groups[0];
+                                locs[1] = groups[1];
+                            }
+                            _ => {
+                                locs[0] = Some(0u);
+                                locs[1] = Some(0u);
+                            }
+                        }
+                        return
+                    }
+                    StepMatch => { matched = true; clist.empty() },
+                    StepContinue => {},
+                }
+                i += 1;
+            }
+        });
+        let search = if rev {
+            quote_expr!(self.cx, {
+                let mut clist = &mut Threads::new(self.which);
+                let mut nlist = &mut Threads::new(self.which);
+
+                let mut groups = $init_groups;
+
+                self.ic = end;
+                let mut next_ic = self.chars.set(end);
+                loop {
+                    if clist.size == 0 && matched {
+                        break
+                    }
+                    if !matched {
+                        self.add(clist, 0, &mut groups)
+                    }
+
+                    let done = self.ic == start;
+                    self.ic = next_ic;
+                    next_ic = self.chars.advance();
+
+                    $step_threads
+                    ::std::mem::swap(&mut clist, &mut nlist);
+                    nlist.empty();
+                    if done {
+                        break
+                    }
+                }
+                groups
+            })
+        } else {
+            let prefix_anchor =
+                match prog.insts.as_slice()[1] {
+                    EmptyBegin(flags) if flags & FLAG_MULTI == 0 => true,
+                    _ => false,
+                };
+            let prefixes = self.vec_expr(prog.prefixes.as_slice(),
+                |cx, lit| { let lit = lit.as_slice(); quote_expr!(cx, $lit) });
+            let suffixes = self.vec_expr(prog.suffixes.as_slice(),
+                |cx, lit| { let lit = lit.as_slice(); quote_expr!(cx, $lit) });
+            let required = prog.required.as_slice();
+            let prefix_searcher = self.searcher_expr(&prog.prefix_searcher);
+            let suffix_searcher = self.searcher_expr(&prog.suffix_searcher);
+            let required_searcher =
+                self.searcher_expr(&prog.required_searcher);
+            let check_prefix = self.check_prefix(prog);
+            quote_expr!(self.cx, {
+                let prefixes: &[&'static str] = &$prefixes;
+                let prefix_searcher: Option<Searcher> = $prefix_searcher;
+                let suffixes: &[&'static str] = &$suffixes;
+                let suffix_searcher: Option<Searcher> = $suffix_searcher;
+                let required_searcher: Option<Searcher> = $required_searcher;
+                if !may_match(suffixes, &suffix_searcher, $required,
+                              &required_searcher, self.input, start) {
+                    return
+                }
+                let mut clist = &mut Threads::new(self.which);
+                let mut nlist = &mut Threads::new(self.which);
+
+                let mut groups = $init_groups;
+                let anchored = match self.anchor {
+                    Unanchored => false,
+                    AnchorStart | AnchorBoth => true,
+                };
+
+                self.ic = start;
+                let mut next_ic = self.chars.set(start);
+                while self.ic <= end {
+                    if clist.size == 0 {
+                        if matched {
+                            break
+                        }
+                        if anchored {
+                            if self.ic > start {
+                                break
+                            }
+                        } else {
+                            $check_prefix
+                        }
+                    }
+                    if clist.size == 0
+                       || (!$prefix_anchor && !anchored && !matched) {
+                        self.add(clist, 0, &mut groups)
+                    }
+
+                    self.ic = next_ic;
+                    next_ic = self.chars.advance();
+
+                    $step_threads
+                    ::std::mem::swap(&mut clist, &mut nlist);
+                    nlist.empty();
+                }
+                groups
+            })
+        };
 
         quote_expr!(self.cx, {
-fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
-            start: uint, end: uint) -> Vec<Option<uint>> {
+fn exec<'t>(which: ::regexp::native::MatchKind,
+            anchor: ::regexp::native::Anchor, locs: &mut [Option<uint>],
+            input: &'t str, start: uint, end: uint) {
     #![allow(unused_imports)]
     use regexp::native::{
-        MatchKind, Exists, Location, Submatches,
+        MatchKind, Exists, Shortest, Location, Submatches,
+        Anchor, Unanchored, AnchorStart, AnchorBoth,
         StepState, StepMatchEarlyReturn, StepMatch, StepContinue,
-        CharReader, find_prefix,
+        CharReader, Searcher, find_any, may_match,
     };
 
     return Nfa {
         which: which,
+        anchor: anchor,
         input: input,
+        end: end,
         ic: 0,
-        chars: CharReader::new(input),
-    }.run(start, end);
+        chars: $chars,
+    }.run(locs, start, end);
 
     type Captures = [Option<uint>, ..$num_cap_locs];
 
     struct Nfa<'t> {
         which: MatchKind,
+        anchor: Anchor,
         input: &'t str,
+        end: uint,
         ic: uint,
         chars: CharReader<'t>,
     }
 
     impl<'t> Nfa<'t> {
         #[allow(unused_variable)]
-        fn run(&mut self, start: uint, end: uint) -> Vec<Option<uint>> {
-            let mut matched = false;
-            let prefix_bytes: &[u8] = &$prefix_bytes;
-            let mut clist = &mut Threads::new(self.which);
-            let mut nlist = &mut Threads::new(self.which);
-
-            let mut groups = $init_groups;
-
-            self.ic = start;
-            let mut next_ic = self.chars.set(start);
-            while self.ic <= end {
-                if clist.size == 0 {
-                    if matched {
-                        break
-                    }
-                    $check_prefix
-                }
-                if clist.size == 0 || (!$prefix_anchor && !matched) {
-                    self.add(clist, 0, &mut groups)
-                }
-
-                self.ic = next_ic;
-                next_ic = self.chars.advance();
-
-                let mut i = 0;
-                while i < clist.size {
-                    let pc = clist.pc(i);
-                    let step_state = self.step(&mut groups, nlist,
-                                               clist.groups(i), pc);
-                    match step_state {
-                        StepMatchEarlyReturn =>
-                            return vec![Some(0u), Some(0u)],
-                        StepMatch => { matched = true; clist.empty() },
-                        StepContinue => {},
-                    }
-                    i += 1;
-                }
-                ::std::mem::swap(&mut clist, &mut nlist);
-                nlist.empty();
+        fn run(&mut self, locs: &mut [Option<uint>], start: uint, end: uint) {
+            for loc in locs.mut_iter() {
+                *loc = None
             }
+            let mut matched = false;
+            let groups = $search;
             match self.which {
-                Exists if matched     => vec![Some(0u), Some(0u)],
-                Exists                => vec![None, None],
-                Location | Submatches => {
-                    let elts = groups.len();
-                    let mut v = Vec::with_capacity(elts);
-                    unsafe {
-                        v.set_len(elts);
-                        ::std::ptr::copy_nonoverlapping_memory(
-                            v.as_mut_ptr(), groups.as_ptr(), elts);
+                Exists if matched => {
+                    locs[0] = Some(0u);
+                    locs[1] = Some(0u);
+                }
+                Exists => {}
+                Shortest | Location | Submatches => {
+                    for (loc, group) in locs.mut_iter().zip(groups.iter()) {
+                        *loc = *group
                     }
-                    v
                 }
             }
         }
@@ -223,7 +352,7 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
         #[allow(unused_variable)]
         #[inline(always)]
         fn step(&self, groups: &mut Captures, nlist: &mut Threads,
-                caps: &mut Captures, pc: uint) -> StepState {
+                caps: &mut Captures, pc: uint, off: uint) -> StepState {
             $step_insts
             StepContinue
         }
@@ -239,12 +368,13 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
 
     struct Thread {
         pc: uint,
+        off: uint,
         groups: Captures,
     }
 
     struct Threads {
         which: MatchKind,
-        queue: [Thread, ..$num_insts],
+        queue: [Thread, ..$num_threads],
         sparse: [uint, ..$num_insts],
         size: uint,
     }
@@ -261,11 +391,18 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
 
         #[inline(always)]
         fn add(&mut self, pc: uint, groups: &Captures) {
+            self.sparse[pc] = self.size;
+            self.add_literal(pc, 0, groups);
+        }
+
+        #[inline(always)]
+        fn add_literal(&mut self, pc: uint, off: uint, groups: &Captures) {
             let t = &mut self.queue[self.size];
             t.pc = pc;
+            t.off = off;
             match self.which {
                 Exists => {},
-                Location => {
+                Shortest | Location => {
                     t.groups[0] = groups[0];
                     t.groups[1] = groups[1];
                 }
@@ -273,13 +410,13 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                     unsafe { t.groups.copy_memory(groups.as_slice()) }
                 }
             }
-            self.sparse[pc] = self.size;
             self.size += 1;
         }
 
         #[inline(always)]
         fn add_empty(&mut self, pc: uint) {
             self.queue[self.size].pc = pc;
+            self.queue[self.size].off = 0;
             self.sparse[pc] = self.size;
             self.size += 1;
         }
@@ -287,7 +424,7 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
         #[inline(always)]
         fn contains(&self, pc: uint) -> bool {
             let s = self.sparse[pc];
-            s < self.size && self.queue[s].pc == pc
+            s < self.size && self.queue[s].pc == pc && self.queue[s].off == 0
         }
 
         #[inline(always)]
@@ -300,6 +437,11 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
             self.queue[i].pc
         }
 
+        #[inline(always)]
+        fn off(&self, i: uint) -> uint {
+            self.queue[i].off
+        }
+
         #[inline(always)]
         fn groups<'r>(&'r mut self, i: uint) -> &'r mut Captures {
             &'r mut self.queue[i].groups
@@ -307,18 +449,14 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
     }
 }
 
-::regexp::Regexp {
-    original: ~$regex,
-    names: ~$cap_names,
-    p: ::regexp::native::Native(exec),
-}
+exec
         })
     }
 
     // Generates code for the `add` method, which is responsible for adding
     // zero-width states to the next queue of states to visit.
-    fn add_insts(&self) -> @ast::Expr {
-        let arms = self.prog.insts.iter().enumerate().map(|(pc, inst)| {
+    fn add_insts(&self, prog: &Program) -> @ast::Expr {
+        let arms = prog.insts.iter().enumerate().map(|(pc, inst)| {
             let nextpc = pc + 1;
             let body = match *inst {
                 EmptyBegin(flags) => {
@@ -327,7 +465,7 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         if flags & FLAG_MULTI > 0 {
                             quote_expr!(self.cx,
                                 self.chars.is_begin()
-                                || self.chars.prev == Some($nl)
+                                || self.chars.before() == Some($nl)
                             )
                         } else {
                             quote_expr!(self.cx, self.chars.is_begin())
@@ -343,7 +481,7 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         if flags & FLAG_MULTI > 0 {
                             quote_expr!(self.cx,
                                 self.chars.is_end()
-                                || self.chars.cur == Some($nl)
+                                || self.chars.after() == Some($nl)
                             )
                         } else {
                             quote_expr!(self.cx, self.chars.is_end())
@@ -354,11 +492,32 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                     })
                 }
                 EmptyWordBoundary(flags) => {
+                    let yes =
+                        if flags & FLAG_UNICODE_WORD > 0 {
+                            quote_expr!(self.cx,
+                                        self.chars.is_unicode_word_boundary())
+                        } else {
+                            quote_expr!(self.cx, self.chars.is_word_boundary())
+                        };
                     let cond =
                         if flags & FLAG_NEGATED > 0 {
-                            quote_expr!(self.cx, !self.chars.is_word_boundary())
+                            quote_expr!(self.cx, !$yes)
                         } else {
-                            quote_expr!(self.cx, self.chars.is_word_boundary())
+                            yes
+                        };
+                    quote_expr!(self.cx, {
+                        nlist.add_empty($pc);
+                        if $cond { self.add(nlist, $nextpc, &mut *groups) }
+                    })
+                }
+                EmptyGraphemeBoundary(flags) => {
+                    let cond =
+                        if flags & FLAG_NEGATED > 0 {
+                            quote_expr!(self.cx,
+                                        !self.chars.is_grapheme_boundary())
+                        } else {
+                            quote_expr!(self.cx,
+                                        self.chars.is_grapheme_boundary())
                         };
                     quote_expr!(self.cx, {
                         nlist.add_empty($pc);
@@ -376,21 +535,21 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         self.add(nlist, $nextpc, &mut *groups);
                     });
                     // If this is saving a submatch location but we request
-                    // existence or only full match location, then we can skip
+                    // existence or only a match location, then we can skip
                     // right over it every time.
                     if slot > 1 {
                         quote_expr!(self.cx, {
                             nlist.add_empty($pc);
                             match self.which {
                                 Submatches => $save,
-                                Exists | Location => $add,
+                                Exists | Shortest | Location => $add,
                             }
                         })
                     } else {
                         quote_expr!(self.cx, {
                             nlist.add_empty($pc);
                             match self.which {
-                                Submatches | Location => $save,
+                                Submatches | Location | Shortest => $save,
                                 Exists => $add,
                             }
                         })
@@ -409,7 +568,15 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         self.add(nlist, $y, &mut *groups);
                     })
                 }
-                // For Match, OneChar, CharClass, Any
+                Match => {
+                    quote_expr!(self.cx, {
+                        match self.anchor {
+                            AnchorBoth if self.ic != self.end => {}
+                            _ => nlist.add($pc, &*groups),
+                        }
+                    })
+                }
+                // For OneChar, Literal, CharClass, Any, Branch
                 _ => quote_expr!(self.cx, nlist.add($pc, &*groups)),
             };
             self.arm_inst(pc, body)
@@ -420,8 +587,8 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
 
     // Generates the code for the `step` method, which processes all states
     // in the current queue that consume a single character.
-    fn step_insts(&self) -> @ast::Expr {
-        let arms = self.prog.insts.iter().enumerate().map(|(pc, inst)| {
+    fn step_insts(&self, prog: &Program) -> @ast::Expr {
+        let arms = prog.insts.iter().enumerate().map(|(pc, inst)| {
             let nextpc = pc + 1;
             let body = match *inst {
                 Match => {
@@ -430,6 +597,11 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                             Exists => {
                                 return StepMatchEarlyReturn
                             }
+                            Shortest => {
+                                groups[0] = caps[0];
+                                groups[1] = caps[1];
+                                return StepMatchEarlyReturn
+                            }
                             Location => {
                                 groups[0] = caps[0];
                                 groups[1] = caps[1];
@@ -459,6 +631,10 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         })
                     }
                 }
+                Literal(ref lit, flags) => {
+                    self.match_literal(pc, lit.as_slice(),
+                                       flags & FLAG_NOCASE > 0)
+                }
                 CharClass(ref ranges, flags) => {
                     let negate = flags & FLAG_NEGATED > 0;
                     let casei = flags & FLAG_NOCASE > 0;
@@ -497,7 +673,23 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
                         })
                     }
                 }
-                // EmptyBegin, EmptyEnd, EmptyWordBoundary, Save, Jump, Split
+                Branch(ref targets, flags) => {
+                    let mbranch = self.match_branch(targets.as_slice());
+                    let get_char =
+                        if flags & FLAG_NOCASE > 0 {
+                            quote_expr!(self.cx, self.chars.prev.unwrap().to_uppercase())
+                        } else {
+                            quote_expr!(self.cx, self.chars.prev.unwrap())
+                        };
+                    quote_expr!(self.cx, {
+                        if self.chars.prev.is_some() {
+                            let c = $get_char;
+                            $mbranch
+                        }
+                    })
+                }
+                // EmptyBegin, EmptyEnd, EmptyWordBoundary,
+                // EmptyGraphemeBoundary, Save, Jump, Split
                 _ => quote_expr!(self.cx, {}),
             };
             self.arm_inst(pc, body)
@@ -533,20 +725,87 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
         self.dummy_expr(ast::ExprMatch(match_on, arms))
     }
 
-    // Generates code for checking a literal prefix of the search string.
-    // The code is only generated if the regexp *has* a literal prefix.
+    // Translates the `Literal` instruction at `pc` into a match expression on
+    // the offset `off` of the next character to match in `lit`. A thread
+    // that matches it moves on to the next character, or to the next
+    // instruction after the last one.
+    fn match_literal(&self, pc: uint, lit: &str, casei: bool) -> @ast::Expr {
+        let nextpc = pc + 1;
+        let mut arms = vec!();
+        let mut off = 0;
+        while off < lit.len() {
+            let CharRange { ch: c, next } = lit.char_range_at(off);
+            let cond =
+                if casei {
+                    let upc = c.to_uppercase();
+                    quote_expr!(self.cx,
+                        self.chars.prev.map(|c| c.to_uppercase())
+                        == Some($upc))
+                } else {
+                    quote_expr!(self.cx, self.chars.prev == Some($c))
+                };
+            let body =
+                if next == lit.len() {
+                    quote_expr!(self.cx,
+                        if $cond { self.add(nlist, $nextpc, caps) })
+                } else {
+                    quote_expr!(self.cx,
+                        if $cond { nlist.add_literal($pc, $next, caps) })
+                };
+            arms.push(ast::Arm {
+                pats: vec!(@ast::Pat{
+                    id: ast::DUMMY_NODE_ID,
+                    span: self.sp,
+                    node: ast::PatLit(quote_expr!(self.cx, $off)),
+                }),
+                guard: None,
+                body: body,
+            });
+            off = next;
+        }
+
+        arms.push(self.wild_arm_expr(quote_expr!(self.cx, {})));
+
+        let match_on = quote_expr!(self.cx, off);
+        self.dummy_expr(ast::ExprMatch(match_on, arms))
+    }
+
+    // Translates the targets of a `Branch` instruction into a match
+    // expression that adds the target of the character `c` to `nlist`.
+    fn match_branch(&self, targets: &[(char, uint)]) -> @ast::Expr {
+        let mut arms = targets.iter().map(|&(c, pc)| {
+            ast::Arm {
+                pats: vec!(@ast::Pat{
+                    id: ast::DUMMY_NODE_ID,
+                    span: self.sp,
+                    node: ast::PatLit(quote_expr!(self.cx, $c)),
+                }),
+                guard: None,
+                body: quote_expr!(self.cx, self.add(nlist, $pc, caps)),
+            }
+        }).collect::<Vec<ast::Arm>>();
+
+        arms.push(self.wild_arm_expr(quote_expr!(self.cx, {})));
+
+        let match_on = quote_expr!(self.cx, c);
+        self.dummy_expr(ast::ExprMatch(match_on, arms))
+    }
+
+    // Generates code for skipping ahead to where one of the literal prefixes
+    // of the regexp occurs in the search string.
+    // The code is only generated if the regexp *has* literal prefixes.
     // Otherwise, a no-op is returned.
-    fn check_prefix(&self) -> @ast::Expr {
-        if self.prog.prefix.len() == 0 {
+    fn check_prefix(&self, prog: &Program) -> @ast::Expr {
+        if prog.prefixes.len() == 0 {
             quote_expr!(self.cx, {})
         } else {
             quote_expr!(self.cx,
                 if clist.size == 0 {
-                    let haystack = self.input.as_bytes().slice_from(self.ic);
-                    match find_prefix(prefix_bytes, haystack) {
+                    match find_any(prefixes, &prefix_searcher,
+                                   self.input, self.ic) {
                         None => break,
                         Some(i) => {
-                            self.ic += i;
+                            self.ic = i;
                             next_ic = self.chars.set(self.ic);
                         }
                     }
@@ -555,6 +814,23 @@ fn exec<'t>(which: ::regexp::native::MatchKind, input: &'t str,
         }
     }
 
+    // Builds an expression for a precomputed substring searcher, so that it
+    // doesn't have to be computed again for every search.
+    fn searcher_expr(&self, searcher: &Option<Searcher>) -> @ast::Expr {
+        match *searcher {
+            None => quote_expr!(self.cx, None),
+            Some(Searcher { rare, crit_pos, period, long_period, byteset }) => {
+                quote_expr!(self.cx, Some(Searcher {
+                    rare: $rare,
+                    crit_pos: $crit_pos,
+                    period: $period,
+                    long_period: $long_period,
+                    byteset: $byteset,
+                }))
+            }
+        }
+    }
+
     // Builds a `match pc { ... }` expression from a list of arms, specifically
     // for matching the current program counter with an instruction.
     // A wild-card arm is automatically added that executes a no-op. It will
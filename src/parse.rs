@@ -49,38 +49,99 @@ impl fmt::Show for Error {
 /// repetition feature. (No other copying is done.)
 ///
 /// Note that this representation prevents one from reproducing the regexp as
-/// it was typed. (But it could be used to reproduce an equivalent regexp.)
+/// it was typed. (But `syntax::to_regexp` can produce an equivalent regexp.)
 #[deriving(Show, Clone)]
-pub enum Ast {
+pub struct Ast {
+    /// The expression.
+    pub expr: Expr,
+    /// Where the expression came from in the regexp. (See `Span` for how
+    /// this works with counted repetitions.)
+    pub span: Span,
+}
+
+/// The different kinds of expressions in the abstract syntax.
+#[deriving(Show, Clone)]
+pub enum Expr {
+    /// The empty expression. It matches everywhere.
     Nothing,
+    /// A single character. The only flag that applies is `FLAG_NOCASE`.
     Literal(char, Flags),
+    /// Any character. Includes '\n' only if `FLAG_DOTNL` is set.
     Dot(Flags),
+    /// A character class given as a sorted list of non-overlapping inclusive
+    /// ranges. The flags may include `FLAG_NOCASE` and `FLAG_NEGATED`.
     Class(Vec<(char, char)>, Flags),
+    /// The beginning of the text (or of a line if `FLAG_MULTI` is set).
     Begin(Flags),
+    /// The end of the text (or of a line if `FLAG_MULTI` is set).
     End(Flags),
+    /// A word boundary, or something that isn't one if `FLAG_NEGATED` is set.
+    /// `FLAG_UNICODE_WORD` selects the UAX#29 word boundary rules.
     WordBoundary(Flags),
+    /// An extended grapheme cluster (`\X`).
+    // It is compiled to a small program rather than a single instruction,
+    // but keeping it as one item means it can be repeated like any other
+    // single character expression.
+    Grapheme,
+    /// A capture group with its index and optional name.
     Capture(uint, Option<~str>, ~Ast),
+    /// A concatenation of expressions.
     // Represent concatenation as a flat vector to avoid blowing the
     // stack in the compiler.
     Cat(Vec<~Ast>),
+    /// An alternation of two expressions, where the first is preferred.
+    /// Longer alternations nest to the right.
     Alt(~Ast, ~Ast),
+    /// A repetition of an expression. Counted repetitions like `a{2,3}` are
+    /// expanded by the parser into these.
     Rep(~Ast, Repeater, Greed),
 }
 
+/// The location of an expression in a regexp, given as the half open range
+/// `[start, end)` of character indices (like `Error::pos`).
+///
+/// Counted repetitions are expanded by the parser, and every expression
+/// produced by the expansion has the span of the whole repetition. For
+/// example, each of the three `a` literals that `a{3}` expands to has the
+/// span of `a{3}`. Only the copies themselves get that span, though. The
+/// expressions inside of them are cloned as they are, so they keep their
+/// own spans and every copy's children have the same spans. For example,
+/// `(a){2}` expands to two capture groups that span all of `(a){2}`, but
+/// the `a` in each of them spans just the `a`. So a span doesn't always
+/// identify a single node in the tree.
+///
+/// Since non-capturing groups have no expression of their own, their
+/// parentheses are included in the span of the expression inside of them.
+#[deriving(Show, Eq, Clone)]
+pub struct Span {
+    /// The index of the first character of the expression.
+    pub start: uint,
+    /// The index just past the last character of the expression.
+    pub end: uint,
+}
+
+/// The kind of a repetition operator.
 #[deriving(Show, Eq, Clone)]
 pub enum Repeater {
+    /// `?`
     ZeroOne,
+    /// `*`
     ZeroMore,
+    /// `+`
     OneMore,
 }
 
+/// Whether a repetition prefers to match more or less.
 #[deriving(Show, Clone)]
 pub enum Greed {
+    /// Match as much as possible.
     Greedy,
+    /// Match as little as possible.
     Ungreedy,
 }
 
 impl Greed {
+    /// Returns true if and only if this is `Greedy`.
     pub fn is_greedy(&self) -> bool {
         match *self {
             Greedy => true,
@@ -104,37 +165,37 @@ impl Greed {
 #[deriving(Show)]
 enum BuildAst {
     Ast(~Ast),
-    Paren(Flags, uint, ~str), // '('
+    Paren(Flags, uint, ~str, uint), // '(' and its position
     Bar, // '|'
 }
 
 impl BuildAst {
     fn paren(&self) -> bool {
         match *self {
-            Paren(_, _, _) => true,
+            Paren(_, _, _, _) => true,
             _ => false,
         }
     }
 
     fn flags(&self) -> Flags {
         match *self {
-            Paren(flags, _, _) => flags,
+            Paren(flags, _, _, _) => flags,
             _ => fail!("Cannot get flags from {}", self),
         }
     }
 
     fn capture(&self) -> Option<uint> {
         match *self {
-            Paren(_, 0, _) => None,
-            Paren(_, c, _) => Some(c),
+            Paren(_, 0, _, _) => None,
+            Paren(_, c, _, _) => Some(c),
             _ => fail!("Cannot get capture group from {}", self),
         }
     }
 
     fn capture_name(&self) -> Option<~str> {
         match *self {
-            Paren(_, 0, _) => None,
-            Paren(_, _, ref name) => {
+            Paren(_, 0, _, _) => None,
+            Paren(_, _, ref name, _) => {
                 if name.len() == 0 {
                     None
                 } else {
@@ -145,6 +206,13 @@ impl BuildAst {
         }
     }
 
+    fn start(&self) -> uint {
+        match *self {
+            Paren(_, _, _, start) => start,
+            _ => fail!("Cannot get start position from {}", self),
+        }
+    }
+
     fn bar(&self) -> bool {
         match *self {
             Bar => true,
@@ -164,12 +232,20 @@ impl BuildAst {
 /// expression.
 pub type Flags = u8;
 
+/// No flags set.
 pub static FLAG_EMPTY:      u8 = 0;
+/// Case insensitive matching (`i`).
 pub static FLAG_NOCASE:     u8 = 1 << 0; // i
+/// `^` and `$` match at the beginning and end of lines (`m`).
 pub static FLAG_MULTI:      u8 = 1 << 1; // m
+/// `.` matches '\n' (`s`).
 pub static FLAG_DOTNL:      u8 = 1 << 2; // s
+/// The meaning of greedy and ungreedy repetitions is swapped (`U`).
 pub static FLAG_SWAP_GREED: u8 = 1 << 3; // U
+/// A negated character class or a negated word boundary.
 pub static FLAG_NEGATED:    u8 = 1 << 4; // char class or not word boundary
+/// A UAX#29 word boundary.
+pub static FLAG_UNICODE_WORD: u8 = 1 << 5; // \b{w} or \B{w}
 
 struct Parser<'a> {
     // The input, parsed only as a sequence of UTF8 code points.
@@ -188,6 +264,7 @@ struct Parser<'a> {
     names: Vec<~str>,
 }
 
+/// Parses the regular expression `s` into its abstract syntax.
 pub fn parse(s: &str) -> Result<~Ast, Error> {
     Parser {
         chars: s.chars().collect(),
@@ -220,7 +297,8 @@ impl<'a> Parser<'a> {
                         try!(self.parse_group_opts())
                     } else {
                         self.caps += 1;
-                        self.stack.push(Paren(self.flags, self.caps, ~""))
+                        self.stack.push(
+                            Paren(self.flags, self.caps, ~"", self.chari))
                     }
                 }
                 ')' => {
@@ -232,19 +310,25 @@ impl<'a> Parser<'a> {
                     // Before we smush the alternates together and pop off the
                     // left paren, let's grab the old flags and see if we
                     // need a capture.
-                    let (cap, cap_name, oldflags) = {
+                    let (cap, cap_name, oldflags, start) = {
                         let paren = self.stack.get(altfrom-1);
-                        (paren.capture(), paren.capture_name(), paren.flags())
+                        (paren.capture(), paren.capture_name(), paren.flags(),
+                         paren.start())
                     };
                     try!(self.alternate(altfrom));
                     self.flags = oldflags;
 
                     // If this was a capture, pop what we just pushed in
-                    // alternate and make it a capture.
+                    // alternate and make it a capture. Otherwise, the
+                    // parentheses become part of the inner expression.
+                    let mut ast = try!(self.pop_ast());
                     if cap.is_some() {
-                        let ast = try!(self.pop_ast());
-                        self.push(~Capture(cap.unwrap(), cap_name, ast));
+                        let cap = Capture(cap.unwrap(), cap_name, ast);
+                        ast = self.ast(start, cap);
+                    } else {
+                        ast.span = Span { start: start, end: self.chari + 1 };
                     }
+                    self.push(ast);
                 }
                 '|' => {
                     let catfrom = try!(
@@ -305,12 +389,18 @@ impl<'a> Parser<'a> {
         self.stack.push(Ast(ast))
     }
 
+    // Returns `expr` with a span from `start` up to and including the
+    // current character.
+    fn ast(&self, start: uint, expr: Expr) -> ~Ast {
+        ~Ast { expr: expr, span: Span { start: start, end: self.chari + 1 } }
+    }
+
     fn push_repeater(&mut self, c: char) -> Result<(), Error> {
         if self.stack.len() == 0 {
             return self.err(
                 "A repeat operator must be preceded by a valid expression.")
         }
-        let rep: Repeater = match c {
+        let repeater: Repeater = match c {
             '?' => ZeroOne, '*' => ZeroMore, '+' => OneMore,
             _ => fail!("Not a valid repeater operator."),
         };
@@ -322,38 +412,35 @@ impl<'a> Parser<'a> {
             _ => {},
         }
         let ast = try!(self.pop_ast());
-        match ast {
-            ~Begin(_) | ~End(_) | ~WordBoundary(_) =>
+        match ast.expr {
+            Begin(_) | End(_) | WordBoundary(_) =>
                 return self.err(
                     "Repeat arguments cannot be empty width assertions."),
             _ => {}
         }
         let greed = try!(self.get_next_greedy());
-        self.push(~Rep(ast, rep, greed));
+        let start = ast.span.start;
+        let rep = self.ast(start, Rep(ast, repeater, greed));
+        self.push(rep);
         Ok(())
     }
 
     fn push_literal(&mut self, c: char) -> Result<(), Error> {
-        match c {
-            '.' => {
-                self.push(~Dot(self.flags))
-            }
-            '^' => {
-                self.push(~Begin(self.flags))
-            }
-            '$' => {
-                self.push(~End(self.flags))
-            }
-            _ => {
-                self.push(~Literal(c, self.flags))
-            }
-        }
+        let expr = match c {
+            '.' => Dot(self.flags),
+            '^' => Begin(self.flags),
+            '$' => End(self.flags),
+            _ => Literal(c, self.flags),
+        };
+        let ast = self.ast(self.chari, expr);
+        self.push(ast);
         Ok(())
     }
 
     // Parses all forms of character classes.
     // Assumes that '[' is the current character.
     fn parse_class(&mut self) -> Result<(), Error> {
+        let start = self.chari;
         let negated =
             if self.peek_is(1, '^') {
                 try!(self.expect('^'))
@@ -378,8 +465,9 @@ impl<'a> Parser<'a> {
             match c {
                 '[' =>
                     match self.try_parse_ascii() {
-                        Some(~Class(asciis, flags)) => {
-                            alts.push(~Class(asciis, flags ^ negated));
+                        Some(~Ast { expr: Class(asciis, flags), span }) => {
+                            let class = Class(asciis, flags ^ negated);
+                            alts.push(~Ast { expr: class, span: span });
                             continue
                         }
                         Some(ast) =>
@@ -388,17 +476,23 @@ impl<'a> Parser<'a> {
                         None => {},
                     },
                 '\\' => {
-                    match try!(self.parse_escape()) {
-                        ~Class(asciis, flags) => {
-                            alts.push(~Class(asciis, flags ^ negated));
+                    let ~Ast { expr, span } = try!(self.parse_escape());
+                    match expr {
+                        Class(asciis, flags) => {
+                            let class = Class(asciis, flags ^ negated);
+                            alts.push(~Ast { expr: class, span: span });
                             continue
                         }
-                        ~Literal(c2, _) => c = c2, // process below
-                        ~Begin(_) | ~End(_) | ~WordBoundary(_) =>
+                        Literal(c2, _) => c = c2, // process below
+                        Begin(_) | End(_) | WordBoundary(_) =>
                             return self.err(
                                 "\\A, \\z, \\b and \\B are not valid escape \
                                  sequences inside a character class."),
-                        ast => fail!("Unexpected AST item '{}'", ast),
+                        Grapheme =>
+                            return self.err(
+                                "\\X is not a valid escape sequence inside a \
+                                 character class."),
+                        expr => fail!("Unexpected AST item '{}'", expr),
                     }
                 }
                 _ => {},
@@ -407,15 +501,16 @@ impl<'a> Parser<'a> {
                 ']' => {
                     if ranges.len() > 0 {
                         let flags = negated | (self.flags & FLAG_NOCASE);
-                        let mut ast = ~Class(combine_ranges(ranges), flags);
+                        let class = Class(combine_ranges(ranges), flags);
+                        let mut ast = self.ast(start, class);
                         for alt in alts.move_iter() {
-                            ast = ~Alt(alt, ast)
+                            ast = self.ast(start, Alt(alt, ast))
                         }
                         self.push(ast);
                     } else if alts.len() > 0 {
                         let mut ast = alts.pop().unwrap();
                         for alt in alts.move_iter() {
-                            ast = ~Alt(alt, ast)
+                            ast = self.ast(start, Alt(alt, ast))
                         }
                         self.push(ast);
                     }
@@ -471,9 +566,10 @@ impl<'a> Parser<'a> {
         match find_class(ASCII_CLASSES, name) {
             None => None,
             Some(ranges) => {
+                let start = self.chari;
                 self.chari = closer;
                 let flags = negated | (self.flags & FLAG_NOCASE);
-                Some(~Class(combine_ranges(ranges), flags))
+                Some(self.ast(start, Class(combine_ranges(ranges), flags)))
             }
         }
     }
@@ -540,30 +636,34 @@ impl<'a> Parser<'a> {
         }
 
         // Now manipulate the AST be repeating elements.
+        // Everything pushed here spans the repeated expression and the
+        // counted repetition operator.
+        let mut ast = try!(self.pop_ast());
+        let span = Span { start: ast.span.start, end: self.chari + 1 };
+        ast.span = span;
         if max.is_none() {
             // Require N copies of what's on the stack and then repeat it.
-            let ast = try!(self.pop_ast());
             for _ in iter::range(0, min) {
                 self.push(ast.clone())
             }
-            self.push(~Rep(ast, ZeroMore, greed));
+            self.push(~Ast { expr: Rep(ast, ZeroMore, greed), span: span });
         } else {
             // Require N copies of what's on the stack and then repeat it
             // up to M times optionally.
-            let ast = try!(self.pop_ast());
             for _ in iter::range(0, min) {
                 self.push(ast.clone())
             }
             if max.is_some() {
                 for _ in iter::range(min, max.unwrap()) {
-                    self.push(~Rep(ast.clone(), ZeroOne, greed))
+                    let rep = Rep(ast.clone(), ZeroOne, greed);
+                    self.push(~Ast { expr: rep, span: span })
                 }
             }
             // It's possible that we popped something off the stack but
             // never put anything back on it. To keep things simple, add
             // a no-op expression.
             if min == 0 && (max.is_none() || max == Some(0)) {
-                self.push(~Nothing)
+                self.push(~Ast { expr: Nothing, span: span })
             }
         }
         Ok(())
@@ -572,34 +672,48 @@ impl<'a> Parser<'a> {
     // Parses all escape sequences.
     // Assumes that '\' is the current character.
     fn parse_escape(&mut self) -> Result<~Ast, Error> {
+        let start = self.chari;
         try!(self.noteof("an escape sequence following a '\\'"))
 
         let c = self.cur();
         if is_punct(c) {
-            return Ok(~Literal(c, FLAG_EMPTY))
+            return Ok(self.ast(start, Literal(c, FLAG_EMPTY)))
         }
-        match c {
-            'a' => Ok(~Literal('\x07', FLAG_EMPTY)),
-            'f' => Ok(~Literal('\x0C', FLAG_EMPTY)),
-            't' => Ok(~Literal('\t', FLAG_EMPTY)),
-            'n' => Ok(~Literal('\n', FLAG_EMPTY)),
-            'r' => Ok(~Literal('\r', FLAG_EMPTY)),
-            'v' => Ok(~Literal('\x0B', FLAG_EMPTY)),
-            'A' => Ok(~Begin(FLAG_EMPTY)),
-            'z' => Ok(~End(FLAG_EMPTY)),
-            'b' => Ok(~WordBoundary(FLAG_EMPTY)),
-            'B' => Ok(~WordBoundary(FLAG_NEGATED)),
-            '0'|'1'|'2'|'3'|'4'|'5'|'6'|'7' => Ok(try!(self.parse_octal())),
-            'x' => Ok(try!(self.parse_hex())),
-            'p' | 'P' => Ok(try!(self.parse_unicode_name())),
+        let expr = match c {
+            'a' => Literal('\x07', FLAG_EMPTY),
+            'f' => Literal('\x0C', FLAG_EMPTY),
+            't' => Literal('\t', FLAG_EMPTY),
+            'n' => Literal('\n', FLAG_EMPTY),
+            'r' => Literal('\r', FLAG_EMPTY),
+            'v' => Literal('\x0B', FLAG_EMPTY),
+            'A' => Begin(FLAG_EMPTY),
+            'z' => End(FLAG_EMPTY),
+            'b' | 'B' => {
+                let mut flags =
+                    if c == 'B' { FLAG_NEGATED } else { FLAG_EMPTY };
+                // Only '{w}' selects UAX#29 word boundaries. Anything else
+                // (like '\b{2}') is left alone for the repetition parser.
+                if self.peek_is(1, '{') && self.peek_is(2, 'w')
+                   && self.peek_is(3, '}') {
+                    self.chari += 3;
+                    flags |= FLAG_UNICODE_WORD;
+                }
+                WordBoundary(flags)
+            }
+            'X' => Grapheme,
+            '0'|'1'|'2'|'3'|'4'|'5'|'6'|'7' => try!(self.parse_octal()),
+            'x' => try!(self.parse_hex()),
+            'p' | 'P' => try!(self.parse_unicode_name()),
             'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
                 let ranges = perl_unicode_class(c);
                 let mut flags = self.flags & FLAG_NOCASE;
                 if c.is_uppercase() { flags |= FLAG_NEGATED }
-                Ok(~Class(ranges, flags))
+                Class(ranges, flags)
             }
-            _ => self.err(format!("Invalid escape sequence '\\\\{}'", c)),
-        }
+            _ => return self.err(format!(
+                "Invalid escape sequence '\\\\{}'", c)),
+        };
+        Ok(self.ast(start, expr))
     }
 
     // Parses a unicode character class name, either of the form \pF where
@@ -607,7 +721,7 @@ impl<'a> Parser<'a> {
     // name is the unicode class name.
     // Assumes that \p or \P has been read (and 'p' or 'P' is the current
     // character).
-    fn parse_unicode_name(&mut self) -> Result<~Ast, Error> {
+    fn parse_unicode_name(&mut self) -> Result<Expr, Error> {
         let negated = if self.cur() == 'P' { FLAG_NEGATED } else { FLAG_EMPTY };
         let mut name: ~str;
         if self.peek_is(1, '{') {
@@ -635,14 +749,14 @@ impl<'a> Parser<'a> {
             None => return self.err(format!(
                 "Could not find Unicode class '{}'", name)),
             Some(ranges) => {
-                Ok(~Class(ranges, negated | (self.flags & FLAG_NOCASE)))
+                Ok(Class(ranges, negated | (self.flags & FLAG_NOCASE)))
             }
         }
     }
 
     // Parses an octal number, up to 3 digits.
     // Assumes that \n has been read, where n is the first digit.
-    fn parse_octal(&mut self) -> Result<~Ast, Error> {
+    fn parse_octal(&mut self) -> Result<Expr, Error> {
         let start = self.chari;
         let mut end = start + 1;
         let (d2, d3) = (self.peek(1), self.peek(2));
@@ -656,7 +770,7 @@ impl<'a> Parser<'a> {
         }
         let s = self.slice(start, end);
         match num::from_str_radix::<u32>(s, 8) {
-            Some(n) => Ok(~Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
+            Some(n) => Ok(Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
             None => self.err(format!(
                 "Could not parse '{}' as octal number.", s)),
         }
@@ -664,7 +778,7 @@ impl<'a> Parser<'a> {
 
     // Parse a hex number. Either exactly two digits or anything in {}.
     // Assumes that \x has been read.
-    fn parse_hex(&mut self) -> Result<~Ast, Error> {
+    fn parse_hex(&mut self) -> Result<Expr, Error> {
         if !self.peek_is(1, '{') {
             try!(self.expect('{'))
             return self.parse_hex_two()
@@ -684,7 +798,7 @@ impl<'a> Parser<'a> {
     // Assumes that \xn has been read, where n is the first digit and is the
     // current character.
     // After return, parser will point at the second digit.
-    fn parse_hex_two(&mut self) -> Result<~Ast, Error> {
+    fn parse_hex_two(&mut self) -> Result<Expr, Error> {
         let (start, end) = (self.chari, self.chari + 2);
         let bad = self.slice(start - 2, self.chars.len());
         try!(self.noteof(format!("Invalid hex escape sequence '{}'", bad)))
@@ -692,9 +806,9 @@ impl<'a> Parser<'a> {
     }
 
     // Parses `s` as a hexadecimal number.
-    fn parse_hex_digits(&self, s: &str) -> Result<~Ast, Error> {
+    fn parse_hex_digits(&self, s: &str) -> Result<Expr, Error> {
         match num::from_str_radix::<u32>(s, 16) {
-            Some(n) => Ok(~Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
+            Some(n) => Ok(Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
             None => self.err(format!(
                 "Could not parse '{}' as hex number.", s)),
         }
@@ -705,6 +819,7 @@ impl<'a> Parser<'a> {
     // is '<'.
     // When done, parser will be at the closing '>' character.
     fn parse_named_capture(&mut self) -> Result<(), Error> {
+        let start = self.chari - 3;
         try!(self.noteof("a capture name"))
         let closer =
             match self.pos('>') {
@@ -725,10 +840,25 @@ impl<'a> Parser<'a> {
         self.names.push(name.clone());
         self.chari = closer;
         self.caps += 1;
-        self.stack.push(Paren(self.flags, self.caps, name));
+        self.stack.push(Paren(self.flags, self.caps, name, start));
         Ok(())
     }
 
+    // Parses a comment of the form '(?#...)'. Comments cannot be nested and
+    // are skipped entirely, so they never appear in the AST.
+    // Assumes that '(?#' has been consumed and that the current character
+    // is '#'.
+    // When done, parser will be at the closing ')' character.
+    fn parse_comment(&mut self) -> Result<(), Error> {
+        match self.pos(')') {
+            Some(i) => {
+                self.chari = i;
+                Ok(())
+            }
+            None => self.err("Unclosed comment."),
+        }
+    }
+
     // Parses non-capture groups and options.
     // Assumes that '(?' has already been consumed and '?' is the current
     // character.
@@ -737,8 +867,15 @@ impl<'a> Parser<'a> {
             try!(self.expect('P')) try!(self.expect('<'))
             return self.parse_named_capture()
         }
+        if self.peek_is(1, '#') {
+            try!(self.expect('#'))
+            return self.parse_comment()
+        }
         let start = self.chari;
         let mut flags = self.flags;
+        // The flags that were set before a '-' was seen. After a '-', `flags`
+        // collects the flags to clear instead.
+        let mut setflags = FLAG_EMPTY;
         let mut sign = 1;
         let mut saw_flag = false;
         loop {
@@ -756,7 +893,8 @@ impl<'a> Parser<'a> {
                     }
                     sign = -1;
                     saw_flag = false;
-                    flags = flags ^ flags;
+                    setflags = flags;
+                    flags = FLAG_EMPTY;
                 }
                 ':' | ')' => {
                     if sign < 0 {
@@ -765,11 +903,11 @@ impl<'a> Parser<'a> {
                                 "A valid flag does not follow negation in '{}'",
                                 self.slice(start, self.chari + 1)))
                         }
-                        flags = flags ^ flags;
+                        flags = setflags & !flags;
                     }
                     if self.cur() == ':' {
                         // Save the old flags with the opening paren.
-                        self.stack.push(Paren(self.flags, 0, ~""));
+                        self.stack.push(Paren(self.flags, 0, ~"", start - 1));
                     }
                     self.flags = flags;
                     return Ok(())
@@ -840,7 +978,7 @@ impl<'a> Parser<'a> {
         // thrown away). But be careful with overflow---we can't count on the
         // open paren to be there.
         if from > 0 { from = from - 1}
-        let ast = try!(self.build_from(from, Alt));
+        let ast = try!(self.build_from(from, alternate_span));
         self.push(ast);
         Ok(())
     }
@@ -917,7 +1055,7 @@ impl<'a> Parser<'a> {
 // Given an unordered collection of character ranges, combine_ranges returns
 // an ordered sequence of character ranges where no two ranges overlap. They
 // are ordered from least to greatest (using start position).
-fn combine_ranges(unordered: Vec<(char, char)>) -> Vec<(char, char)> {
+pub fn combine_ranges(unordered: Vec<(char, char)>) -> Vec<(char, char)> {
     // Returns true iff the two character classes overlap or share a boundary.
     // e.g., ('a', 'g') and ('h', 'm') would return true.
     fn should_merge((a, b): (char, char), (x, y): (char, char)) -> bool {
@@ -962,12 +1100,23 @@ fn perl_unicode_class(which: char) -> Vec<(char, char)> {
 // Returns a concatenation of two expressions. This also guarantees that a
 // `Cat` expression will never be a direct child of another `Cat` expression.
 fn concat_flatten(x: ~Ast, y: ~Ast) -> Ast {
-    match (x, y) {
-        (~Cat(mut xs), ~Cat(ys)) => { xs.push_all_move(ys); Cat(xs) }
-        (~Cat(mut xs), ast) => { xs.push(ast); Cat(xs) }
-        (ast, ~Cat(mut xs)) => { xs.unshift(ast); Cat(xs) }
+    let span = Span { start: x.span.start, end: y.span.end };
+    let expr = match (x, y) {
+        (~Ast { expr: Cat(mut xs), .. }, ~Ast { expr: Cat(ys), .. }) => {
+            xs.push_all_move(ys);
+            Cat(xs)
+        }
+        (~Ast { expr: Cat(mut xs), .. }, ast) => { xs.push(ast); Cat(xs) }
+        (ast, ~Ast { expr: Cat(mut xs), .. }) => { xs.unshift(ast); Cat(xs) }
         (ast1, ast2) => Cat(vec!(ast1, ast2)),
-    }
+    };
+    Ast { expr: expr, span: span }
+}
+
+// Returns an alternation of two expressions that spans both of them.
+fn alternate_span(x: ~Ast, y: ~Ast) -> Ast {
+    let span = Span { start: x.span.start, end: y.span.end };
+    Ast { expr: Alt(x, y), span: span }
 }
 
 pub fn is_punct(c: char) -> bool {
@@ -18,12 +18,17 @@ use std::str::raw;
 use std::mem;
 use std::ptr;
 use std::rt::global_heap::malloc_raw;
+use std::slice;
+use std::vec;
 use RawVec = std::raw::Vec;
+use sync::{Arc, Mutex};
 
 use compile::Program;
 use parse;
+use simplify;
 use vm;
-use vm::{CaptureLocs, MatchKind, Exists, Location, Submatches};
+use vm::{CaptureLocs, MatchKind, Exists, Shortest, Location, Submatches};
+use vm::{Anchor, Unanchored, AnchorStart, AnchorBoth};
 
 /// Escapes all regular expression meta characters in `text` so that it may be
 /// safely used in a regular expression as a literal string.
@@ -58,7 +63,8 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
 /// or replace text. All searching is done with an implicit `.*?` at the
 /// beginning and end of an expression. To force an expression to match the
 /// whole string (or a prefix or a suffix), you must use an anchor like `^` or
-/// `$` (or `\A` and `\z`).
+/// `$` (or `\A` and `\z`), or one of the anchored searches like
+/// `is_full_match` or `is_match_at_start`.
 ///
 /// While this crate will handle Unicode strings (whether in the regular
 /// expression or in the search text), all positions returned are **byte
@@ -82,7 +88,9 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
 ///     Ok(re) => re,
 ///     Err(err) => fail!("{}", err),
 /// };
-/// assert_eq!(re.find("phone: 111-222-3333"), Some((7, 19)));
+/// let m = re.find("phone: 111-222-3333").unwrap();
+/// assert_eq!(m.range(), (7, 19));
+/// assert_eq!(m.as_str(), "111-222-3333");
 /// ```
 ///
 /// You can also use the `regexp!` macro to compile a regular expression when
@@ -95,7 +103,7 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
 ///
 /// fn main() {
 ///     let re = regexp!(r"\d+");
-///     assert_eq!(re.find("123 abc"), Some((0, 3)));
+///     assert_eq!(re.find("123 abc").unwrap().range(), (0, 3));
 /// }
 /// ```
 ///
@@ -118,9 +126,81 @@ pub struct Regexp {
     #[doc(hidden)]
     pub names: ~[Option<~str>],
     #[doc(hidden)]
+    pub named: Option<NamedGroups>,
+    #[doc(hidden)]
     pub p: MaybeNative,
 }
 
+/// The names and indices of the named capture groups of a regexp, sorted by
+/// name. A dynamic regexp builds them once and shares them with the
+/// `Captures` of its matches, while the `regexp!` macro generates them as
+/// static data.
+#[doc(hidden)]
+#[deriving(Clone)]
+pub enum NamedGroups {
+    NamedStatic(&'static [(&'static str, uint)]),
+    NamedOwned(Arc<Vec<(~str, uint)>>),
+}
+
+impl NamedGroups {
+    fn len(&self) -> uint {
+        match *self {
+            NamedStatic(named) => named.len(),
+            NamedOwned(ref named) => named.len(),
+        }
+    }
+
+    // Returns the name and index of the `i`th group, in order of name.
+    fn get<'a>(&'a self, i: uint) -> (&'a str, uint) {
+        match *self {
+            NamedStatic(named) => named[i],
+            NamedOwned(ref named) => {
+                let &(ref name, j) = named.get(i);
+                (name.as_slice(), j)
+            }
+        }
+    }
+
+    // Returns the index of the capture group named `name`, if there is one.
+    fn find(&self, name: &str) -> Option<uint> {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (found, i) = self.get(mid);
+            if found == name {
+                return Some(i)
+            } else if found < name {
+                lo = mid + 1
+            } else {
+                hi = mid
+            }
+        }
+        None
+    }
+}
+
+// Builds the named capture groups of a dynamic regexp given its capture
+// names. There are none if there are no named groups.
+fn named_groups(names: &[Option<~str>]) -> Option<NamedGroups> {
+    let mut named: Vec<(~str, uint)> =
+        names.iter().enumerate().filter_map(|(i, name)| {
+            name.as_ref().map(|name| (name.clone(), i))
+        }).collect();
+    if named.len() == 0 {
+        return None
+    }
+    named.as_mut_slice().sort_by(|&(ref x, _), &(ref y, _)| x.cmp(y));
+    Some(NamedOwned(Arc::new(named)))
+}
+
+// Returns the index of the capture group named `name`, if there is one.
+fn name_index(named: &Option<NamedGroups>, name: &str) -> Option<uint> {
+    match *named {
+        None => None,
+        Some(ref named) => named.find(name),
+    }
+}
+
 impl fmt::Show for Regexp {
     /// Shows the original regular expression.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -128,20 +208,41 @@ impl fmt::Show for Regexp {
     }
 }
 
+// A dynamic regexp has its program and a place for its reversed program,
+// which is compiled the first time that `rfind` or `rfind_iter` needs it.
+// (The one that the program may have is compiled from the simplified
+// expression, so it's only fit for finding where a match ending with `$`
+// starts; see `reversed_program`.) The place is shared by the clones of the
+// regexp.
+// A native regexp has a function that searches forwards and one that
+// searches backwards with the reversed program (from `end` to `start`).
+// The latter only does unanchored searches, so it ignores its `Anchor`.
 pub enum MaybeNative {
-    Dynamic(Program),
-    Native(fn(MatchKind, &str, uint, uint) -> Vec<Option<uint>>),
+    Dynamic(Program, Arc<Mutex<Option<Arc<Program>>>>),
+    Native(fn(MatchKind, Anchor, &mut [Option<uint>], &str, uint, uint),
+           fn(MatchKind, Anchor, &mut [Option<uint>], &str, uint, uint)),
 }
 
 impl Clone for MaybeNative {
     fn clone(&self) -> MaybeNative {
         match *self {
-            Dynamic(ref p) => Dynamic(p.clone()),
-            Native(fp) => Native(fp),
+            Dynamic(ref p, ref rev) => Dynamic(p.clone(), rev.clone()),
+            Native(fp, rfp) => Native(fp, rfp),
         }
     }
 }
 
+/// Compiles the reversed program of the regular expression `re`, which must
+/// be valid.
+///
+/// It isn't simplified first, since the simplifier may reorder alternatives
+/// that can't match at the same position when the input is read forwards,
+/// but can when it's read backwards (e.g., `b|ab`).
+#[doc(hidden)]
+pub fn reversed_program(re: &str) -> Program {
+    Program::new_rev(parse::parse(re).unwrap())
+}
+
 impl Regexp {
     /// Compiles a dynamic regular expression. Once compiled, it can be
     /// used repeatedly to search, split or replace text in a string.
@@ -151,45 +252,222 @@ impl Regexp {
     ///
     /// If an invalid expression is given, then an error is returned.
     pub fn new(re: &str) -> Result<Regexp, parse::Error> {
-        let ast = try!(parse::parse(re));
-        let (prog, names) = Program::new(ast);
-        Ok(Regexp { original: re.to_owned(), names: names, p: Dynamic(prog) })
+        RegexpBuilder::new(re).build()
+    }
+}
+
+/// A builder for dynamic regular expressions, for the options that can't be
+/// set with flags in the expression itself.
+///
+/// # Example
+///
+/// Find the leftmost-longest match instead of the leftmost-first one:
+///
+/// ```rust
+/// # use regexp::RegexpBuilder;
+/// let re = RegexpBuilder::new("a|ab").longest(true).build().unwrap();
+/// assert_eq!(re.find("ab").unwrap().as_str(), "ab");
+/// ```
+#[deriving(Clone)]
+pub struct RegexpBuilder {
+    re: ~str,
+    longest: bool,
+}
+
+impl RegexpBuilder {
+    /// Returns a builder for the regular expression `re`, with every option
+    /// set to its default.
+    pub fn new(re: &str) -> RegexpBuilder {
+        RegexpBuilder { re: re.to_owned(), longest: false }
+    }
+
+    /// When `yes` is true, searches find the leftmost-longest match (as in
+    /// POSIX) instead of the leftmost-first match (as in Perl). That is, of
+    /// the matches that start at the leftmost position, the longest one is
+    /// found, no matter which alternatives or repetitions it takes.
+    ///
+    /// Its submatches are those of the highest priority way of matching it
+    /// (e.g., the first alternative that works), which isn't always what
+    /// POSIX would report. Lazy repetitions are as greedy as any other.
+    /// Searching backwards with `rfind` isn't affected by this option.
+    ///
+    /// This option is off by default, and the `regexp!` macro always finds
+    /// the leftmost-first match.
+    pub fn longest(mut self, yes: bool) -> RegexpBuilder {
+        self.longest = yes;
+        self
+    }
+
+    /// Compiles the regular expression with the options given.
+    ///
+    /// If an invalid expression is given, then an error is returned.
+    pub fn build(&self) -> Result<Regexp, parse::Error> {
+        let ast = simplify::simplify(try!(parse::parse(self.re.as_slice())));
+        let (mut prog, names) = Program::new(ast);
+        prog.longest = self.longest;
+        let named = named_groups(names.as_slice());
+        Ok(Regexp {
+            original: self.re.clone(),
+            names: names,
+            named: named,
+            p: Dynamic(prog, Arc::new(Mutex::new(None))),
+        })
     }
 }
 
 impl Regexp {
     /// Returns true if and only if the regexp matches the string given.
     pub fn is_match(&self, text: &str) -> bool {
-        has_match(&exec(self, Exists, text))
+        has_match(&exec(self, Exists, Unanchored, text))
     }
 
-    /// Returns the start and end byte range of the leftmost-first match in
-    /// `text`. If no match exists, then `None` is returned.
+    /// Returns true if and only if the regexp matches the string given at or
+    /// after the byte index `pos`. This is like searching
+    /// `text.slice_from(pos)`, except that assertions like `^` and `\b` still
+    /// see the text before `pos`.
     ///
-    /// Note that this should only be used if you want to discover the position
-    /// of the match. Testing the existence of a match is faster if you use
-    /// `is_match`.
-    pub fn find(&self, text: &str) -> Option<(uint, uint)> {
-        let caps = exec(self, Location, text);
+    /// `pos` must be at a UTF8 codepoint boundary. If it's past the end of
+    /// `text`, then there is no match.
+    pub fn is_match_at(&self, text: &str, pos: uint) -> bool {
+        has_match(&exec_slice(self, Exists, Unanchored, text, pos, text.len()))
+    }
+
+    /// Returns the end byte index of the first match found in `text`, i.e.,
+    /// the match that ends first. If no match exists, then `None` is
+    /// returned.
+    ///
+    /// The search stops as soon as a match is found, so this is faster than
+    /// `find` when only the end of a match is needed. But the end may be
+    /// before the end of the leftmost-first match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new("a+").unwrap();
+    /// assert_eq!(re.shortest_match("xaaa"), Some(2));
+    /// assert_eq!(re.find("xaaa").unwrap().range(), (1, 4));
+    /// ```
+    pub fn shortest_match(&self, text: &str) -> Option<uint> {
+        let caps = exec(self, Shortest, Unanchored, text);
         if has_match(&caps) {
-            Some((caps.get(0).unwrap(), caps.get(1).unwrap()))
+            Some(caps.get(1).unwrap())
         } else {
             None
         }
     }
 
+    /// Returns the leftmost-first match in `text`. If no match exists, then
+    /// `None` is returned.
+    ///
+    /// Note that this should only be used if you want to discover the position
+    /// of the match. Testing the existence of a match is faster if you use
+    /// `is_match`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"\d+").unwrap();
+    /// let m = re.find("abc 123").unwrap();
+    /// assert_eq!(m.as_str(), "123");
+    /// assert_eq!(m.range(), (4, 7));
+    /// ```
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        Match::new(text, exec(self, Location, Unanchored, text))
+    }
+
+    /// Returns the start and end byte range of the leftmost-first match in
+    /// `text`. This is the same as `find`, but returns a tuple instead of a
+    /// `Match`.
+    pub fn find_pos(&self, text: &str) -> Option<(uint, uint)> {
+        self.find(text).map(|m| m.range())
+    }
+
+    /// Returns the leftmost-first match in `text` that starts at or after
+    /// the byte index `pos`. Its range is with respect to `text` (not to
+    /// `pos`).
+    ///
+    /// Unlike searching `text.slice_from(pos)`, assertions see the text
+    /// before `pos`, so they work the same as they do for `find_iter`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"\b\w+").unwrap();
+    /// assert_eq!(re.find_at("foo bar", 1).unwrap().range(), (4, 7));
+    /// ```
+    pub fn find_at<'t>(&self, text: &'t str, pos: uint) -> Option<Match<'t>> {
+        let caps = exec_slice(self, Location, Unanchored, text,
+                              pos, text.len());
+        Match::new(text, caps)
+    }
+
     /// Returns an iterator for each successive non-overlapping match in
-    /// `text`, returning the start and end byte indices with respect to
-    /// `text`.
+    /// `text`. The start and end byte indices of each match are with respect
+    /// to `text`.
     pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> FindMatches<'r, 't> {
         FindMatches {
-            re: self,
+            matcher: self.matcher(),
             search: text,
             last_end: 0,
             last_match: None,
         }
     }
 
+    /// Returns an iterator for each successive non-overlapping match in
+    /// `text`, returning the start and end byte indices with respect to
+    /// `text`. This is the same as `find_iter`, but yields tuples instead of
+    /// `Match` values.
+    pub fn find_iter_pos<'r, 't>(&'r self, text: &'t str)
+                                -> FindMatchesPos<'r, 't> {
+        FindMatchesPos { finder: self.find_iter(text) }
+    }
+
+    /// Returns the last match in `text`, found by searching backwards from
+    /// the end of `text`. If no match exists, then `None` is returned.
+    ///
+    /// The match found is the one that ends last. Where it starts is decided
+    /// by reading the expression backwards too, e.g., `a+?` finds only the
+    /// last `a` in `aaa`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"\d+").unwrap();
+    /// assert_eq!(re.rfind("10 200 3000 x").unwrap().range(), (7, 11));
+    /// ```
+    pub fn rfind<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.rfind_iter(text).next()
+    }
+
+    /// Returns an iterator for each successive non-overlapping match in
+    /// `text`, starting with the last match and searching backwards.
+    /// The start and end byte indices are with respect to `text`.
+    ///
+    /// The first time that this is called, a dynamic regexp compiles its
+    /// reversed program.
+    pub fn rfind_iter<'r, 't>(&'r self, text: &'t str)
+                             -> RFindMatches<'r, 't> {
+        let rev = match self.p {
+            Dynamic(_, ref cache) => {
+                let rev = reversed(self, &**cache);
+                let scratch = vm::Scratch::for_kind(&*rev, Location);
+                Some((rev, scratch))
+            }
+            Native(..) => None,
+        };
+        RFindMatches {
+            re: self,
+            rev: rev,
+            search: text,
+            last_start: Some(text.len()),
+            last_match: None,
+        }
+    }
+
     /// Returns the capture groups corresponding to the leftmost-first
     /// match in `text`. Capture group `0` always corresponds to the entire
     /// match. If no match is found, then `None` is returned.
@@ -198,23 +476,185 @@ impl Regexp {
     /// Otherwise, `find` is faster for discovering the location of the overall
     /// match.
     pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
-        let caps = exec(self, Submatches, text);
+        let caps = exec(self, Submatches, Unanchored, text);
         Captures::new(self, text, caps)
     }
 
+    /// Returns the capture groups corresponding to the leftmost-first match
+    /// in `text` that starts at or after the byte index `pos`. Like
+    /// `find_at`, assertions see the text before `pos`, and the locations of
+    /// the groups are with respect to `text`.
+    pub fn captures_at<'t>(&self, text: &'t str, pos: uint)
+                          -> Option<Captures<'t>> {
+        let caps = exec_slice(self, Submatches, Unanchored, text,
+                              pos, text.len());
+        Captures::new(self, text, caps)
+    }
+
+    /// Returns a buffer with room for the locations of every capture group in
+    /// this regexp. It can be filled by `read_captures_at` any number of
+    /// times.
+    pub fn capture_locations(&self) -> CaptureLocations {
+        CaptureLocations {
+            locs: Vec::from_elem(num_locs(self, Submatches), None),
+        }
+    }
+
+    /// Searches for the leftmost-first match in `text` that starts at or
+    /// after the byte index `start`, like `captures_at`. But instead of
+    /// allocating the locations of its capture groups, they're written into
+    /// `locs`, which must have been returned by `capture_locations` for this
+    /// regexp. The entire match is returned, if there is one.
+    ///
+    /// This fails if `locs` has room for a different number of capture
+    /// groups than this regexp has.
+    ///
+    /// Searching with a dynamic regexp still allocates the memory that the
+    /// VM needs. Use a `Matcher` to reuse that too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"(\w)=(\d+)").unwrap();
+    /// let mut locs = re.capture_locations();
+    /// let m = re.read_captures_at(&mut locs, "a=1 b=22", 1).unwrap();
+    /// assert_eq!(m.as_str(), "b=22");
+    /// assert_eq!(locs.pos(2), Some((6, 8)));
+    /// ```
+    pub fn read_captures_at<'t>(&self, locs: &mut CaptureLocations,
+                                text: &'t str, start: uint)
+                               -> Option<Match<'t>> {
+        check_locs(self, locs);
+        read_slice(self, Submatches, Unanchored, locs.locs.as_mut_slice(),
+                   text, start, text.len());
+        locs.pos(0).map(|(s, e)| Match { text: text, start: s, end: e })
+    }
+
     /// Returns an iterator over all the non-overlapping capture groups matched
     /// in `text`. This is operationally the same as `find_iter` (except it
     /// yields information about submatches).
     pub fn captures_iter<'r, 't>(&'r self, text: &'t str)
                                 -> FindCaptures<'r, 't> {
         FindCaptures {
-            re: self,
+            matcher: self.matcher(),
             search: text,
             last_match: None,
             last_end: 0,
         }
     }
 
+    /// Returns true if and only if the regexp matches at the start of
+    /// `text`. This is like adding `\A` to the beginning of the regexp,
+    /// except that the regexp doesn't have to be changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"\d+").unwrap();
+    /// assert!(re.is_match_at_start("123 abc"));
+    /// assert!(!re.is_match_at_start("abc 123"));
+    /// ```
+    pub fn is_match_at_start(&self, text: &str) -> bool {
+        has_match(&exec(self, Exists, AnchorStart, text))
+    }
+
+    /// Returns the leftmost-first match that starts at the start of `text`
+    /// (so its start is always `0`). If no match exists, then `None` is
+    /// returned.
+    pub fn find_at_start<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        Match::new(text, exec(self, Location, AnchorStart, text))
+    }
+
+    /// Returns the capture groups corresponding to the leftmost-first match
+    /// that starts at the start of `text`. If no match is found, then `None`
+    /// is returned.
+    pub fn captures_at_start<'t>(&self, text: &'t str)
+                                -> Option<Captures<'t>> {
+        let caps = exec(self, Submatches, AnchorStart, text);
+        Captures::new(self, text, caps)
+    }
+
+    /// Returns true if and only if the regexp matches all of `text`. This is
+    /// like adding `\A` to the beginning of the regexp and `\z` to its end,
+    /// except that the regexp doesn't have to be changed (so, e.g., `(?m)`
+    /// doesn't change what it means).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"a|ab").unwrap();
+    /// assert!(re.is_full_match("ab"));
+    /// assert!(!re.is_full_match("abc"));
+    /// ```
+    pub fn is_full_match(&self, text: &str) -> bool {
+        has_match(&exec(self, Exists, AnchorBoth, text))
+    }
+
+    /// Returns the capture groups corresponding to a match of all of
+    /// `text`. (It's the leftmost-first of such matches, which only matters
+    /// for the submatches.) If there is no such match, then `None` is
+    /// returned.
+    pub fn captures_full<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        let caps = exec(self, Submatches, AnchorBoth, text);
+        Captures::new(self, text, caps)
+    }
+
+    /// Returns an iterator over the names of the capture groups in order of
+    /// appearance in the regular expression (starting with the zeroth
+    /// group, which is the entire match). Unnamed groups yield `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"(?P<y>\d{4})-(\d{2})").unwrap();
+    /// let names: Vec<Option<&str>> = re.capture_names().collect();
+    /// assert_eq!(names, vec!(None, Some("y"), None));
+    /// ```
+    pub fn capture_names<'r>(&'r self) -> CaptureNames<'r> {
+        CaptureNames { names: self.names.iter() }
+    }
+
+    /// Returns the number of capture groups in the regular expression,
+    /// including the zeroth group (the entire match).
+    pub fn captures_len(&self) -> uint {
+        self.names.len()
+    }
+
+    /// Returns the index of the capture group named `name`, or `None` if
+    /// there isn't one.
+    pub fn capture_index(&self, name: &str) -> Option<uint> {
+        name_index(&self.named, name)
+    }
+
+    /// Returns a `Matcher` for this regexp, which can be used to search any
+    /// number of strings without allocating memory for every search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"\d+").unwrap();
+    /// let mut matcher = re.matcher();
+    /// for line in ["a1", "b", "c22"].iter() {
+    ///     if matcher.is_match(*line) {
+    ///         println!("{}", matcher.find(*line).unwrap().range());
+    ///     }
+    /// }
+    /// ```
+    pub fn matcher<'r>(&'r self) -> Matcher<'r> {
+        Matcher {
+            re: self,
+            scratch: match self.p {
+                Dynamic(ref prog, _) => Some(vm::Scratch::new(prog)),
+                Native(..) => None,
+            },
+        }
+    }
+
     /// Returns an iterator of substrings of `text` delimited by a match
     /// of the regular expression.
     /// Namely, each element of the iterator corresponds to text that *isn't*
@@ -409,6 +849,184 @@ impl Regexp {
     }
 }
 
+/// Matcher searches text with a particular regular expression, reusing the
+/// memory that a search needs from one search to the next. (Searching with
+/// the methods on `Regexp` allocates it again for every search.)
+///
+/// A `Matcher` is created with the `matcher` method on `Regexp`. It can't be
+/// shared, but any number of them can be created for the same `Regexp`.
+///
+/// The memory is allocated once, when the matcher is created, and reused by
+/// every search after that. It only grows when a search needs more room for
+/// capture groups than the earlier searches did.
+///
+/// `'r` is the lifetime of the compiled expression.
+pub struct Matcher<'r> {
+    re: &'r Regexp,
+    // This is `None` for native regexps, which don't allocate.
+    scratch: Option<vm::Scratch>,
+}
+
+impl<'r> Matcher<'r> {
+    /// Returns the regexp that this matcher searches with.
+    pub fn regexp(&self) -> &'r Regexp {
+        self.re
+    }
+
+    /// Returns true if and only if the regexp matches the string given.
+    /// This is the same as `Regexp::is_match`.
+    pub fn is_match(&mut self, text: &str) -> bool {
+        has_match(&self.exec(Exists, Unanchored, text, 0, text.len()))
+    }
+
+    /// Returns the leftmost-first match in `text`. This is the same as
+    /// `Regexp::find`.
+    pub fn find<'t>(&mut self, text: &'t str) -> Option<Match<'t>> {
+        let caps = self.exec(Location, Unanchored, text, 0, text.len());
+        Match::new(text, caps)
+    }
+
+    /// Returns the capture groups corresponding to the leftmost-first
+    /// match in `text`. This is the same as `Regexp::captures`.
+    pub fn captures<'t>(&mut self, text: &'t str) -> Option<Captures<'t>> {
+        let caps = self.exec(Submatches, Unanchored, text, 0, text.len());
+        Captures::new(self.re, text, caps)
+    }
+
+    /// Reads the capture groups of the leftmost-first match in `text` that
+    /// starts at or after the byte index `start` into `locs`. This is the
+    /// same as `Regexp::read_captures_at`, except that the memory that the
+    /// VM needs isn't allocated either.
+    pub fn read_captures_at<'t>(&mut self, locs: &mut CaptureLocations,
+                                text: &'t str, start: uint)
+                               -> Option<Match<'t>> {
+        check_locs(self.re, locs);
+        self.read(Submatches, Unanchored, locs.locs.as_mut_slice(),
+                  text, start, text.len());
+        locs.pos(0).map(|(s, e)| Match { text: text, start: s, end: e })
+    }
+
+    fn exec(&mut self, which: MatchKind, anchor: Anchor,
+            input: &str, s: uint, e: uint) -> CaptureLocs {
+        match (&self.re.p, &mut self.scratch) {
+            (&Dynamic(ref prog, _), &Some(ref mut scratch)) => {
+                vm::run_with(which, anchor, prog, scratch, input, s, e)
+            }
+            _ => exec_slice(self.re, which, anchor, input, s, e),
+        }
+    }
+
+    fn read(&mut self, which: MatchKind, anchor: Anchor,
+            locs: &mut [Option<uint>], input: &str, s: uint, e: uint) {
+        match (&self.re.p, &mut self.scratch) {
+            (&Dynamic(ref prog, _), &Some(ref mut scratch)) => {
+                vm::run_into(which, anchor, prog, scratch, locs, input, s, e)
+            }
+            _ => read_slice(self.re, which, anchor, locs, input, s, e),
+        }
+    }
+}
+
+/// Scanner reads a string from left to right by matching regexps where the
+/// last match left off, like Ruby's `StringScanner`. It's handy for writing
+/// lexers.
+///
+/// Matches are always found in the whole string, so assertions like `^` and
+/// `\b` see the text before the scanner's position.
+///
+/// # Example
+///
+/// ```rust
+/// # use regexp::{Regexp, Scanner};
+/// let (num, op, space) = (Regexp::new(r"\d+").unwrap(),
+///                         Regexp::new(r"[-+*/]").unwrap(),
+///                         Regexp::new(r"\s*").unwrap());
+/// let mut scanner = Scanner::new("1 + 23");
+/// assert_eq!(scanner.scan(&num), Some("1"));
+/// scanner.scan(&space);
+/// assert_eq!(scanner.scan(&num), None);
+/// assert_eq!(scanner.scan(&op), Some("+"));
+/// assert_eq!(scanner.rest(), " 23");
+/// ```
+///
+/// `'t` is the lifetime of the string being scanned.
+pub struct Scanner<'t> {
+    text: &'t str,
+    pos: uint,
+}
+
+impl<'t> Scanner<'t> {
+    /// Returns a scanner positioned at the start of `text`.
+    pub fn new(text: &'t str) -> Scanner<'t> {
+        Scanner { text: text, pos: 0 }
+    }
+
+    /// If `re` matches at the current position, then the position advances
+    /// to the end of the match and the text matched is returned. Otherwise,
+    /// `None` is returned and the position doesn't change.
+    ///
+    /// Note that an empty match doesn't advance the position.
+    pub fn scan(&mut self, re: &Regexp) -> Option<&'t str> {
+        match self.match_here(re) {
+            None => None,
+            Some(end) => {
+                let matched = self.text.slice(self.pos, end);
+                self.pos = end;
+                Some(matched)
+            }
+        }
+    }
+
+    /// Returns the text that `scan` would match, without advancing.
+    pub fn check(&self, re: &Regexp) -> Option<&'t str> {
+        self.match_here(re).map(|end| self.text.slice(self.pos, end))
+    }
+
+    /// Searches for `re` at or after the current position. If a match is
+    /// found, then the position advances to the end of it and the number of
+    /// bytes advanced over is returned. Otherwise, `None` is returned and the
+    /// position doesn't change.
+    pub fn skip_until(&mut self, re: &Regexp) -> Option<uint> {
+        let caps = exec_slice(re, Location, Unanchored,
+                              self.text, self.pos, self.text.len());
+        if !has_match(&caps) {
+            return None
+        }
+        let end = caps.get(1).unwrap();
+        let skipped = end - self.pos;
+        self.pos = end;
+        Some(skipped)
+    }
+
+    /// Returns the text that hasn't been scanned yet.
+    pub fn rest(&self) -> &'t str {
+        self.text.slice_from(self.pos)
+    }
+
+    /// Returns the current position, as a byte index into the string being
+    /// scanned.
+    pub fn pos(&self) -> uint {
+        self.pos
+    }
+
+    /// Returns true if and only if the whole string has been scanned.
+    pub fn is_eos(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    // Returns the end of the match of `re` that starts at the current
+    // position, if there is one.
+    fn match_here(&self, re: &Regexp) -> Option<uint> {
+        let caps = exec_slice(re, Location, AnchorStart,
+                              self.text, self.pos, self.text.len());
+        if has_match(&caps) {
+            Some(caps.get(1).unwrap())
+        } else {
+            None
+        }
+    }
+}
+
 /// NoExpand indicates literal string replacement.
 ///
 /// It can be used with `replace` and `replace_all` to do a literal
@@ -469,9 +1087,9 @@ impl<'r, 't> Iterator<&'t str> for RegexpSplits<'r, 't> {
                     Some(s)
                 }
             }
-            Some((s, e)) => {
-                let matched = text.slice(self.last, s);
-                self.last = e;
+            Some(m) => {
+                let matched = text.slice(self.last, m.start());
+                self.last = m.end();
                 Some(matched)
             }
         }
@@ -506,6 +1124,80 @@ impl<'r, 't> Iterator<&'t str> for RegexpSplitsN<'r, 't> {
     }
 }
 
+/// Match represents a single match of a regexp in some text: the start and
+/// end byte indices of the match, along with the text that they index into.
+///
+/// `'t` is the lifetime of the matched text.
+#[deriving(Clone, Eq, Show)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: uint,
+    end: uint,
+}
+
+impl<'t> Match<'t> {
+    // Returns the match at the first pair of locations in `locs`, if there
+    // is one.
+    fn new(text: &'t str, locs: CaptureLocs) -> Option<Match<'t>> {
+        if !has_match(&locs) {
+            return None
+        }
+        Some(Match {
+            text: text,
+            start: locs.get(0).unwrap(),
+            end: locs.get(1).unwrap(),
+        })
+    }
+
+    /// Returns the byte index of the start of the match.
+    pub fn start(&self) -> uint {
+        self.start
+    }
+
+    /// Returns the byte index of the end of the match.
+    pub fn end(&self) -> uint {
+        self.end
+    }
+
+    /// Returns the start and end byte indices of the match.
+    pub fn range(&self) -> (uint, uint) {
+        (self.start, self.end)
+    }
+
+    /// Returns the matched text.
+    pub fn as_str(&self) -> &'t str {
+        self.text.slice(self.start, self.end)
+    }
+}
+
+/// CaptureLocations is a buffer for the locations of a regexp's capture
+/// groups, which `read_captures_at` fills in place. It's created by
+/// `Regexp::capture_locations`.
+///
+/// Positions are always byte indices into the text of the last search.
+#[deriving(Clone)]
+pub struct CaptureLocations {
+    locs: CaptureLocs,
+}
+
+impl CaptureLocations {
+    /// Returns the start and end positions of the Nth capture group in the
+    /// last match found.
+    /// Returns `None` if `i` is not a valid capture group, if the capture
+    /// group did not match anything or if the last search found no match.
+    pub fn pos(&self, i: uint) -> Option<(uint, uint)> {
+        group_pos(self.locs.as_slice(), i)
+    }
+}
+
+impl Container for CaptureLocations {
+    /// Returns the number of capture groups.
+    #[inline]
+    fn len(&self) -> uint {
+        self.locs.len() / 2
+    }
+}
+
 /// Captures represents a group of captured strings for a single match.
 ///
 /// The 0th capture always corresponds to the entire match. Each subsequent
@@ -520,7 +1212,7 @@ impl<'r, 't> Iterator<&'t str> for RegexpSplitsN<'r, 't> {
 pub struct Captures<'t> {
     text: &'t str,
     locs: CaptureLocs,
-    named: Option<HashMap<~str, uint>>,
+    named: Option<NamedGroups>,
 }
 
 impl<'t> Captures<'t> {
@@ -530,25 +1222,10 @@ impl<'t> Captures<'t> {
             return None
         }
 
-        let named =
-            if re.names.len() == 0 {
-                None
-            } else {
-                let mut named = HashMap::new();
-                for (i, name) in re.names.iter().enumerate() {
-                    match name {
-                        &None => {},
-                        &Some(ref name) => {
-                            named.insert(name.to_owned(), i);
-                        }
-                    }
-                }
-                Some(named)
-            };
         Some(Captures {
             text: search,
             locs: locs,
-            named: named,
+            named: re.named.clone(),
         })
     }
 
@@ -558,39 +1235,53 @@ impl<'t> Captures<'t> {
     /// The positions returned are *always* byte indices with respect to the
     /// original string matched.
     pub fn pos(&self, i: uint) -> Option<(uint, uint)> {
-        let (s, e) = (i * 2, i * 2 + 1);
-        if e >= self.locs.len() || self.locs.get(s).is_none() {
-            // VM guarantees that each pair of locations are both Some or None.
-            return None
-        }
-        Some((self.locs.get(s).unwrap(), self.locs.get(e).unwrap()))
+        group_pos(self.locs.as_slice(), i)
+    }
+
+    /// Returns the match of the Nth capture group.
+    /// Returns `None` if `i` is not a valid capture group or if the capture
+    /// group did not match anything.
+    pub fn submatch(&self, i: uint) -> Option<Match<'t>> {
+        self.pos(i).map(|(s, e)| Match { text: self.text, start: s, end: e })
     }
 
     /// Returns the matched string for the capture group `i`.
     /// If `i` isn't a valid capture group or didn't match anything, then the
     /// empty string is returned.
     pub fn at(&self, i: uint) -> &'t str {
-        match self.pos(i) {
-            None => "",
-            Some((s, e)) => {
-                self.text.slice(s, e)
-            }
-        }
+        self.get(i).unwrap_or("")
+    }
+
+    /// Returns the matched string for the capture group `i`.
+    /// Returns `None` if `i` isn't a valid capture group or if the capture
+    /// group didn't match anything. (Unlike `at`, this tells those apart
+    /// from a capture group that matched the empty string.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"(\w+)(:\d*)?").unwrap();
+    /// assert_eq!(re.captures("host:").unwrap().get(2), Some(":"));
+    /// assert_eq!(re.captures("host").unwrap().get(2), None);
+    /// ```
+    pub fn get(&self, i: uint) -> Option<&'t str> {
+        self.pos(i).map(|(s, e)| self.text.slice(s, e))
     }
 
     /// Returns the matched string for the capture group named `name`.
     /// If `name` isn't a valid capture group or didn't match anything, then
     /// the empty string is returned.
     pub fn name(&self, name: &str) -> &'t str {
-        match self.named {
-            None => "",
-            Some(ref h) => {
-                match h.find(&name.to_owned()) {
-                    None => "",
-                    Some(i) => self.at(*i),
-                }
-            }
-        }
+        self.get_name(name).unwrap_or("")
+    }
+
+    /// Returns the matched string for the capture group named `name`.
+    /// Returns `None` if `name` isn't a valid capture group or if the
+    /// capture group didn't match anything. (Unlike `name`, this tells those
+    /// apart from a capture group that matched the empty string.)
+    pub fn get_name(&self, name: &str) -> Option<&'t str> {
+        name_index(&self.named, name).and_then(|i| self.get(i))
     }
 
     /// Creates an iterator of all the capture groups in order of appearance
@@ -606,6 +1297,60 @@ impl<'t> Captures<'t> {
         SubCapturesPos { idx: 0, caps: self, }
     }
 
+    /// Creates an iterator of the matches of all the capture groups in order
+    /// of appearance in the regular expression. A capture group that didn't
+    /// match anything yields `None`.
+    pub fn iter_submatches(&'t self) -> SubMatches<'t> {
+        SubMatches { idx: 0, caps: self, }
+    }
+
+    /// Creates an iterator of the names of the named capture groups along
+    /// with the strings that they matched, in order of appearance in the
+    /// regular expression. A group that didn't match anything yields `None`
+    /// (so it can be told apart from one that matched the empty string).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regexp::Regexp;
+    /// let re = Regexp::new(r"(?P<host>[a-z]+)(?P<port>:\d+)?").unwrap();
+    /// let caps = re.captures("localhost").unwrap();
+    /// let named: Vec<(&str, Option<&str>)> = caps.iter_named().collect();
+    /// assert_eq!(named, vec!(("host", Some("localhost")), ("port", None)));
+    /// ```
+    pub fn iter_named(&'t self) -> SubCapturesNamed<'t> {
+        let mut named: Vec<(&'t str, uint)> = match self.named {
+            None => vec!(),
+            Some(ref named) => {
+                range(0, named.len()).map(|i| named.get(i)).collect()
+            }
+        };
+        named.as_mut_slice().sort_by(|&(_, i), &(_, j)| i.cmp(&j));
+        SubCapturesNamed { named: named.move_iter(), caps: self }
+    }
+
+    /// Returns the strings matched by the named capture groups, keyed by
+    /// their names. A group that didn't match anything isn't in the map,
+    /// while a group that matched the empty string is.
+    pub fn to_map(&self) -> HashMap<~str, ~str> {
+        let mut map = HashMap::new();
+        match self.named {
+            None => {}
+            Some(ref named) => {
+                for k in range(0, named.len()) {
+                    let (name, i) = named.get(k);
+                    match self.get(i) {
+                        None => {}
+                        Some(matched) => {
+                            map.insert(name.to_owned(), matched.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+        map
+    }
+
     /// Expands all instances of `$name` in `text` to the corresponding capture
     /// group `name`.
     ///
@@ -661,6 +1406,19 @@ impl<'t> Iterator<&'t str> for SubCaptures<'t> {
     }
 }
 
+/// An iterator over the names of the capture groups of a regular expression.
+///
+/// `'r` is the lifetime of the compiled expression.
+pub struct CaptureNames<'r> {
+    names: slice::Items<'r, Option<~str>>,
+}
+
+impl<'r> Iterator<Option<&'r str>> for CaptureNames<'r> {
+    fn next(&mut self) -> Option<Option<&'r str>> {
+        self.names.next().map(|name| name.as_ref().map(|name| name.as_slice()))
+    }
+}
+
 /// An iterator over capture group positions for a particular match of a
 /// regular expression.
 ///
@@ -683,6 +1441,44 @@ impl<'t> Iterator<Option<(uint, uint)>> for SubCapturesPos<'t> {
     }
 }
 
+/// An iterator over the names of the named capture groups for a particular
+/// match of a regular expression, along with the strings that they matched.
+///
+/// `'t` is the lifetime of the matched text.
+pub struct SubCapturesNamed<'t> {
+    named: vec::MoveItems<(&'t str, uint)>,
+    caps: &'t Captures<'t>,
+}
+
+impl<'t> Iterator<(&'t str, Option<&'t str>)> for SubCapturesNamed<'t> {
+    fn next(&mut self) -> Option<(&'t str, Option<&'t str>)> {
+        match self.named.next() {
+            None => None,
+            Some((name, i)) => Some((name, self.caps.get(i))),
+        }
+    }
+}
+
+/// An iterator over the matches of the capture groups for a particular match
+/// of a regular expression.
+///
+/// `'t` is the lifetime of the matched text.
+pub struct SubMatches<'t> {
+    idx: uint,
+    caps: &'t Captures<'t>,
+}
+
+impl<'t> Iterator<Option<Match<'t>>> for SubMatches<'t> {
+    fn next(&mut self) -> Option<Option<Match<'t>>> {
+        if self.idx < self.caps.len() {
+            self.idx += 1;
+            Some(self.caps.submatch(self.idx - 1))
+        } else {
+            None
+        }
+    }
+}
+
 /// An iterator that yields all non-overlapping capture groups matching a
 /// particular regular expression. The iterator stops when no more matches can
 /// be found.
@@ -690,7 +1486,7 @@ impl<'t> Iterator<Option<(uint, uint)>> for SubCapturesPos<'t> {
 /// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
 /// of the matched string.
 pub struct FindCaptures<'r, 't> {
-    re: &'r Regexp,
+    matcher: Matcher<'r>,
     search: &'t str,
     last_match: Option<uint>,
     last_end: uint,
@@ -702,8 +1498,8 @@ impl<'r, 't> Iterator<Captures<'t>> for FindCaptures<'r, 't> {
             return None
         }
 
-        let caps = exec_slice(self.re, Submatches, self.search,
-                              self.last_end, self.search.len());
+        let caps = self.matcher.exec(Submatches, Unanchored, self.search,
+                                     self.last_end, self.search.len());
         let (s, e) =
             if !has_match(&caps) {
                 return None
@@ -719,33 +1515,32 @@ impl<'r, 't> Iterator<Captures<'t>> for FindCaptures<'r, 't> {
         }
         self.last_end = e;
         self.last_match = Some(self.last_end);
-        Captures::new(self.re, self.search, caps)
+        Captures::new(self.matcher.re, self.search, caps)
     }
 }
 
 /// An iterator over all non-overlapping matches for a particular string.
 ///
-/// The iterator yields a tuple of integers corresponding to the start and end
-/// of the match. The indices are byte offsets. The iterator stops when no more
-/// matches can be found.
+/// The iterator yields a `Match` for each match. The iterator stops when no
+/// more matches can be found.
 ///
 /// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
 /// of the matched string.
 pub struct FindMatches<'r, 't> {
-    re: &'r Regexp,
+    matcher: Matcher<'r>,
     search: &'t str,
     last_match: Option<uint>,
     last_end: uint,
 }
 
-impl<'r, 't> Iterator<(uint, uint)> for FindMatches<'r, 't> {
-    fn next(&mut self) -> Option<(uint, uint)> {
+impl<'r, 't> Iterator<Match<'t>> for FindMatches<'r, 't> {
+    fn next(&mut self) -> Option<Match<'t>> {
         if self.last_end > self.search.len() {
             return None
         }
 
-        let caps = exec_slice(self.re, Location, self.search,
-                              self.last_end, self.search.len());
+        let caps = self.matcher.exec(Location, Unanchored, self.search,
+                                     self.last_end, self.search.len());
         let (s, e) =
             if !has_match(&caps) {
                 return None
@@ -761,20 +1556,151 @@ impl<'r, 't> Iterator<(uint, uint)> for FindMatches<'r, 't> {
         }
         self.last_end = e;
         self.last_match = Some(self.last_end);
-        Some((s, e))
+        Some(Match { text: self.search, start: s, end: e })
+    }
+}
+
+/// An iterator over all non-overlapping matches for a particular string.
+///
+/// The iterator yields a tuple of integers corresponding to the start and end
+/// of the match. The indices are byte offsets. The iterator stops when no more
+/// matches can be found.
+///
+/// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
+/// of the matched string.
+pub struct FindMatchesPos<'r, 't> {
+    finder: FindMatches<'r, 't>,
+}
+
+impl<'r, 't> Iterator<(uint, uint)> for FindMatchesPos<'r, 't> {
+    fn next(&mut self) -> Option<(uint, uint)> {
+        self.finder.next().map(|m| m.range())
+    }
+}
+
+/// An iterator over all non-overlapping matches for a particular string,
+/// from the last match to the first.
+///
+/// The iterator yields a `Match` for each match. The iterator stops when no
+/// more matches can be found.
+///
+/// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
+/// of the matched string.
+pub struct RFindMatches<'r, 't> {
+    re: &'r Regexp,
+    // The reversed program of a dynamic regexp and the memory to run it.
+    rev: Option<(Arc<Program>, vm::Scratch)>,
+    search: &'t str,
+    last_match: Option<uint>,
+    last_start: Option<uint>,
+}
+
+impl<'r, 't> Iterator<Match<'t>> for RFindMatches<'r, 't> {
+    fn next(&mut self) -> Option<Match<'t>> {
+        let end = match self.last_start {
+            None => return None,
+            Some(end) => end,
+        };
+        let caps = match (&self.re.p, &mut self.rev) {
+            (_, &Some((ref prog, ref mut scratch))) => {
+                vm::run_rev(Location, &**prog, scratch, self.search, 0, end)
+            }
+            (&Native(_, exec_rev), &None) => {
+                let mut locs = Vec::from_elem(2, None);
+                exec_rev(Location, Unanchored, locs.as_mut_slice(),
+                         self.search, 0, end);
+                locs
+            }
+            (&Dynamic(..), &None) => unreachable!(),
+        };
+        let (s, e) =
+            if !has_match(&caps) {
+                self.last_start = None;
+                return None
+            } else {
+                (caps.get(0).unwrap(), caps.get(1).unwrap())
+            };
+
+        // Don't accept empty matches immediately preceding a match.
+        if e - s == 0 && Some(end) == self.last_match {
+            self.last_start =
+                if end == 0 {
+                    None
+                } else {
+                    Some(self.search.char_range_at_reverse(end).next)
+                };
+            return self.next()
+        }
+        self.last_start = Some(s);
+        self.last_match = Some(s);
+        Some(Match { text: self.search, start: s, end: e })
     }
 }
 
-fn exec(re: &Regexp, which: MatchKind, input: &str) -> CaptureLocs {
-    exec_slice(re, which, input, 0, input.len())
+fn exec(re: &Regexp, which: MatchKind, anchor: Anchor,
+        input: &str) -> CaptureLocs {
+    exec_slice(re, which, anchor, input, 0, input.len())
 }
 
-fn exec_slice(re: &Regexp, which: MatchKind,
+fn exec_slice(re: &Regexp, which: MatchKind, anchor: Anchor,
               input: &str, s: uint, e: uint) -> CaptureLocs {
+    let mut locs = Vec::from_elem(num_locs(re, which), None);
+    read_slice(re, which, anchor, locs.as_mut_slice(), input, s, e);
+    locs
+}
+
+// Like `exec_slice`, but writes the capture locations into `locs`, which
+// must have room for `num_locs(re, which)` of them.
+fn read_slice(re: &Regexp, which: MatchKind, anchor: Anchor,
+              locs: &mut [Option<uint>], input: &str, s: uint, e: uint) {
     match re.p {
-        Dynamic(ref prog) => vm::run(which, prog, input, s, e),
-        Native(exec) => exec(which, input, s, e),
+        Dynamic(ref prog, _) => {
+            let mut scratch = vm::Scratch::for_kind(prog, which);
+            vm::run_into(which, anchor, prog, &mut scratch, locs, input, s, e)
+        }
+        Native(exec, _) => exec(which, anchor, locs, input, s, e),
+    }
+}
+
+// Returns the reversed program of `re`, which is compiled into `cache` the
+// first time that it's needed.
+fn reversed(re: &Regexp,
+            cache: &Mutex<Option<Arc<Program>>>) -> Arc<Program> {
+    let mut rev = cache.lock();
+    if (*rev).is_none() {
+        *rev = Some(Arc::new(reversed_program(re.original.as_slice())));
+    }
+    (*rev).get_ref().clone()
+}
+
+// The number of capture locations that a search of `re` reports for `which`
+// (see `vm::num_locs`).
+fn num_locs(re: &Regexp, which: MatchKind) -> uint {
+    match which {
+        Exists | Shortest | Location => 2,
+        Submatches => re.names.len() * 2,
+    }
+}
+
+// Fails unless `locs` has room for exactly the capture groups in `re`, so
+// that a buffer from another regexp can't be read past or left half filled.
+fn check_locs(re: &Regexp, locs: &CaptureLocations) {
+    let ngroups = num_locs(re, Submatches) / 2;
+    if locs.len() != ngroups {
+        fail!("CaptureLocations has room for {} capture groups, but the \
+               regexp '{}' has {}", locs.len(), re.original, ngroups)
+    }
+}
+
+// Returns the start and end positions of the Nth capture group in `locs`,
+// if it matched anything.
+fn group_pos(locs: &[Option<uint>], i: uint) -> Option<(uint, uint)> {
+    let (s, e) = (i * 2, i * 2 + 1);
+    if e >= locs.len() || locs[s].is_none() {
+        // VM guarantees that each pair of locations are both Some or None.
+        return None
     }
+    Some((locs[s].unwrap(), locs[e].unwrap()))
 }
 
 #[inline(always)]
@@ -0,0 +1,246 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Substring search for the literals found by literal analysis.
+//
+// The common case is handled by looking for the rarest byte of the needle
+// and checking the needle wherever it occurs. This is very fast on typical
+// text, but it is quadratic in the worst case (e.g., looking for `ab` in
+// `aaaa...`, where `a` happens to be the rarest byte of the needle). So the
+// searcher keeps track of how much time it spends checking candidates, and
+// if it isn't paying off, it switches to the Two-Way algorithm, which runs in
+// linear time with constant space.
+//
+// The Two-Way algorithm is described in "Two-way string-matching" by Maxime
+// Crochemore and Dominique Perrin (Journal of the ACM, 1991).
+
+use std::cmp;
+
+/// A substring searcher for a needle that is given to `Searcher::new`.
+///
+/// A searcher only stores what it has computed about its needle, so that
+/// it can be built as static data by the `regexp!` macro. The needle itself
+/// must be given again to every search.
+#[deriving(Show, Clone)]
+pub struct Searcher {
+    /// The index of the byte in the needle that is least likely to occur in
+    /// a haystack.
+    pub rare: uint,
+    /// The critical position of the needle (for Two-Way).
+    pub crit_pos: uint,
+    /// The period of the needle (for Two-Way).
+    pub period: uint,
+    /// Whether the period of the needle is long (for Two-Way).
+    pub long_period: bool,
+    /// A set of the bytes in the needle, indexed by their low six bits.
+    pub byteset: u64,
+}
+
+impl Searcher {
+    /// Computes a searcher for `needle`.
+    pub fn new(needle: &[u8]) -> Searcher {
+        let mut rare = 0;
+        for (i, &b) in needle.iter().enumerate() {
+            if frequency(b) < frequency(needle[rare]) {
+                rare = i
+            }
+        }
+        let byteset = needle.iter().fold(0u64, |set, &b| set | byte_bit(b));
+
+        let (crit_pos1, period1) = maximal_suffix(needle, false);
+        let (crit_pos2, period2) = maximal_suffix(needle, true);
+        let (crit_pos, period) =
+            if crit_pos1 > crit_pos2 {
+                (crit_pos1, period1)
+            } else {
+                (crit_pos2, period2)
+            };
+
+        // The needle has a short period if its left part is repeated after
+        // one period. Otherwise, any shift larger than both parts is safe.
+        let short = crit_pos + period <= needle.len()
+                    && needle.slice_to(crit_pos)
+                       == needle.slice(period, period + crit_pos);
+        Searcher {
+            rare: rare,
+            crit_pos: crit_pos,
+            period:
+                if short {
+                    period
+                } else {
+                    cmp::max(crit_pos, needle.len() - crit_pos) + 1
+                },
+            long_period: !short,
+            byteset: byteset,
+        }
+    }
+
+    /// Returns the index of the first occurrence of `needle` in `haystack`.
+    /// `needle` must be the same needle given to `Searcher::new`.
+    pub fn find(&self, needle: &[u8], haystack: &[u8]) -> Option<uint> {
+        let (nlen, hlen) = (needle.len(), haystack.len());
+        if nlen == 0 {
+            return Some(0)
+        }
+        if nlen > hlen {
+            return None
+        }
+        if nlen == 1 {
+            return memchr(needle[0], haystack, 0)
+        }
+
+        // Look for the rare byte until checking candidates costs more than
+        // the bytes that were skipped over.
+        let rare = needle[self.rare];
+        let mut pos = 0;
+        let mut work = 0;
+        while pos + nlen <= hlen {
+            if work > pos + 8 * nlen {
+                return self.two_way(needle, haystack, pos)
+            }
+            let found = match memchr(rare, haystack, pos + self.rare) {
+                None => return None,
+                Some(found) => found - self.rare,
+            };
+            if found + nlen > hlen {
+                return None
+            }
+            let mut i = 0;
+            while i < nlen && needle[i] == haystack[found + i] {
+                i += 1
+            }
+            if i == nlen {
+                return Some(found)
+            }
+            work += i + 1;
+            pos = found + 1;
+        }
+        None
+    }
+
+    // Returns the index of the first occurrence of `needle` in `haystack`
+    // at or after `pos`, using the Two-Way algorithm.
+    fn two_way(&self, needle: &[u8], haystack: &[u8], mut pos: uint)
+              -> Option<uint> {
+        let (nlen, hlen) = (needle.len(), haystack.len());
+        // For needles with a short period, this remembers how much of the
+        // left part of the needle is known to match after a shift.
+        let mut memory = 0;
+        'SEARCH: loop {
+            if pos + nlen > hlen {
+                return None
+            }
+
+            // Skip past any position whose last byte isn't in the needle.
+            if self.byteset & byte_bit(haystack[pos + nlen - 1]) == 0 {
+                pos += nlen;
+                memory = 0;
+                continue 'SEARCH
+            }
+
+            // Match the right part of the needle.
+            let start =
+                if self.long_period {
+                    self.crit_pos
+                } else {
+                    cmp::max(self.crit_pos, memory)
+                };
+            for i in range(start, nlen) {
+                if needle[i] != haystack[pos + i] {
+                    pos += i - self.crit_pos + 1;
+                    memory = 0;
+                    continue 'SEARCH
+                }
+            }
+
+            // Match the left part of the needle.
+            let start = if self.long_period { 0 } else { memory };
+            let mut i = self.crit_pos;
+            while i > start {
+                i -= 1;
+                if needle[i] != haystack[pos + i] {
+                    pos += self.period;
+                    if !self.long_period {
+                        memory = nlen - self.period;
+                    }
+                    continue 'SEARCH
+                }
+            }
+            return Some(pos)
+        }
+    }
+}
+
+// Computes the maximal suffix of `needle` (under the byte order, or under
+// the reverse of it if `reversed` is true). Returns its start position and
+// its period.
+fn maximal_suffix(needle: &[u8], reversed: bool) -> (uint, uint) {
+    // These are `i + 1`, `j`, `k` and `p` in the paper.
+    let (mut left, mut right, mut offset, mut period) = (0u, 1u, 1u, 1u);
+    while right + offset - 1 < needle.len() {
+        let (a, b) = (needle[right + offset - 1], needle[left + offset - 1]);
+        let (a, b) = if reversed { (b, a) } else { (a, b) };
+        if a < b {
+            // The suffix is smaller, so the period is the whole prefix.
+            right += offset;
+            offset = 1;
+            period = right - left;
+        } else if a == b {
+            // Advance through the repetition of the current period.
+            if offset == period {
+                right += offset;
+                offset = 1;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // The suffix is larger, so start over from here.
+            left = right;
+            right += 1;
+            offset = 1;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+// Returns the index of the first occurrence of `b` in `haystack` at or after
+// `start`.
+#[inline]
+fn memchr(b: u8, haystack: &[u8], start: uint) -> Option<uint> {
+    let mut i = start;
+    while i < haystack.len() {
+        if haystack[i] == b {
+            return Some(i)
+        }
+        i += 1;
+    }
+    None
+}
+
+#[inline]
+fn byte_bit(b: u8) -> u64 {
+    1u64 << ((b & 0x3f) as u64)
+}
+
+// A rough guess of how common a byte is in text, from 0 (rare) to 9
+// (very common).
+fn frequency(b: u8) -> uint {
+    match b as char {
+        ' ' | 'e' | 't' | 'a' | 'o' | 'i' | 'n' | 's' | 'r' | 'h' => 9,
+        'a'..'z' => 7,
+        '\n' | '\t' | '.' | ',' => 6,
+        '0'..'9' => 5,
+        'A'..'Z' => 4,
+        _ if b >= 0x80 => 3,
+        '!'..'~' => 2,
+        _ => 0,
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The simplifier rewrites an expression into one that compiles to a smaller
+// (or at least no bigger) program. The rewritten expression must be
+// equivalent in the strongest sense: every search has to report exactly the
+// same match and submatch locations. In particular, this means the
+// leftmost-first preference order of alternations and repetitions must be
+// preserved, so alternatives are only ever combined with their neighbors and
+// only reordered when they can't match at the same position.
+
+use parse;
+use parse::{
+    Ast, Expr, Span, Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_NEGATED,
+    Nothing, Literal, Class, Begin, End, WordBoundary, Capture, Cat, Alt, Rep,
+    Repeater, ZeroOne, ZeroMore, OneMore, Greed, Greedy, Ungreedy,
+};
+use syntax::{Visitor, walk};
+
+/// Returns an expression that is equivalent to `ast` and compiles to a
+/// program that is no bigger.
+///
+/// This:
+///
+/// * merges adjacent single character alternatives into one class,
+/// * factors common literal prefixes out of adjacent alternatives,
+/// * removes `Nothing` from concatenations and flattens nested
+///   concatenations and alternations,
+/// * turns repetitions of repetitions into a single repetition, and
+/// * removes repetitions of `Nothing`.
+pub fn simplify(ast: ~Ast) -> ~Ast {
+    let ~Ast { expr, span } = ast;
+    match expr {
+        Capture(i, name, x) => mk(span, Capture(i, name, simplify(x))),
+        Cat(xs) => cat(span, xs.move_iter().map(simplify).collect()),
+        Alt(x, y) => {
+            let alts = alternates(mk(span, Alt(x, y)));
+            let alts = alts.move_iter().map(simplify).collect::<Vec<~Ast>>();
+            let mut flat = Vec::with_capacity(alts.len());
+            for alt in alts.move_iter() {
+                flat.push_all_move(alternates(alt));
+            }
+            alternate(span, flat)
+        }
+        Rep(x, rep, greed) => repeat(span, simplify(x), rep, greed),
+        expr => mk(span, expr),
+    }
+}
+
+fn mk(span: Span, expr: Expr) -> ~Ast {
+    ~Ast { expr: expr, span: span }
+}
+
+// Builds a concatenation of `xs`, dropping `Nothing` and flattening nested
+// concatenations. Returns `Nothing` or the only expression left if there
+// aren't at least two.
+fn cat(span: Span, xs: Vec<~Ast>) -> ~Ast {
+    let mut items = Vec::with_capacity(xs.len());
+    for x in xs.move_iter() {
+        match x {
+            ~Ast { expr: Nothing, .. } => {}
+            ~Ast { expr: Cat(ys), .. } => items.push_all_move(ys),
+            x => items.push(x),
+        }
+    }
+    match items.len() {
+        0 => mk(span, Nothing),
+        1 => items.pop().unwrap(),
+        _ => mk(span, Cat(items)),
+    }
+}
+
+// Returns the alternatives of `ast` from left to right. (An expression that
+// isn't an alternation is its own only alternative.)
+// Both sides of an alternation are flattened, since `(a|b)|c` is the same
+// as `a|(b|c)` under leftmost-first semantics.
+fn alternates(ast: ~Ast) -> Vec<~Ast> {
+    let mut alts = vec!();
+    let mut stack = vec!(ast);
+    loop {
+        match stack.pop() {
+            None => return alts,
+            Some(~Ast { expr: Alt(x, y), .. }) => {
+                stack.push(y);
+                stack.push(x);
+            }
+            Some(x) => alts.push(x),
+        }
+    }
+}
+
+// Builds an alternation of the (already simplified) alternatives in `alts`.
+fn alternate(span: Span, alts: Vec<~Ast>) -> ~Ast {
+    let mut alts = merge_classes(factor(span, alts));
+
+    // `x|` and `|x` are just `x?` and `x??`. (Factoring can produce these.)
+    if alts.len() == 2 {
+        let first = is_nothing(alts.get(0));
+        if first != is_nothing(alts.get(1)) {
+            let x = if first {
+                alts.pop().unwrap()
+            } else {
+                alts.shift().unwrap()
+            };
+            if !is_assertion(&*x) {
+                let greed = if first { Ungreedy } else { Greedy };
+                return mk(span, Rep(x, ZeroOne, greed))
+            }
+            if first { alts.push(x) } else { alts.unshift(x) }
+        }
+    }
+
+    // Alternations nest to the right, so build from the last alternative.
+    let mut ast = match alts.pop() {
+        None => return mk(span, Nothing),
+        Some(ast) => ast,
+    };
+    while alts.len() > 0 {
+        let x = alts.pop().unwrap();
+        let span = Span { start: x.span.start, end: ast.span.end };
+        ast = mk(span, Alt(x, ast));
+    }
+    ast
+}
+
+// Factors common literal prefixes out of adjacent alternatives, e.g.,
+// `abc|abd|x` becomes `ab[cd]|x`.
+fn factor(span: Span, alts: Vec<~Ast>) -> Vec<~Ast> {
+    let mut factored = Vec::with_capacity(alts.len());
+    let mut group: Vec<Vec<~Ast>> = vec!();
+    for alt in group_literals(alts).move_iter() {
+        let items = items(alt);
+        let same = group.len() > 0
+                   && first_literal(group.get(0).as_slice()).is_some()
+                   && first_literal(group.get(0).as_slice())
+                      == first_literal(items.as_slice());
+        if !same {
+            factor_group(span, &mut factored, group);
+            group = vec!();
+        }
+        group.push(items);
+    }
+    factor_group(span, &mut factored, group);
+    factored
+}
+
+// Reorders alternatives so that the ones starting with the same case
+// sensitive literal are adjacent, which lets `factor` turn a list of words
+// into a trie even if it isn't sorted.
+// Two alternatives starting with different case sensitive literals can never
+// match at the same position, so their order doesn't matter. Alternatives
+// starting with the same literal keep their order, and nothing is moved
+// across an alternative that doesn't start with a case sensitive literal.
+fn group_literals(alts: Vec<~Ast>) -> Vec<~Ast> {
+    let mut grouped = Vec::with_capacity(alts.len());
+    let mut run: Vec<(char, uint, ~Ast)> = vec!();
+    for alt in alts.move_iter() {
+        match first_char(&*alt) {
+            Some(c) => {
+                let i = run.len();
+                run.push((c, i, alt))
+            }
+            None => {
+                sort_run(&mut grouped, run);
+                run = vec!();
+                grouped.push(alt);
+            }
+        }
+    }
+    sort_run(&mut grouped, run);
+    grouped
+}
+
+fn sort_run(grouped: &mut Vec<~Ast>, mut run: Vec<(char, uint, ~Ast)>) {
+    run.sort_by(|&(c1, i1, _), &(c2, i2, _)| (c1, i1).cmp(&(c2, i2)));
+    for (_, _, alt) in run.move_iter() {
+        grouped.push(alt)
+    }
+}
+
+// Pushes the alternatives in `group`, which all start with the same
+// literal, on to `factored` as a single alternative.
+fn factor_group(span: Span, factored: &mut Vec<~Ast>, group: Vec<Vec<~Ast>>) {
+    if group.len() == 0 {
+        return
+    }
+    if group.len() == 1 {
+        for items in group.move_iter() {
+            factored.push(cat(items_span(span, &items), items))
+        }
+        return
+    }
+
+    // Find the length of the longest literal prefix shared by all of them.
+    let mut n = 1;
+    'PREFIX: loop {
+        let lit = match group.get(0).as_slice().get(n) {
+            None => break,
+            Some(x) => literal(&**x),
+        };
+        if lit.is_none() {
+            break
+        }
+        for items in group.iter() {
+            match items.as_slice().get(n) {
+                Some(x) if literal(&**x) == lit => {}
+                _ => break 'PREFIX,
+            }
+        }
+        n += 1;
+    }
+
+    let mut prefix = Vec::with_capacity(n + 1);
+    let mut rests = Vec::with_capacity(group.len());
+    for (i, items) in group.move_iter().enumerate() {
+        let mut rest = vec!();
+        for (j, x) in items.move_iter().enumerate() {
+            if j >= n {
+                rest.push(x)
+            } else if i == 0 {
+                prefix.push(x)
+            }
+        }
+        let rest_span = items_span(span, &rest);
+        rests.push(cat(rest_span, rest));
+    }
+    let alt_span = Span {
+        start: rests.get(0).span.start,
+        end: rests.get(rests.len() - 1).span.end,
+    };
+    prefix.push(alternate(alt_span, rests));
+    factored.push(cat(items_span(span, &prefix), prefix));
+}
+
+// Merges runs of adjacent single character alternatives into one class,
+// e.g., `a|[x-z]|b|cd` becomes `[abx-z]|cd`.
+// Since each of these alternatives matches exactly one character, it
+// doesn't matter which one of them is preferred.
+fn merge_classes(alts: Vec<~Ast>) -> Vec<~Ast> {
+    let mut merged = Vec::with_capacity(alts.len());
+    let mut run: Vec<~Ast> = vec!();
+    for alt in alts.move_iter() {
+        if single_char(&*alt).is_none() {
+            merge_run(&mut merged, run);
+            run = vec!();
+            merged.push(alt);
+        } else {
+            run.push(alt);
+        }
+    }
+    merge_run(&mut merged, run);
+    merged
+}
+
+fn merge_run(merged: &mut Vec<~Ast>, run: Vec<~Ast>) {
+    if run.len() < 2 {
+        merged.push_all_move(run);
+        return
+    }
+    let span = Span {
+        start: run.get(0).span.start,
+        end: run.get(run.len() - 1).span.end,
+    };
+    let mut ranges = vec!();
+    for alt in run.iter() {
+        ranges.push_all_move(single_char(&**alt).unwrap());
+    }
+    let class = Class(parse::combine_ranges(ranges), FLAG_EMPTY);
+    merged.push(mk(span, class));
+}
+
+// Builds a repetition of an (already simplified) expression.
+fn repeat(span: Span, x: ~Ast, rep: Repeater, greed: Greed) -> ~Ast {
+    match x {
+        ~Ast { expr: Nothing, .. } => mk(span, Nothing),
+        ~Ast { expr: Rep(y, rep2, greed2), span: span2 } => {
+            // Without captures inside, `(?:x+)?` and `x*` (and friends)
+            // match exactly the same way as long as they agree on greed.
+            if greed.is_greedy() == greed2.is_greedy() && !has_capture(&*y) {
+                let rep = match (rep, rep2) {
+                    (ZeroOne, ZeroOne) => ZeroOne,
+                    (OneMore, OneMore) => OneMore,
+                    _ => ZeroMore,
+                };
+                mk(span, Rep(y, rep, greed))
+            } else {
+                let x = mk(span2, Rep(y, rep2, greed2));
+                mk(span, Rep(x, rep, greed))
+            }
+        }
+        x => mk(span, Rep(x, rep, greed)),
+    }
+}
+
+// Returns the items of a concatenation. (Anything else is a concatenation
+// of one item.)
+fn items(ast: ~Ast) -> Vec<~Ast> {
+    match ast {
+        ~Ast { expr: Cat(xs), .. } => xs,
+        ~Ast { expr: Nothing, .. } => vec!(),
+        ast => vec!(ast),
+    }
+}
+
+fn items_span(default: Span, items: &Vec<~Ast>) -> Span {
+    if items.len() == 0 {
+        return default
+    }
+    Span {
+        start: items.get(0).span.start,
+        end: items.get(items.len() - 1).span.end,
+    }
+}
+
+// Returns the character and case sensitivity of a literal.
+// (Other flags have no effect on literals.)
+fn literal(ast: &Ast) -> Option<(char, Flags)> {
+    match ast.expr {
+        Literal(c, flags) => Some((c, flags & FLAG_NOCASE)),
+        _ => None,
+    }
+}
+
+fn first_literal(items: &[~Ast]) -> Option<(char, Flags)> {
+    items.get(0).and_then(|x| literal(&**x))
+}
+
+/// Returns the first character matched by `ast` if it starts with a case
+/// sensitive literal.
+fn first_char(ast: &Ast) -> Option<char> {
+    let first = match ast.expr {
+        Cat(ref xs) => match xs.as_slice().get(0) {
+            None => return None,
+            Some(x) => &**x,
+        },
+        _ => ast,
+    };
+    match first.expr {
+        Literal(c, flags) if flags & FLAG_NOCASE == 0 => Some(c),
+        _ => None,
+    }
+}
+
+// Returns the ranges of characters matched by `ast` if it always matches
+// exactly one character case sensitively.
+// Case insensitive expressions are left alone since the VM only compares
+// the end points of a range case insensitively.
+fn single_char(ast: &Ast) -> Option<Vec<(char, char)>> {
+    match ast.expr {
+        Literal(c, flags) if flags & FLAG_NOCASE == 0 => Some(vec!((c, c))),
+        Class(ref ranges, flags)
+            if flags & (FLAG_NOCASE | FLAG_NEGATED) == 0 => Some(ranges.clone()),
+        _ => None,
+    }
+}
+
+fn is_nothing(ast: &~Ast) -> bool {
+    match ast.expr {
+        Nothing => true,
+        _ => false,
+    }
+}
+
+// Empty width assertions can't be repeated in the concrete syntax, so keep
+// them out of repetitions to keep the result printable.
+fn is_assertion(ast: &Ast) -> bool {
+    match ast.expr {
+        Begin(_) | End(_) | WordBoundary(_) => true,
+        _ => false,
+    }
+}
+
+struct CaptureFinder {
+    found: bool,
+}
+
+impl Visitor for CaptureFinder {
+    fn visit_pre(&mut self, ast: &Ast) -> bool {
+        match ast.expr {
+            Capture(_, _, _) => { self.found = true; false }
+            _ => !self.found,
+        }
+    }
+}
+
+fn has_capture(ast: &Ast) -> bool {
+    let mut finder = CaptureFinder { found: false };
+    walk(ast, &mut finder);
+    finder.found
+}
@@ -0,0 +1,568 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Access to the abstract syntax of regular expressions.
+//!
+//! This is useful for tools that need to inspect a pattern, e.g., to find
+//! its capture groups or the literals it contains. The syntax is exactly
+//! what the parser produces for `Regexp::new`, so some sugar is already gone:
+//! counted repetitions are expanded, Perl classes are turned into `Class`
+//! ranges and flags are stored on each expression they apply to.
+//!
+//! Every `Ast` records the `Span` of the pattern that it was parsed from, so
+//! expressions can always be traced back to the text of the pattern.
+//!
+//! Expressions can be inspected directly by matching on `Expr`, or with a
+//! `Visitor`, which does not need to worry about deeply nested expressions
+//! (like long alternations) overflowing the stack:
+//!
+//! ```rust
+//! use regexp::syntax::{Ast, Visitor, Capture, parse, walk};
+//!
+//! struct Names { names: Vec<~str> }
+//!
+//! impl Visitor for Names {
+//!     fn visit_pre(&mut self, ast: &Ast) -> bool {
+//!         match ast.expr {
+//!             Capture(_, Some(ref name), _) => self.names.push(name.clone()),
+//!             _ => {}
+//!         }
+//!         true
+//!     }
+//! }
+//!
+//! let ast = parse(r"(?P<year>\d{4})-(?P<month>\d{2})").unwrap();
+//! let mut v = Names { names: vec!() };
+//! walk(&*ast, &mut v);
+//! assert_eq!(v.names, vec!(~"year", ~"month"));
+//! ```
+//!
+//! An expression can also be turned back into a pattern with `to_regexp`.
+//! Equivalent patterns often print the same way, which is handy for
+//! normalizing them:
+//!
+//! ```rust
+//! use regexp::syntax::{parse, to_regexp};
+//!
+//! let ast = parse(r"(?i:[a-cb])(?-i:x{2})").unwrap();
+//! assert_eq!(to_regexp(&*ast), ~"(?i)[a-c](?-i)xx");
+//! ```
+
+use std::char;
+use parse::is_punct;
+use parse::unicode::{UNICODE_CLASSES, PERLD, PERLS, PERLW};
+
+pub use parse::{
+    Ast, Expr, Span,
+    Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Grapheme,
+    Capture, Cat, Alt, Rep,
+    Repeater, ZeroOne, ZeroMore, OneMore,
+    Greed, Greedy, Ungreedy,
+    Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_SWAP_GREED,
+    FLAG_NEGATED, FLAG_UNICODE_WORD,
+    Error, parse,
+};
+
+/// A visitor for the abstract syntax of a regular expression.
+///
+/// Both methods do nothing by default, so implementors only need to provide
+/// the ones they care about.
+pub trait Visitor {
+    /// Called when `ast` is reached, before any of its sub-expressions.
+    /// If this returns `false`, then the sub-expressions of `ast` are
+    /// skipped. (`visit_post` is still called for `ast`.)
+    fn visit_pre(&mut self, _ast: &Ast) -> bool { true }
+
+    /// Called after all of the sub-expressions of `ast` have been visited.
+    fn visit_post(&mut self, _ast: &Ast) {}
+}
+
+/// Walks `ast` depth first, from left to right, calling `visitor` for every
+/// expression in it.
+///
+/// An explicit stack is used instead of recursion, so the depth of `ast`
+/// is only limited by available memory.
+pub fn walk<V: Visitor>(ast: &Ast, visitor: &mut V) {
+    enum Frame<'a> {
+        Pre(&'a Ast),
+        Post(&'a Ast),
+    }
+
+    let mut stack = vec!(Pre(ast));
+    loop {
+        match stack.pop() {
+            None => break,
+            Some(Post(ast)) => visitor.visit_post(ast),
+            Some(Pre(ast)) => {
+                stack.push(Post(ast));
+                if !visitor.visit_pre(ast) {
+                    continue
+                }
+                // Children are pushed in reverse so that they are popped
+                // (and therefore visited) from left to right.
+                match ast.expr {
+                    Capture(_, _, ref x) | Rep(ref x, _, _) =>
+                        stack.push(Pre(&**x)),
+                    Cat(ref xs) => {
+                        for x in xs.iter().rev() {
+                            stack.push(Pre(&**x))
+                        }
+                    }
+                    Alt(ref x, ref y) => {
+                        stack.push(Pre(&**y));
+                        stack.push(Pre(&**x));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Returns a regular expression that parses to an expression equivalent to
+/// `ast`.
+///
+/// The result is canonical in the sense that printing what it parses to
+/// gives back exactly the same string. Flags are only switched (with inline
+/// `(?flags)` groups) where they make a difference, character classes are
+/// written with their smallest set of ranges and non-capturing groups are only
+/// added where precedence requires them.
+///
+/// Since the syntax is already simplified by the parser, the result may be
+/// longer than the original pattern. (e.g., `a{2,3}` is printed as `aaa?`.)
+/// Counted repetitions of capture groups are printed as counted repetitions
+/// again, though, since the copies share their group (e.g., `(a){2,3}`).
+pub fn to_regexp(ast: &Ast) -> ~str {
+    let mut p = Printer { buf: StrBuf::new(), flags: FLAG_EMPTY };
+    p.alt(ast);
+    p.buf.into_owned()
+}
+
+struct Printer {
+    buf: StrBuf,
+    // The flags in effect at the end of `buf`, as the parser would see them.
+    // Only `i`, `m` and `s` are ever set. (Greediness is printed explicitly
+    // for each repetition operator.)
+    flags: Flags,
+}
+
+impl Printer {
+    // Prints an alternation. Alternations nest to the right, so they are
+    // printed with a loop rather than recursion.
+    fn alt(&mut self, mut ast: &Ast) {
+        loop {
+            match ast.expr {
+                Alt(ref x, ref y) => {
+                    self.cat(&**x);
+                    self.buf.push_char('|');
+                    ast = &**y;
+                }
+                _ => return self.cat(ast),
+            }
+        }
+    }
+
+    fn cat(&mut self, ast: &Ast) {
+        match ast.expr {
+            Cat(ref xs) => {
+                let xs = items(xs.as_slice());
+                if xs.len() == 0 {
+                    return self.nothing()
+                }
+                let caps: Vec<bool> =
+                    xs.iter().map(|x| has_capture(*x)).collect();
+                let mut i = 0;
+                while i < xs.len() {
+                    let found = Counted::find(xs.slice_from(i),
+                                              caps.slice_from(i));
+                    match found {
+                        Some(c) => { self.counted(&c); i += c.len }
+                        None => { self.rep(*xs.get(i)); i += 1 }
+                    }
+                }
+            }
+            Nothing => self.nothing(),
+            _ => self.rep(ast),
+        }
+    }
+
+    fn counted(&mut self, c: &Counted) {
+        if c.body.len() == 1 {
+            self.atom(*c.body.get(0))
+        } else {
+            let flags = self.flags;
+            self.buf.push_str("(?:");
+            for &x in c.body.iter() {
+                self.rep(x)
+            }
+            self.buf.push_char(')');
+            self.flags = flags;
+        }
+        match c.max {
+            Some(max) if max == c.min => {
+                return self.buf.push_str(format!("\\{{}\\}", c.min))
+            }
+            Some(max) => self.buf.push_str(format!("\\{{},{}\\}", c.min, max)),
+            None => self.buf.push_str(format!("\\{{},\\}", c.min)),
+        }
+        if !c.greed.is_greedy() {
+            self.buf.push_char('?')
+        }
+    }
+
+    fn rep(&mut self, ast: &Ast) {
+        match ast.expr {
+            Rep(ref x, rep, greed) => {
+                self.atom(&**x);
+                self.buf.push_char(match rep {
+                    ZeroOne => '?',
+                    ZeroMore => '*',
+                    OneMore => '+',
+                });
+                if !greed.is_greedy() {
+                    self.buf.push_char('?')
+                }
+            }
+            _ => self.atom(ast),
+        }
+    }
+
+    fn atom(&mut self, ast: &Ast) {
+        match ast.expr {
+            Literal(c, flags) => {
+                if c.to_uppercase() != c || c.to_lowercase() != c {
+                    self.set_flags(flags, FLAG_NOCASE)
+                }
+                if is_punct(c) {
+                    self.buf.push_char('\\')
+                }
+                self.char(c)
+            }
+            Dot(flags) => {
+                self.set_flags(flags, FLAG_DOTNL);
+                self.buf.push_char('.')
+            }
+            Class(ref ranges, flags) => self.class(ranges.as_slice(), flags),
+            Begin(flags) => {
+                if flags & FLAG_MULTI > 0 {
+                    self.set_flags(flags, FLAG_MULTI);
+                    self.buf.push_char('^')
+                } else if self.flags & FLAG_MULTI > 0 {
+                    self.buf.push_str("\\A")
+                } else {
+                    self.buf.push_char('^')
+                }
+            }
+            End(flags) => {
+                if flags & FLAG_MULTI > 0 {
+                    self.set_flags(flags, FLAG_MULTI);
+                    self.buf.push_char('$')
+                } else if self.flags & FLAG_MULTI > 0 {
+                    self.buf.push_str("\\z")
+                } else {
+                    self.buf.push_char('$')
+                }
+            }
+            WordBoundary(flags) => {
+                self.buf.push_str(
+                    if flags & FLAG_NEGATED > 0 { "\\B" } else { "\\b" });
+                if flags & FLAG_UNICODE_WORD > 0 {
+                    self.buf.push_str("{w}")
+                }
+            }
+            Grapheme => self.buf.push_str("\\X"),
+            Capture(_, None, ref x) => self.group("(", &**x),
+            Capture(_, Some(ref name), ref x) =>
+                self.group(format!("(?P<{}>", *name), &**x),
+            Nothing | Cat(_) | Alt(_, _) | Rep(_, _, _) =>
+                self.group("(?:", ast),
+        }
+    }
+
+    fn group(&mut self, open: &str, ast: &Ast) {
+        // The parser restores the flags from before a group once it is
+        // closed, so do the same here.
+        let flags = self.flags;
+        self.buf.push_str(open);
+        self.alt(ast);
+        self.buf.push_char(')');
+        self.flags = flags;
+    }
+
+    // The empty expression can't be written directly in most places (empty
+    // groups and alternates are not allowed), so write something that the
+    // parser turns into `Nothing`.
+    fn nothing(&mut self) {
+        self.buf.push_str("a{0}")
+    }
+
+    fn class(&mut self, ranges: &[(char, char)], flags: Flags) {
+        self.set_flags(flags, FLAG_NOCASE);
+        let negated = flags & FLAG_NEGATED > 0;
+        for &(name, perl) in [('d', PERLD), ('s', PERLS), ('w', PERLW)].iter() {
+            if ranges == perl {
+                self.buf.push_char('\\');
+                self.buf.push_char(if negated { name.to_uppercase() }
+                                   else { name });
+                return
+            }
+        }
+        for &(name, class) in UNICODE_CLASSES.iter() {
+            if ranges == class {
+                self.buf.push_str(if negated { "\\P" } else { "\\p" });
+                self.buf.push_str(format!("\\{{}\\}", name));
+                return
+            }
+        }
+
+        // ']' and '-' can only be written at the start of a class, so they
+        // are pulled out of the ends of ranges.
+        let (mut close, mut dash) = (false, false);
+        let mut rest = Vec::with_capacity(ranges.len());
+        for &(s, e) in ranges.iter() {
+            let (mut s, mut e) = (s as u32, e as u32);
+            while s <= e && (s == ']' as u32 || s == '-' as u32) {
+                if s == ']' as u32 { close = true } else { dash = true }
+                s += 1;
+            }
+            while s <= e && (e == ']' as u32 || e == '-' as u32) {
+                if e == ']' as u32 { close = true } else { dash = true }
+                e -= 1;
+            }
+            if s <= e {
+                rest.push((char::from_u32(s).unwrap(),
+                           char::from_u32(e).unwrap()))
+            }
+        }
+
+        self.buf.push_char('[');
+        if negated { self.buf.push_char('^') }
+        if close { self.buf.push_char(']') }
+        if dash { self.buf.push_char('-') }
+        for &(s, e) in rest.iter() {
+            self.class_char(s);
+            if s != e {
+                // The parser doesn't look for escapes at the end of a range.
+                self.buf.push_char('-');
+                self.buf.push_char(e);
+            }
+        }
+        self.buf.push_char(']');
+    }
+
+    fn class_char(&mut self, c: char) {
+        match c {
+            '\\' | '[' | '^' => self.buf.push_char('\\'),
+            _ => {}
+        }
+        self.char(c)
+    }
+
+    // Writes `c`, escaping it if it isn't printable.
+    fn char(&mut self, c: char) {
+        match c {
+            '\x07' => self.buf.push_str("\\a"),
+            '\t' => self.buf.push_str("\\t"),
+            '\n' => self.buf.push_str("\\n"),
+            '\x0B' => self.buf.push_str("\\v"),
+            '\x0C' => self.buf.push_str("\\f"),
+            '\r' => self.buf.push_str("\\r"),
+            '\x00' .. '\x1F' | '\x7F' =>
+                self.buf.push_str(format!("\\\\x\\{{:X}\\}", c as u32)),
+            c => self.buf.push_char(c),
+        }
+    }
+
+    // Switches the flags in `mask` to their values in `flags` if they aren't
+    // set that way already.
+    fn set_flags(&mut self, flags: Flags, mask: Flags) {
+        let want = (self.flags & !mask) | (flags & mask);
+        if want == self.flags {
+            return
+        }
+        let (on, off) = (want & !self.flags, self.flags & !want);
+        self.buf.push_str("(?");
+        self.flag_chars(on);
+        if off != FLAG_EMPTY {
+            self.buf.push_char('-');
+            self.flag_chars(off);
+        }
+        self.buf.push_char(')');
+        self.flags = want;
+    }
+
+    fn flag_chars(&mut self, flags: Flags) {
+        let names = [(FLAG_NOCASE, 'i'), (FLAG_MULTI, 'm'), (FLAG_DOTNL, 's')];
+        for &(flag, c) in names.iter() {
+            if flags & flag > 0 {
+                self.buf.push_char(c)
+            }
+        }
+    }
+}
+
+// The copies of an expression that the parser expanded a counted repetition
+// into, e.g., `(a){1,3}` is expanded to `(a)(a)?(a)?`.
+//
+// Only the repetitions of expressions with capture groups are found. Their
+// copies can't be printed one by one, since the groups would get new indices
+// when they're parsed again (and duplicate names wouldn't parse at all).
+// Nothing else in a pattern can produce the same group more than once, so
+// these are always copies. (Expressions without groups are printed as they
+// are, since the copies are equivalent to the repetition.)
+struct Counted<'a> {
+    // The repeated expression. It has more than one item if it was a
+    // concatenation, since those are flattened into the surrounding one.
+    body: Vec<&'a Ast>,
+    min: uint,
+    max: Option<uint>,
+    greed: Greed,
+    // The number of items in the concatenation that the copies take up.
+    len: uint,
+}
+
+impl<'a> Counted<'a> {
+    // Finds the copies at the start of `xs`, which are items of
+    // a concatenation. `caps` says which of them have capture groups.
+    fn find(xs: &[&'a Ast], caps: &[bool]) -> Option<Counted<'a>> {
+        // The body has a capture group, so the first item with one shows up
+        // again in the next copy (or in the optional copy after the body).
+        let first = match caps.iter().position(|&yes| yes) {
+            None => return None,
+            Some(first) => first,
+        };
+        for i in range(first + 1, xs.len()) {
+            if !caps[i] {
+                continue
+            }
+            let p = match xs[i].expr {
+                Rep(ref x, _, _) if same_body(xs.slice_to(i), &**x) => i,
+                _ if same(xs[first], xs[i]) => i - first,
+                _ => continue,
+            };
+            if p <= first {
+                continue
+            }
+            match Counted::copies(xs, p) {
+                Some(c) => return Some(c),
+                None => {}
+            }
+        }
+        None
+    }
+
+    // Counts the copies of the first `p` items of `xs` at the start of it.
+    // There are `min` copies of them, followed by `max - min` optional
+    // copies or by one `*` repetition of them.
+    fn copies(xs: &[&'a Ast], p: uint) -> Option<Counted<'a>> {
+        let body = xs.slice_to(p);
+        let mut min = 1;
+        while xs.len() >= (min + 1) * p
+              && same_items(body, xs.slice(min * p, (min + 1) * p)) {
+            min += 1;
+        }
+        let (mut len, mut max, mut greed) = (min * p, Some(min), Greedy);
+        for x in xs.slice_from(min * p).iter() {
+            let first = len == min * p;
+            match x.expr {
+                Rep(ref y, ZeroOne, g) if same_body(body, &**y) => {
+                    if !first && !same_greed(g, greed) {
+                        break
+                    }
+                    max = max.map(|max| max + 1);
+                    greed = g;
+                    len += 1;
+                }
+                Rep(ref y, ZeroMore, g) if first && same_body(body, &**y) => {
+                    max = None;
+                    greed = g;
+                    len += 1;
+                    break
+                }
+                _ => break,
+            }
+        }
+        if len == p {
+            return None
+        }
+        Some(Counted {
+            body: Vec::from_slice(body),
+            min: min,
+            max: max,
+            greed: greed,
+            len: len,
+        })
+    }
+}
+
+// Returns the items of a concatenation, without any `Nothing` in it.
+fn items<'a>(xs: &'a [~Ast]) -> Vec<&'a Ast> {
+    xs.iter().filter(|x| match x.expr { Nothing => false, _ => true })
+      .map(|x| &**x).collect()
+}
+
+// Returns true if `x` is a copy of `body` (see `Counted`).
+fn same_body(body: &[&Ast], x: &Ast) -> bool {
+    match x.expr {
+        Cat(ref ys) => same_items(body, items(ys.as_slice()).as_slice()),
+        _ => body.len() == 1 && same(body[0], x),
+    }
+}
+
+fn same_items(xs: &[&Ast], ys: &[&Ast]) -> bool {
+    xs.len() == ys.len() && xs.iter().zip(ys.iter()).all(|(&x, &y)| same(x, y))
+}
+
+// Returns true if `x` and `y` are the same expression, ignoring their spans.
+fn same(x: &Ast, y: &Ast) -> bool {
+    match (&x.expr, &y.expr) {
+        (&Nothing, &Nothing) | (&Grapheme, &Grapheme) => true,
+        (&Literal(c1, f1), &Literal(c2, f2)) => c1 == c2 && f1 == f2,
+        (&Dot(f1), &Dot(f2)) | (&Begin(f1), &Begin(f2))
+        | (&End(f1), &End(f2)) | (&WordBoundary(f1), &WordBoundary(f2)) => {
+            f1 == f2
+        }
+        (&Class(ref r1, f1), &Class(ref r2, f2)) => r1 == r2 && f1 == f2,
+        (&Capture(i1, ref n1, ref x1), &Capture(i2, ref n2, ref x2)) => {
+            i1 == i2 && n1 == n2 && same(&**x1, &**x2)
+        }
+        (&Cat(ref xs1), &Cat(ref xs2)) => {
+            same_items(items(xs1.as_slice()).as_slice(),
+                       items(xs2.as_slice()).as_slice())
+        }
+        (&Alt(ref x1, ref y1), &Alt(ref x2, ref y2)) => {
+            same(&**x1, &**x2) && same(&**y1, &**y2)
+        }
+        (&Rep(ref x1, r1, g1), &Rep(ref x2, r2, g2)) => {
+            r1 == r2 && same_greed(g1, g2) && same(&**x1, &**x2)
+        }
+        _ => false,
+    }
+}
+
+fn same_greed(g1: Greed, g2: Greed) -> bool {
+    g1.is_greedy() == g2.is_greedy()
+}
+
+fn has_capture(ast: &Ast) -> bool {
+    struct Found { yes: bool }
+    impl Visitor for Found {
+        fn visit_pre(&mut self, ast: &Ast) -> bool {
+            match ast.expr {
+                Capture(..) => self.yes = true,
+                _ => {}
+            }
+            !self.yes
+        }
+    }
+    let mut v = Found { yes: false };
+    walk(ast, &mut v);
+    v.yes
+}
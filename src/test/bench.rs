@@ -64,6 +64,19 @@ fn replace_all(b: &mut Bencher) {
     b.iter(|| re.replace_all(text, NoExpand("")));
 }
 
+#[bench]
+fn dynamic_is_match(b: &mut Bencher) {
+    let re = Regexp::new(r"(\w+)@(\w+)\.com").unwrap();
+    b.iter(|| re.is_match("contact: someone@example.com"));
+}
+
+#[bench]
+fn dynamic_is_match_matcher(b: &mut Bencher) {
+    let re = Regexp::new(r"(\w+)@(\w+)\.com").unwrap();
+    let mut matcher = re.matcher();
+    b.iter(|| matcher.is_match("contact: someone@example.com"));
+}
+
 #[bench]
 fn anchored_literal_short_non_match(b: &mut Bencher) {
     let re = regexp!("^zbc(d|e)");
@@ -177,3 +190,56 @@ throughput!(hard_32, hard(), 32)
 throughput!(hard_1K, hard(), 1<<10)
 throughput!(hard_32K,hard(), 32<<10)
 
+
+fn dictionary(n: uint) -> Regexp {
+    let words: Vec<~str> = range(0, n).map(|i| {
+        format!("w{}x", (i * 7919) % 100000)
+    }).collect();
+    Regexp::new(r"\b(?:" + words.connect("|") + r")\b").unwrap()
+}
+
+#[bench]
+fn dictionary_compile(b: &mut Bencher) {
+    b.iter(|| dictionary(5000));
+}
+
+#[bench]
+fn dictionary_32K(b: &mut Bencher) {
+    let re = dictionary(5000);
+    let text = gen_text(32<<10);
+    b.bytes = 32<<10;
+    b.iter(|| re.is_match(text));
+}
+
+// Searches for long literal prefixes. The adversarial haystacks are ones
+// where a naive substring search would have to compare (almost) the whole
+// needle at every position.
+
+macro_rules! prefix(
+    ($name:ident, $needle:expr, $haystack:expr) => (
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let re = Regexp::new($needle + "[0-9]").unwrap();
+            let text = $haystack;
+            b.bytes = text.len() as u64;
+            b.iter(|| if re.is_match(text) { fail!("match") });
+        }
+    );
+)
+
+prefix!(prefix_long_32K, ~"ABCDEFGHIJKLMNOPQRSTUVWXYZ", gen_text(32<<10))
+prefix!(prefix_long_rare_32K, ~"the quick brown fox jumps over the lazy dog",
+        "the quick brown fox jumps over the lazy cat ".repeat(750))
+prefix!(prefix_adversarial_32K, "a".repeat(64) + "b", "a".repeat(32<<10))
+prefix!(prefix_adversarial_periodic_32K, "ab".repeat(32) + "c",
+        "ab".repeat(16<<10))
+prefix!(prefix_adversarial_rare_32K, "b" + "a".repeat(64),
+        ("b" + "a".repeat(63)).repeat(512))
+
+#[bench]
+fn suffix_anchored_32K(b: &mut Bencher) {
+    let re = Regexp::new(r"[a-z]+\.(?:jpg|png)$").unwrap();
+    let text = gen_text(32<<10) + "photo.jpg";
+    b.bytes = text.len() as u64;
+    b.iter(|| if !re.is_match(text) { fail!("no match") });
+}
@@ -0,0 +1,194 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use compile::{
+    Program, Match, OneChar, Literal, Any, Branch, Save, Jump, Split,
+};
+use parse;
+use parse::{FLAG_EMPTY, FLAG_NOCASE};
+use simplify::simplify;
+use vm;
+use vm::{Submatches, Unanchored};
+
+// Compiles a regexp as parsed (i.e., without simplifying it) and checks the
+// instructions of the resulting program.
+macro_rules! prog(
+    ($name:ident, $re:expr, $($inst:expr),+) => (
+        #[test]
+        fn $name() {
+            let (prog, _) = Program::new(parse::parse($re).unwrap());
+            assert_eq!(prog.insts, vec!($($inst),+));
+        }
+    );
+)
+
+prog!(prog_literal, "a(?i)bc",
+      Save(0), OneChar('a', FLAG_EMPTY), Literal(~"bc", FLAG_NOCASE),
+      Save(1), Match)
+prog!(prog_literal_flags, "ab(?i)cd",
+      Save(0), Literal(~"ab", FLAG_EMPTY), Literal(~"cd", FLAG_NOCASE),
+      Save(1), Match)
+prog!(prog_literal_jump_target, "ab+cd",
+      Save(0), OneChar('a', FLAG_EMPTY), OneChar('b', FLAG_EMPTY),
+      Split(2, 4), Literal(~"cd", FLAG_EMPTY), Save(1), Match)
+prog!(prog_thread_jumps, "(?:.a|.b)*",
+      Save(0), Split(2, 9), Split(3, 6),
+      Any(FLAG_EMPTY), OneChar('a', FLAG_EMPTY), Jump(1),
+      Any(FLAG_EMPTY), OneChar('b', FLAG_EMPTY), Jump(1),
+      Save(1), Match)
+prog!(prog_remove_unreachable, "ab|c|de",
+      Save(0), Branch(vec!(('a', 2), ('c', 5), ('d', 4)), FLAG_EMPTY),
+      OneChar('b', FLAG_EMPTY), Jump(5), OneChar('e', FLAG_EMPTY),
+      Save(1), Match)
+prog!(prog_branch_nocase, "(?i)ab|c",
+      Save(0), Branch(vec!(('A', 2), ('C', 4)), FLAG_NOCASE),
+      OneChar('b', FLAG_NOCASE), Jump(4), Save(1), Match)
+prog!(prog_branch_split, ".|ab|ac|(?i:d)|(?i:f)|e",
+      Save(0), Split(2, 4), Any(FLAG_EMPTY), Jump(10),
+      Split(5, 7), Branch(vec!(('a', 6)), FLAG_EMPTY),
+      Branch(vec!(('b', 10), ('c', 10)), FLAG_EMPTY),
+      Split(8, 9), Branch(vec!(('D', 10), ('F', 10)), FLAG_NOCASE),
+      OneChar('e', FLAG_EMPTY), Save(1), Match)
+
+// The same, but for the reversed program of a regexp.
+macro_rules! rprog(
+    ($name:ident, $re:expr, $($inst:expr),+) => (
+        #[test]
+        fn $name() {
+            let prog = Program::new_rev(parse::parse($re).unwrap());
+            assert_eq!(prog.insts, vec!($($inst),+));
+        }
+    );
+)
+
+rprog!(rprog_capture, "a(b)",
+       Save(1), Save(3), OneChar('b', FLAG_EMPTY), Save(2),
+       OneChar('a', FLAG_EMPTY), Save(0), Match)
+rprog!(rprog_literal, "abc",
+       Save(1), Literal(~"cba", FLAG_EMPTY), Save(0), Match)
+rprog!(rprog_alt, "ab|cd",
+       Save(1), Branch(vec!(('b', 2), ('d', 4)), FLAG_EMPTY),
+       OneChar('a', FLAG_EMPTY), Jump(5), OneChar('c', FLAG_EMPTY),
+       Save(0), Match)
+rprog!(rprog_alt_suffix, "ab|cb",
+       Save(1), Branch(vec!(('b', 2)), FLAG_EMPTY),
+       Branch(vec!(('a', 3), ('c', 3)), FLAG_EMPTY),
+       Save(0), Match)
+
+#[test]
+fn prog_branch_dictionary() {
+    // A list of words compiles to a trie of `Branch` instructions, so it
+    // never needs a `Split` instruction and grows with the number of
+    // characters rather than with the number of alternatives times their
+    // length.
+    let check = |n: uint| {
+        let words: Vec<~str> = range(0u, n).map(|i| {
+            format!("w{}x", (i * 7919) % 100000)
+        }).collect();
+        let chars = words.iter().fold(0, |len, w| len + w.len());
+        let re = words.connect("|");
+        let nocase = "(?i)" + re;
+        let ast = |re: &str| parse::parse(re).unwrap();
+        let progs = vec!(
+            Program::new(simplify(ast(re.as_slice()))).val0(),
+            Program::new(simplify(ast(nocase.as_slice()))).val0(),
+            Program::new_rev(ast(re.as_slice())),
+            Program::new_rev(ast(nocase.as_slice())));
+        for prog in progs.iter() {
+            assert!(!prog.insts.iter().any(|inst| {
+                match *inst { Split(_, _) => true, _ => false }
+            }));
+            assert!(prog.insts.len() <= 2 * chars + 3);
+        }
+    };
+    check(10);
+    check(100);
+    check(1000);
+}
+
+#[test]
+fn prog_literal_matches() {
+    // Writing each character as a class keeps it from being fused, so both
+    // programs must find the same matches with different sizes.
+    let res = [
+        ("abcd(ef)*gh", "[a][b][c][d]([e][f])*[g][h]", 10, 15),
+        ("(?i)hello, world", "(?i)[h][e][l][l][o][,][ ][w][o][r][l][d]",
+         4, 15),
+        ("a+bcd|xyz", "[a]+[b][c][d]|[x][y][z]", 9, 13),
+        ("\u2603\u00e9\u2603", "[\u2603][\u00e9][\u2603]", 4, 6)
+    ];
+    let texts = [
+        "abcdefefgh", "abcdgh", "xabcdefg", "HeLLo, WoRLD!", "hello, wor",
+        "aaabcd", "xyzabcd", "xy", "x\u2603\u00e9\u2603", "\u2603\u00e9", ""
+    ];
+    for &(re, classes, nfused, nclasses) in res.iter() {
+        let (fused, _) = Program::new(parse::parse(re).unwrap());
+        let (unfused, _) = Program::new(parse::parse(classes).unwrap());
+        assert_eq!(fused.insts.len(), nfused);
+        assert_eq!(unfused.insts.len(), nclasses);
+        for &text in texts.iter() {
+            assert_eq!(
+                vm::run(Submatches, Unanchored, &fused, text, 0, text.len()),
+                vm::run(Submatches, Unanchored, &unfused, text, 0,
+                        text.len()));
+        }
+    }
+}
+
+#[test]
+fn prog_reversed_only_for_suffix() {
+    // Only programs whose matches all end at the end of the input are
+    // searched backwards, so they're the only ones with reversed programs.
+    let reversed = |re: &str| {
+        let (prog, _) = Program::new(parse::parse(re).unwrap());
+        prog.reversed.is_some()
+    };
+    assert!(reversed("a$"));
+    assert!(reversed("(?:a|b)$"));
+    assert!(!reversed("a$|b"));
+    assert!(!reversed("(?m)a$"));
+    assert!(!reversed("abc"));
+}
+
+// Checks the literals found for a regexp, which is simplified first (like
+// `Regexp::new` does).
+macro_rules! lits(
+    ($name:ident, $re:expr, [$($pre:expr),*], [$($suf:expr),*], $req:expr) => (
+        #[test]
+        fn $name() {
+            let ast = simplify(parse::parse($re).unwrap());
+            let (prog, _) = Program::new(ast);
+            let (pre, suf): (Vec<~str>, Vec<~str>) =
+                (vec!($(~$pre),*), vec!($(~$suf),*));
+            assert_eq!(prog.prefixes, pre);
+            assert_eq!(prog.suffixes, suf);
+            assert_eq!(prog.required, ~$req);
+        }
+    );
+)
+
+lits!(lits_alt, "foo|bar", ["bar", "foo"], ["bar", "foo"], "")
+lits!(lits_nocase, "(?i)ab",
+      ["AB", "Ab", "aB", "ab"], ["AB", "aB", "Ab", "ab"], "")
+lits!(lits_nocase_unicode, "(?i)\u00e9", [], [], "")
+lits!(lits_class, "a[bc]d?", ["ab", "ac"], ["ab", "ac", "abd", "acd"], "a")
+lits!(lits_inner, r"\d+ms timeout", [], ["ms timeout"], "ms timeout")
+lits!(lits_anchored, "^abc$", ["abc"], ["abc"], "abc")
+lits!(lits_dot, "a.*b", ["a"], ["b"], "a")
+lits!(lits_empty, "x*", [], [], "")
+
+#[test]
+fn lits_too_many() {
+    // There are 2^8 ways to write this, so the prefixes stop early.
+    let (prog, _) = Program::new(parse::parse("(?i)abcdefgh").unwrap());
+    assert_eq!(prog.prefixes.len(), 64);
+    assert!(prog.prefixes.iter().all(|lit| lit.len() == 6));
+    assert!(prog.prefixes.iter().any(|lit| lit.as_slice() == "aBcDeF"));
+}
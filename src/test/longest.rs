@@ -0,0 +1,108 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-tidy-linelength
+
+use re::{Regexp, RegexpBuilder};
+
+fn longest(re: &str) -> Regexp {
+    match RegexpBuilder::new(re).longest(true).build() {
+        Ok(re) => re,
+        Err(err) => fail!("{}", err),
+    }
+}
+
+#[test]
+fn longest_off_by_default() {
+    let re = RegexpBuilder::new("a|ab").build().unwrap();
+    assert_eq!(re.find_pos("ab"), Some((0, 1)));
+    assert_eq!(longest("a|ab").find_pos("ab"), Some((0, 2)));
+}
+
+#[test]
+fn longest_find_iter() {
+    let got: Vec<(uint, uint)> = longest("a|ab").find_iter_pos("abab").collect();
+    assert_eq!(got, vec!((0, 2), (2, 4)));
+    let got: Vec<(uint, uint)> = longest("a*").find_iter_pos("baab").collect();
+    assert_eq!(got, vec!((0, 0), (1, 3), (4, 4)));
+}
+
+#[test]
+fn longest_is_match() {
+    assert!(longest("a|ab").is_match("xab"));
+    assert!(!longest("a|ab").is_match("xb"));
+}
+
+// The testregex suite is POSIX, so all of it must hold in this mode too.
+macro_rules! mat(
+    ($name:ident, $re:expr, $text:expr, $($loc:tt)+) => (
+        #[test]
+        fn $name() {
+            let text = $text;
+            let expected: Vec<Option<(uint, uint)>> = vec!($($loc)+);
+            let r = super::longest($re);
+            let got = match r.captures(text) {
+                Some(c) => c.iter_pos().collect::<Vec<Option<(uint, uint)>>>(),
+                None => vec!(None),
+            };
+            // The test set sometimes leave out capture groups, so truncate
+            // actual capture groups to match test set.
+            let (sexpect, mut sgot) = (expected.as_slice(), got.as_slice());
+            if sgot.len() > sexpect.len() {
+                sgot = sgot.slice(0, sexpect.len())
+            }
+            if sexpect != sgot {
+                fail!("For RE '{}' against '{}', expected '{}' but got '{}'",
+                      $re, text, sexpect, sgot);
+            }
+        }
+    );
+)
+
+mat!(longest_alt, "a|ab", "xab", Some((1, 3)))
+mat!(longest_alt_order, "ab|abcd|abc", "abcd", Some((0, 4)))
+mat!(longest_leftmost, "bcd|ab", "abcd", Some((0, 2)))
+mat!(longest_lazy, "a+?", "aaa", Some((0, 3)))
+mat!(longest_empty, "a*", "baaa", Some((0, 0)))
+mat!(longest_submatches, "(a|ab)(c|bcd)(d*)", "abcd",
+     Some((0, 4)), Some((0, 1)), Some((1, 4)), Some((4, 4)))
+mat!(longest_suffix_anchor, "(a|ab)(b*)$", "xabb",
+     Some((1, 4)), Some((1, 2)), Some((2, 4)))
+mat!(longest_none, "a|ab", "b", None)
+
+mod matches;
+
+// The POSIX results that the suite comments out, since they pick submatches
+// differently than RE2/Go does. Only the submatches in `$checked` agree with
+// the RE2/Go results, but the overall match is always the leftmost-longest
+// one.
+macro_rules! posix(
+    ($name:ident, $re:expr, $text:expr, $checked:expr, $($loc:tt)+) => (
+        #[test]
+        fn $name() {
+            let text = $text;
+            let expected: Vec<Option<(uint, uint)>> = vec!($($loc)+);
+            let r = super::longest($re);
+            let got = match r.captures(text) {
+                Some(c) => c.iter_pos().collect::<Vec<Option<(uint, uint)>>>(),
+                None => vec!(None),
+            };
+            let (sexpect, sgot) = (expected.as_slice(), got.as_slice());
+            for &i in $checked.iter() {
+                if sexpect.get(i) != sgot.get(i) {
+                    fail!("For RE '{}' against '{}', expected '{}' for group \
+                           {} but got '{}'", $re, text, sexpect, i, sgot);
+                }
+            }
+        }
+    );
+)
+
+mod posix;
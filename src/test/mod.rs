@@ -25,5 +25,9 @@ macro_rules! regexp(
 )
 
 mod bench;
+mod compile;
+mod longest;
+mod search;
+mod simplify;
 mod tests;
 
@@ -0,0 +1,54 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-tidy-linelength
+
+// DO NOT EDIT. Automatically generated by 'src/etc/regexp-match-tests'
+// on 2026-10-18 18:34:26.158562.
+
+// Tests from basic.dat
+posix!(match_basic_127, r"(a*)*", r"-", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_basic_130, r"(a*|b)*", r"-", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_basic_136, r"(^)*", r"-", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_basic_143, r"((a*|b))*", r"-", &[0], Some((0, 0)), Some((0, 0)), Some((0, 0)))
+
+// Tests from nullsubexpr.dat
+posix!(match_nullsubexpr_4, r"(a*)*", r"x", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_nullsubexpr_22, r"([a]*)*", r"x", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_nullsubexpr_31, r"([^b]*)*", r"b", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_nullsubexpr_44, r"([^a]*)*", r"aaaaaa", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_nullsubexpr_47, r"([^ab]*)*", r"ababab", &[0], Some((0, 0)), Some((0, 0)))
+posix!(match_nullsubexpr_68, r"(a*)*(x)", r"x", &[0, 2], Some((0, 1)), Some((0, 0)), Some((0, 1)))
+
+// Tests from repetition.dat
+posix!(match_repetition_45, r"((..)|(.)){2}", r"aaa", &[0, 1, 3], Some((0, 3)), Some((2, 3)), None, Some((2, 3)))
+posix!(match_repetition_49, r"((..)|(.))*", r"aaa", &[0, 1, 3], Some((0, 3)), Some((2, 3)), None, Some((2, 3)))
+posix!(match_repetition_58, r"((..)|(.)){3}", r"aaaa", &[0, 1, 3], Some((0, 4)), Some((3, 4)), None, Some((3, 4)))
+posix!(match_repetition_69, r"((..)|(.)){3}", r"aaaaa", &[0, 1, 3], Some((0, 5)), Some((4, 5)), None, Some((4, 5)))
+posix!(match_repetition_72, r"((..)|(.))*", r"aaaaa", &[0, 1, 3], Some((0, 5)), Some((4, 5)), None, Some((4, 5)))
+posix!(match_repetition_99, r"X(.?){0,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_101, r"X(.?){1,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_103, r"X(.?){2,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_105, r"X(.?){3,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_107, r"X(.?){4,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_109, r"X(.?){5,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_111, r"X(.?){6,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_113, r"X(.?){7,8}Y", r"X1234567Y", &[0], Some((0, 9)), Some((7, 8)))
+posix!(match_repetition_142, r"(ab|a|c|bcd){0,}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_144, r"(ab|a|c|bcd){1,}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_146, r"(ab|a|c|bcd){2,}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_148, r"(ab|a|c|bcd){3,}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_151, r"(ab|a|c|bcd){0,10}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_153, r"(ab|a|c|bcd){1,10}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_155, r"(ab|a|c|bcd){2,10}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_157, r"(ab|a|c|bcd){3,10}(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_160, r"(ab|a|c|bcd)*(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+posix!(match_repetition_162, r"(ab|a|c|bcd)+(d*)", r"ababcd", &[0], Some((0, 6)), Some((3, 6)), Some((6, 6)))
+
@@ -0,0 +1,78 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rand::{Rng, task_rng};
+use search::Searcher;
+
+fn naive(needle: &[u8], haystack: &[u8]) -> Option<uint> {
+    if needle.len() > haystack.len() {
+        return None
+    }
+    range(0, haystack.len() - needle.len() + 1).find(|&i| {
+        haystack.slice_from(i).starts_with(needle)
+    })
+}
+
+fn check(needle: &str, haystack: &str) {
+    let (nbytes, hbytes) = (needle.as_bytes(), haystack.as_bytes());
+    let got = Searcher::new(nbytes).find(nbytes, hbytes);
+    let expected = naive(nbytes, hbytes);
+    if got != expected {
+        fail!("Searching for '{}' in '{}' found {} instead of {}",
+              needle, haystack, got, expected);
+    }
+}
+
+macro_rules! find(
+    ($name:ident, $needle:expr, $haystack:expr) => (
+        #[test]
+        fn $name() {
+            check($needle, $haystack);
+        }
+    );
+)
+
+find!(find_empty, "", "abc")
+find!(find_one, "c", "abcabc")
+find!(find_start, "abc", "abcabc")
+find!(find_end, "cab", "abcabc")
+find!(find_missing, "abd", "abcabc")
+find!(find_too_long, "abcabcabc", "abcabc")
+find!(find_overlap, "aab", "aaaab")
+find!(find_periodic, "abab", "abaababab")
+find!(find_periodic_missing, "abababc", "abababababab")
+find!(find_rare_last, "aaaaaaaaaz", "aaaaaaaaaaaaaaaaaaaz")
+find!(find_unicode, "☃ snow", "a ☃ snowman")
+
+#[test]
+fn find_adversarial() {
+    // These are long enough that the searcher gives up on the rare byte
+    // and finishes with Two-Way.
+    check("a".repeat(64) + "b", "a".repeat(4096) + "b");
+    check("a".repeat(64) + "b", "a".repeat(4096));
+    check("ab".repeat(32) + "c", "ab".repeat(2048) + "c");
+    check("b" + "a".repeat(64), ("b" + "a".repeat(63)).repeat(64));
+    check("aab".repeat(20) + "aaa", "aab".repeat(1000) + "aaa");
+}
+
+#[test]
+fn find_random() {
+    // A small alphabet makes for lots of partial matches.
+    let mut rng = task_rng();
+    for _ in range(0, 1000) {
+        let nlen = rng.gen_range(1u, 12);
+        let hlen = rng.gen_range(0u, 200);
+        let needle = rng.gen_vec::<bool>(nlen).move_iter()
+                        .map(|b| if b { 'a' } else { 'b' }).collect::<~str>();
+        let haystack = rng.gen_vec::<bool>(hlen).move_iter()
+                          .map(|b| if b { 'a' } else { 'b' }).collect::<~str>();
+        check(needle, haystack);
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use compile::Program;
+use parse;
+use parse::Ast;
+use simplify::simplify;
+use syntax::to_regexp;
+use vm;
+use vm::{CaptureLocs, Submatches, Unanchored};
+
+macro_rules! simp(
+    ($name:ident, $re:expr, $simplified:expr) => (
+        #[test]
+        fn $name() {
+            let ast = simplify(parse::parse($re).unwrap());
+            assert_eq!(to_regexp(&*ast), ~$simplified);
+        }
+    );
+)
+
+simp!(simp_alt_class, "a|b|c", "[a-c]")
+simp!(simp_alt_class_mixed, "a|[x-z]|b|cd|e|f", "[a-bx-z]|cd|[e-f]")
+simp!(simp_alt_class_nocase, "(?i)a|b", "(?i)a|b")
+simp!(simp_alt_class_negated, "[^a]|b", "[^a]|b")
+simp!(simp_alt_flatten, "(?:a|b)|c", "[a-c]")
+simp!(simp_prefix, "abc|abd", "ab[c-d]")
+simp!(simp_prefix_group, "x(?:abc|abd)y", "xab[c-d]y")
+simp!(simp_prefix_regroup, "abc|x|abd|ay", "a(?:b[c-d]|y)|x")
+simp!(simp_prefix_regroup_order, "b2|a|b1", "a|b[1-2]")
+simp!(simp_prefix_regroup_barrier, "ab|.|ac", "ab|.|ac")
+simp!(simp_prefix_short_first, "ab|abc", "abc??")
+simp!(simp_prefix_short_last, "abc|ab", "abc?")
+simp!(simp_prefix_nested, "abc|abd|acd", "a(?:b[c-d]|cd)")
+simp!(simp_prefix_nocase, "(?i)ab|(?-i)ac", "(?i)ab|(?-i)ac")
+simp!(simp_prefix_capture, "(a)b|(a)c", "(a)b|(a)c")
+simp!(simp_nothing, "ab{0}c", "ac")
+simp!(simp_nothing_rep, "(?:a{0})*b", "b")
+simp!(simp_rep_star, "(?:a*)*", "a*")
+simp!(simp_rep_plus_opt, "(?:a+)?", "a*")
+simp!(simp_rep_plus, "(?:a+)+", "a+")
+simp!(simp_rep_lazy, "(?:a+?)??", "a*?")
+simp!(simp_rep_mixed_greed, "(?:a+?)*", "(?:a+?)*")
+simp!(simp_rep_capture, "(a*)*", "(a*)*")
+
+// Every test from the testregex suite is run twice: once as parsed and once
+// simplified. Both must report exactly the same submatch locations.
+
+fn search(ast: ~Ast, text: &str) -> CaptureLocs {
+    let (prog, _) = Program::new(ast);
+    vm::run(Submatches, Unanchored, &prog, text, 0, text.len())
+}
+
+fn check_simplified(re: &str, text: &str) {
+    let ast = parse::parse(re).unwrap();
+    let simple = simplify(ast.clone());
+    let simple_re = to_regexp(&*simple);
+    let (expected, got) = (search(ast, text), search(simple, text));
+    if expected != got {
+        fail!("For RE '{}' against '{}', simplifying to '{}' changed the \
+               result from '{}' to '{}'", re, text, simple_re, expected, got);
+    }
+}
+
+macro_rules! mat(
+    ($name:ident, $re:expr, $text:expr, $($loc:tt)+) => (
+        #[test]
+        fn $name() {
+            super::check_simplified($re, $text);
+        }
+    );
+)
+
+mod matches;
@@ -10,7 +10,11 @@
 
 // ignore-tidy-linelength
 
-use regexp::{Regexp, NoExpand};
+use std::str;
+use collections::HashMap;
+use regexp::{Regexp, Captures, NoExpand, Scanner};
+use regexp::{Lexer, Lexeme, Token, NoToken};
+use regexp::syntax;
 
 #[test]
 fn splitn() {
@@ -28,6 +32,175 @@ fn split() {
     assert_eq!(subs, vec!("cauchy", "plato", "tyler", "binx"));
 }
 
+struct Trace { events: Vec<~str>, skip_groups: bool }
+
+impl syntax::Visitor for Trace {
+    fn visit_pre(&mut self, ast: &syntax::Ast) -> bool {
+        self.events.push(format!("pre {}", ast_name(ast)));
+        match ast.expr {
+            syntax::Capture(_, _, _) => !self.skip_groups,
+            _ => true,
+        }
+    }
+
+    fn visit_post(&mut self, ast: &syntax::Ast) {
+        self.events.push(format!("post {}", ast_name(ast)));
+    }
+}
+
+fn ast_name(ast: &syntax::Ast) -> ~str {
+    match ast.expr {
+        syntax::Literal(c, _) => str::from_char(c),
+        syntax::Capture(i, _, _) => format!("cap{}", i),
+        syntax::Cat(_) => ~"cat",
+        syntax::Alt(_, _) => ~"alt",
+        syntax::Rep(_, _, _) => ~"rep",
+        _ => ~"other",
+    }
+}
+
+fn trace(re: &str, skip_groups: bool) -> Vec<~str> {
+    let ast = syntax::parse(re).unwrap();
+    let mut v = Trace { events: vec!(), skip_groups: skip_groups };
+    syntax::walk(&*ast, &mut v);
+    v.events
+}
+
+struct Spans { spans: Vec<(~str, uint, uint)> }
+
+impl syntax::Visitor for Spans {
+    fn visit_pre(&mut self, ast: &syntax::Ast) -> bool {
+        self.spans.push((ast_name(ast), ast.span.start, ast.span.end));
+        true
+    }
+}
+
+fn spans(re: &str) -> Vec<(~str, uint, uint)> {
+    let ast = syntax::parse(re).unwrap();
+    let mut v = Spans { spans: vec!() };
+    syntax::walk(&*ast, &mut v);
+    v.spans
+}
+
+#[test]
+fn syntax_walk_order() {
+    assert_eq!(trace("a(b)|c*", false), vec!(
+        ~"pre alt", ~"pre cat", ~"pre a", ~"post a",
+        ~"pre cap1", ~"pre b", ~"post b", ~"post cap1", ~"post cat",
+        ~"pre rep", ~"pre c", ~"post c", ~"post rep", ~"post alt"));
+}
+
+#[test]
+fn syntax_walk_skip() {
+    assert_eq!(trace("(a)b", true), vec!(
+        ~"pre cat", ~"pre cap1", ~"post cap1", ~"pre b", ~"post b",
+        ~"post cat"));
+}
+
+#[test]
+fn syntax_spans_cat() {
+    assert_eq!(spans("ab(c)"), vec!(
+        (~"cat", 0, 5), (~"a", 0, 1), (~"b", 1, 2),
+        (~"cap1", 2, 5), (~"c", 3, 4)));
+}
+
+#[test]
+fn syntax_spans_alt_rep() {
+    assert_eq!(spans("a|b*?"), vec!(
+        (~"alt", 0, 5), (~"a", 0, 1), (~"rep", 2, 5), (~"b", 2, 3)));
+}
+
+#[test]
+fn syntax_spans_group() {
+    assert_eq!(spans("(?:ab)+(?P<n>c)"), vec!(
+        (~"cat", 0, 15), (~"rep", 0, 7), (~"cat", 0, 6),
+        (~"a", 3, 4), (~"b", 4, 5), (~"cap1", 7, 15), (~"c", 13, 14)));
+}
+
+#[test]
+fn syntax_spans_counted() {
+    assert_eq!(spans("ax{1,2}"), vec!(
+        (~"cat", 0, 7), (~"a", 0, 1), (~"x", 1, 7),
+        (~"rep", 1, 7), (~"x", 1, 7)));
+}
+
+#[test]
+fn syntax_spans_counted_children() {
+    // The copies' children keep their own spans.
+    assert_eq!(spans("(a){2}"), vec!(
+        (~"cat", 0, 6), (~"cap1", 0, 6), (~"a", 1, 2),
+        (~"cap1", 0, 6), (~"a", 1, 2)));
+}
+
+#[test]
+fn syntax_spans_escapes_and_classes() {
+    assert_eq!(spans(r"\pN[a\d]\x{41}"), vec!(
+        (~"cat", 0, 14), (~"other", 0, 3), (~"alt", 3, 8),
+        (~"other", 5, 7), (~"other", 3, 8), (~"A", 8, 14)));
+}
+
+macro_rules! reprint(
+    ($name:ident, $re:expr, $printed:expr) => (
+        #[test]
+        fn $name() {
+            let printed = syntax::to_regexp(&*syntax::parse($re).unwrap());
+            assert_eq!(printed.as_slice(), $printed);
+            // Printing is idempotent.
+            let again = syntax::to_regexp(&*syntax::parse(printed).unwrap());
+            assert_eq!(again, printed);
+        }
+    );
+)
+
+reprint!(print_literals, r"a\.b\*\{", r"a\.b\*\{")
+reprint!(print_escapes, "\t\x01\x7F", r"\t\x{1}\x{7F}")
+reprint!(print_alt, "a|bc|(?:d|e)f", "a|bc|(?:d|e)f")
+reprint!(print_alt_nested, "(?:a|b)|c", "(?:a|b)|c")
+reprint!(print_rep, "(?:ab)*?c+(?:d*)?", "(?:ab)*?c+(?:d*)?")
+reprint!(print_rep_ungreedy_flag, "(?U)a*b*?", "a*?b*")
+reprint!(print_counted, "a{2,3}b{2,}", "aaa?bbb*")
+reprint!(print_counted_capture, "(a){2}", "(a){2}")
+reprint!(print_counted_capture_named, "(?P<x>a){2}", "(?P<x>a){2}")
+reprint!(print_counted_capture_range, "(a){1,3}?b", "(a){1,3}?b")
+reprint!(print_counted_capture_min, "(a){2,}", "(a){2,}")
+reprint!(print_counted_capture_optional, "(a){0,2}", "(?:(a)?){2}")
+reprint!(print_counted_capture_cat, "x(?:b(a)){2}c", "x(?:b(a)){2}c")
+reprint!(print_counted_capture_nested, "((a){2}){1,2}", "((a){2}){1,2}")
+
+#[test]
+fn print_counted_capture_reparse() {
+    // The copies of a group share it, so printing them one by one would
+    // add groups (or fail to parse with a duplicate name).
+    for re in ["(a){2}", "(?P<x>a){2}"].iter() {
+        let printed = syntax::to_regexp(&*syntax::parse(*re).unwrap());
+        let (orig, again) =
+            (Regexp::new(*re).unwrap(), Regexp::new(printed).unwrap());
+        assert_eq!(again.captures_len(), orig.captures_len());
+        assert_eq!(again.captures("aa").unwrap().pos(1), Some((1, 2)));
+    }
+}
+reprint!(print_nothing, "a{0}", "a{0}")
+reprint!(print_nothing_cat, "ab{0}c", "ac")
+reprint!(print_nothing_alt, "a|b{0}", "a|a{0}")
+reprint!(print_captures, "(a)(?P<foo>b(c))", "(a)(?P<foo>b(c))")
+reprint!(print_flags_lazy, "(?i)1a(?-i)2b", "1(?i)a2(?-i)b")
+reprint!(print_flags_combined, "(?is)a.(?i-s)b.", "(?i)a(?s).b(?-s).")
+reprint!(print_flags_group_scope, "((?i)a)a", "((?i)a)a")
+reprint!(print_flags_escape, r"(?i)\x{41}a", "A(?i)a")
+reprint!(print_anchors, "^a$", "^a$")
+reprint!(print_anchors_multi, "(?m)^a$\\A\\z", "(?m)^a$\\A\\z")
+reprint!(print_anchors_multi_off, "(?m)^(?-m)^", "(?m)^\\A")
+reprint!(print_boundaries, r"\b\B\b{w}\B{w}\X", r"\b\B\b{w}\B{w}\X")
+reprint!(print_perl_classes, r"\d\S\w", r"\d\S\w")
+reprint!(print_unicode_classes, r"\pN\P{Greek}", r"\p{N}\P{Greek}")
+reprint!(print_class_ranges, "[zyxa-cb]", "[a-cx-z]")
+reprint!(print_class_negated, "[^a]", "[^a]")
+reprint!(print_class_specials, r"[]a\[\^\\-]", r"[-\[-^a]")
+reprint!(print_class_special_ends, r"[]!--]", r"[]-!-,]")
+reprint!(print_class_raw_end, r"[Z-\]", r"[Z-\]")
+reprint!(print_class_nocase, "(?i)[a-z]", "(?i)[a-z]")
+reprint!(print_class_perl_inside, r"[a\d]", r"\d|[a]")
+
 macro_rules! replace(
     ($name:ident, $which:ident, $re:expr,
      $search:expr, $replace:expr, $result:expr) => (
@@ -99,6 +272,12 @@ noparse!(fail_double_neg, "(?-i-i)")
 noparse!(fail_neg_empty, "(?i-)")
 noparse!(fail_empty_group, "()")
 noparse!(fail_dupe_named, "(?P<a>.)(?P<a>.)")
+noparse!(fail_unclosed_comment, "a(?#b")
+noparse!(fail_class_no_grapheme, r"[\X]")
+noparse!(fail_class_no_unicode_boundary, r"[\b{w}]")
+noparse!(fail_unicode_boundary_repeat, r"\b{w}+")
+noparse!(fail_boundary_counted_repeat, r"\b{2}")
+noparse!(fail_only_comment_repeat, "(?#a)*")
 
 macro_rules! mat(
     ($name:ident, $re:expr, $text:expr, $($loc:tt)+) => (
@@ -164,6 +343,23 @@ mat!(match_flag_multi, "(?m)(?:^\\d+$\n?)+", "123\n456\n789", Some((0, 11)))
 mat!(match_flag_ungreedy, "(?U)a+", "aa", Some((0, 1)))
 mat!(match_flag_ungreedy_greedy, "(?U)a+?", "aa", Some((0, 2)))
 mat!(match_flag_ungreedy_noop, "(?U)(?-U)a+", "aa", Some((0, 2)))
+// Flags before a '-' are set and flags after it are cleared, and any other
+// flags keep their values. (Negating once cleared every flag.)
+mat!(match_flag_set_and_clear, "(?s)a(?i-s)a.", "aAb", Some((0, 3)))
+mat!(match_flag_set_and_clear_not, "(?s)a(?i-s)a.", "aA\n", None)
+mat!(match_flag_clear_keeps_others, "(?is)a(?-i).", "A\n", Some((0, 2)))
+mat!(match_flag_group_set_and_clear, "(?s)(?i-s:a.)", "Ab", Some((0, 2)))
+mat!(match_flag_group_set_and_clear_not, "(?s)(?i-s:a.)", "A\n", None)
+mat!(match_flag_group_set_and_clear_restore, "(?s)(?i-s:a).", "A\n",
+     Some((0, 2)))
+mat!(match_flag_group_clear_keeps_others, "(?im)(?-i:^a)", "b\na",
+     Some((2, 3)))
+
+// Comments are ignored entirely.
+mat!(match_comment, "a(?#comment)b", "ab", Some((0, 2)))
+mat!(match_comment_repeat, "a(?#comment)+", "aa", Some((0, 2)))
+mat!(match_comment_paren, r"(?#(a)(c)(d)", "cd", Some((0, 2)), Some((0, 1)), Some((1, 2)))
+mat!(match_comment_alt, "a(?#x|y)|b", "b", Some((0, 1)))
 
 // Some Unicode tests.
 mat!(uni_literal, r"Ⅰ", "Ⅰ", Some((0, 3)))
@@ -194,6 +390,622 @@ mat!(uni_perl_s_neg, r"\S+", "☃", Some((0, 3)))
 mat!(uni_boundary_none, r"\d\b", "6δ", None)
 mat!(uni_boundary_ogham, r"\d\b", "6 ", Some((0, 1)))
 
+// UAX#29 word boundaries.
+mat!(uni_wordbreak_ascii, r"^.+?\b", "don't stop", Some((0, 3)))
+mat!(uni_wordbreak_contraction, r"^.+?\b{w}", "don't stop", Some((0, 5)))
+mat!(uni_wordbreak_number, r"^.+?\b{w}", "3.14 x", Some((0, 4)))
+mat!(uni_wordbreak_katakana, r"^.+?\b{w}", "カタカナ漢字", Some((0, 12)))
+mat!(uni_wordbreak_extend, r"^a\b{w}", "a\u0301", None)
+mat!(uni_wordbreak_regional, r"^.+?\b{w}",
+     "\U0001F1FA\U0001F1F8x", Some((0, 8)))
+mat!(uni_wordbreak_crlf, r"\r\B{w}\n", "\r\n", Some((0, 2)))
+mat!(uni_wordbreak_not, r"a\B{w}'", "a'b", Some((0, 2)))
+mat!(uni_wordbreak_not_end, r"a\B{w}'", "a' b", None)
+mat!(uni_wordbreak_end, r"x\b{w}", "x", Some((0, 1)))
+mat!(uni_wordbreak_empty, r"\b{w}", "", None)
+
+// Extended grapheme clusters.
+mat!(uni_grapheme_ascii, r"\X", "abc", Some((0, 1)))
+mat!(uni_grapheme_combining, r"\X", "e\u0301\u0302x", Some((0, 5)))
+mat!(uni_grapheme_crlf, r"\X", "\r\nx", Some((0, 2)))
+mat!(uni_grapheme_control, r"\X", "\r\u0301", Some((0, 1)))
+mat!(uni_grapheme_hangul, r"^\X$", "\u1100\u1161\u11a8", Some((0, 9)))
+mat!(uni_grapheme_regional, r"^\X$", "\U0001F1FA\U0001F1F8", Some((0, 8)))
+mat!(uni_grapheme_spacing_mark, r"^\X$", "\u0915\u093f", Some((0, 6)))
+// U+0600 is a Control in Unicode 6.3. It only became Prepend in 9.0.
+mat!(uni_grapheme_no_prepend, r"\X", "\u0600\u0661", Some((0, 2)))
+mat!(uni_grapheme_repeat, r"^\X{2}$", "a\u0301b", Some((0, 4)))
+mat!(uni_grapheme_not_one, r"^\X$", "ab", None)
+mat!(uni_grapheme_capture, r"(\X)(\X)", "e\u0301e", Some((0, 4)), Some((0, 3)), Some((3, 4)))
+
+// Alternations of literals compile to a single branch instruction.
+mat!(match_branch_words, r"\b(?:foo|bar|baz|quux)\b", "a baz b", Some((2, 5)))
+mat!(match_branch_prefix_first, r"ab|abc|x", "abc", Some((0, 2)))
+mat!(match_branch_prefix_last, r"abc|ab|x", "abc", Some((0, 3)))
+mat!(match_branch_unsorted, r"x|b1|a|b2", "b2", Some((0, 2)))
+mat!(match_branch_after_split, r".z|ab|ac", "ac", Some((0, 2)))
+mat!(match_branch_captures, r"a(x)|b(y)|c(z)", "by", Some((0, 2)), None, None, Some((1, 2)), None, None)
+mat!(match_branch_shared, r"ab|a|ac", "ac", Some((0, 1)))
+mat!(match_branch_before_split, r"ab|cd|.z|ef", "ef", Some((0, 2)))
+mat!(match_branch_nocase, r"(?i)foo|bar|baz", "xBaZ", Some((1, 4)))
+mat!(match_branch_nocase_mixed, r"a|(?i:b)|B|c", "B", Some((0, 1)))
+
+// Runs of literal characters.
+mat!(match_literal_overlap, r"aab", "aaab", Some((1, 4)))
+mat!(match_literal_nocase, r"(?i)hello", "xHeLLo", Some((1, 6)))
+mat!(match_literal_truncated, r"abc", "xab", None)
+mat!(match_literal_unicode, "\u2603\u00e9", "x\u2603\u00e9", Some((1, 6)))
+mat!(match_literal_capture, r"a(bc)d", "abcd", Some((0, 4)), Some((1, 3)))
+mat!(match_literal_restart, r"aaab", "aaaaab", Some((2, 6)))
+mat!(match_literal_loop, r"(?:ab)+c", "abababc", Some((0, 7)))
+mat!(match_literal_suffix, r"abc$", "abcabc", Some((3, 6)))
+mat!(match_literal_nocase_unicode, "(?i)\u00e9\u00e9x", "\u00c9\u00e9X", Some((0, 5)))
+
+// Prefilters skip to where literals occur or give up without running.
+mat!(match_prefilter_alt, r"foo|bar", "xxbarxxfoo", Some((2, 5)))
+mat!(match_prefilter_nocase, r"(?i)error", "an ErRoR!", Some((3, 8)))
+mat!(match_prefilter_dotless_i, r"(?i)i", "\u0131", Some((0, 2)))
+mat!(match_prefilter_long_s, r"(?i)s", "\u017f", Some((0, 2)))
+mat!(match_prefilter_required, r"\d+ms timeout", "after 250ms timeout", Some((6, 19)))
+mat!(match_prefilter_required_missing, r"\d+ms timeout", "after 250ms", None)
+mat!(match_prefilter_suffix_missing, r"\w+(?:ing|ed)", "walks", None)
+
+// Expressions ending with `$` are searched backwards from the end.
+mat!(match_suffix_anchor, r"[a-z]+\.(?:jpg|png)$", "a.png bc.jpg", Some((6, 12)))
+mat!(match_suffix_anchor_groups, r"(\w+)\.(\w+)$", "x foo.bar", Some((2, 9)), Some((2, 5)), Some((6, 9)))
+mat!(match_suffix_anchor_first, r"(a|ab)(c|bcd)?$", "xabcd", Some((1, 5)), Some((1, 2)), Some((2, 5)))
+mat!(match_suffix_anchor_empty, r"$", "ab", Some((2, 2)))
+mat!(match_suffix_anchor_star, r"a*$", "baa", Some((1, 3)))
+mat!(match_suffix_anchor_none, r"a$", "ab", None)
+mat!(match_suffix_anchor_alt, r"a$|b", "ab", Some((1, 2)))
+
+#[test]
+fn suffix_anchor_dynamic() {
+    let re = Regexp::new(r"(?:a|b)+\.log$").unwrap();
+    let text = "a.log ab.log\nba.log";
+    assert!(re.is_match(text));
+    assert_eq!(re.find_pos(text), Some((13, 19)));
+    assert_eq!(re.find_iter_pos(text).collect::<Vec<(uint, uint)>>(),
+               vec!((13, 19)));
+    assert_eq!(re.captures(text).unwrap().pos(0), Some((13, 19)));
+    assert!(!re.is_match("a.log "));
+    assert_eq!(re.find_pos("a.log "), None);
+}
+
+#[test]
+fn prefilter_find_iter() {
+    let re = regexp!(r"\d+ms");
+    let ms: Vec<(uint, uint)> =
+        re.find_iter_pos("1ms 22ms x 333ms").collect();
+    assert_eq!(ms, vec!((0, 3), (4, 8), (11, 16)));
+}
+
+#[test]
+fn branch_dictionary() {
+    let words: Vec<~str> = range(0u, 2000).map(|i| {
+        format!("w{}x", (i * 7919) % 100000)
+    }).collect();
+    let re = Regexp::new("^(?:" + words.connect("|") + ")$").unwrap();
+    for word in words.iter() {
+        assert!(re.is_match(*word));
+    }
+    assert!(!re.is_match("w7919"));
+    assert!(!re.is_match("w7919xx"));
+
+    let re = Regexp::new("(?i)" + words.connect("|")).unwrap();
+    let text = words.as_slice().slice_to(100).connect(" ");
+    let text: ~str = text.chars().map(|c| c.to_uppercase()).collect();
+    let got: Vec<(uint, uint)> =
+        re.rfind_iter(text.as_slice()).map(|m| m.range()).collect();
+    let mut want: Vec<(uint, uint)> =
+        re.find_iter(text.as_slice()).map(|m| m.range()).collect();
+    want.reverse();
+    assert_eq!(got.len(), 100);
+    assert_eq!(got, want);
+}
+
+#[test]
+fn matcher_reuse() {
+    let re = regexp!(r"(\w+)=(\d+)");
+    let mut matcher = re.matcher();
+    let texts = ["a=1", "nothing", "x bb=22 y", "", "c=3 d=4"];
+    for text in texts.iter() {
+        assert_eq!(matcher.is_match(*text), re.is_match(*text));
+        assert_eq!(matcher.find(*text), re.find(*text));
+        assert_eq!(positions(matcher.captures(*text)),
+                   positions(re.captures(*text)));
+    }
+}
+
+#[test]
+fn match_values() {
+    let text = "x 12 345";
+    let m = regexp!(r"\d+").find(text).unwrap();
+    assert_eq!((m.start(), m.end()), (2, 4));
+    assert_eq!(m.range(), (2, 4));
+    assert_eq!(m.as_str(), "12");
+    let ms: Vec<&str> = regexp!(r"\d+").find_iter(text)
+                                       .map(|m| m.as_str())
+                                       .collect();
+    assert_eq!(ms, vec!("12", "345"));
+    let re = Regexp::new(r"\d+").unwrap();
+    assert_eq!(re.find_pos(text), Some((2, 4)));
+    let ps: Vec<(uint, uint)> = re.find_iter_pos(text).collect();
+    assert_eq!(ps, vec!((2, 4), (5, 8)));
+}
+
+#[test]
+fn captures_submatches() {
+    let re = regexp!(r"([a-z]+)(-)?(\d+)");
+    let caps = re.captures("ab12").unwrap();
+    assert_eq!(caps.submatch(1).map(|m| m.as_str()), Some("ab"));
+    assert_eq!(caps.submatch(2), None);
+    assert_eq!(caps.submatch(3).map(|m| m.range()), Some((2, 4)));
+    assert_eq!(caps.submatch(4), None);
+    let got: Vec<Option<&str>> =
+        caps.iter_submatches().map(|m| m.map(|m| m.as_str())).collect();
+    assert_eq!(got, vec!(Some("ab12"), Some("ab"), None, Some("12")));
+}
+
+#[test]
+fn capture_names() {
+    let check = |re: &Regexp| {
+        let names: Vec<Option<&str>> = re.capture_names().collect();
+        assert_eq!(names, vec!(None, Some("a"), None, Some("c")));
+        assert_eq!(re.captures_len(), 4);
+        assert_eq!(re.capture_index("a"), Some(1));
+        assert_eq!(re.capture_index("c"), Some(3));
+        assert_eq!(re.capture_index("b"), None);
+        assert_eq!(re.captures("xyz").unwrap().name("c"), "z");
+    };
+    check(&regexp!(r"(?P<a>x)(y)(?P<c>z)"));
+    check(&Regexp::new(r"(?P<a>x)(y)(?P<c>z)").unwrap());
+}
+
+#[test]
+fn capture_index_unsorted() {
+    // The names are looked up in sorted order, which isn't the order of
+    // the groups.
+    let check = |re: &Regexp| {
+        let names = ["zed", "b", "mid", "a", "yy"];
+        for (i, name) in names.iter().enumerate() {
+            assert_eq!(re.capture_index(*name), Some(i + 1));
+        }
+        assert_eq!(re.capture_index("c"), None);
+        assert_eq!(re.capture_index("zz"), None);
+        let caps = re.captures("12345").unwrap();
+        let named: Vec<(&str, Option<&str>)> = caps.iter_named().collect();
+        assert_eq!(named, vec!(("zed", Some("1")), ("b", Some("2")),
+                               ("mid", Some("3")), ("a", Some("4")),
+                               ("yy", Some("5"))));
+    };
+    let re = r"(?P<zed>.)(?P<b>.)(?P<mid>.)(?P<a>.)(?P<yy>.)";
+    check(&regexp!(r"(?P<zed>.)(?P<b>.)(?P<mid>.)(?P<a>.)(?P<yy>.)"));
+    check(&Regexp::new(re).unwrap());
+}
+
+#[test]
+fn capture_names_none() {
+    let re = regexp!(r"a(b)");
+    let names: Vec<Option<&str>> = re.capture_names().collect();
+    assert_eq!(names, vec!(None, None));
+    assert_eq!(re.captures_len(), 2);
+    assert_eq!(re.capture_index("b"), None);
+    assert_eq!(Regexp::new("a").unwrap().captures_len(), 1);
+}
+
+#[test]
+fn captures_iter_named() {
+    let check = |re: &Regexp| {
+        let caps = re.captures("k=").unwrap();
+        let named: Vec<(&str, Option<&str>)> = caps.iter_named().collect();
+        assert_eq!(named, vec!(("key", Some("k")), ("val", Some("")),
+                               ("note", None)));
+    };
+    check(&regexp!(r"(?P<key>\w+)(=)(?P<val>\w*)(?P<note>#.*)?"));
+    check(&Regexp::new(r"(?P<key>\w+)(=)(?P<val>\w*)(?P<note>#.*)?").unwrap());
+    let caps = regexp!(r"(a)(b)").captures("ab").unwrap();
+    assert_eq!(caps.iter_named().next(), None);
+}
+
+#[test]
+fn captures_to_map() {
+    let re = regexp!(r"(?P<key>\w+)=(?P<val>\w*)(?P<note>#.*)?");
+    let map = re.captures("k=").unwrap().to_map();
+    let mut expected = HashMap::new();
+    expected.insert(~"key", ~"k");
+    expected.insert(~"val", ~"");
+    assert_eq!(map, expected);
+    assert!(regexp!(r"a").captures("a").unwrap().to_map().is_empty());
+}
+
+#[test]
+fn captures_get() {
+    let check = |re: &Regexp| {
+        let caps = re.captures("host:").unwrap();
+        assert_eq!(caps.get(1), Some("host"));
+        assert_eq!(caps.get_name("port"), Some(""));
+        assert_eq!(caps.name("port"), "");
+        let caps = re.captures("host").unwrap();
+        assert_eq!(caps.get(2), None);
+        assert_eq!(caps.get_name("port"), None);
+        assert_eq!(caps.name("port"), "");
+        assert_eq!(caps.get(3), None);
+        assert_eq!(caps.get_name("nope"), None);
+    };
+    check(&regexp!(r"(?P<host>\w+)(?::(?P<port>\d*))?"));
+    check(&Regexp::new(r"(?P<host>\w+)(?::(?P<port>\d*))?").unwrap());
+}
+
+fn positions(caps: Option<Captures>) -> Option<Vec<Option<(uint, uint)>>> {
+    caps.map(|caps| range(0, caps.len()).map(|i| caps.pos(i)).collect())
+}
+
+#[test]
+fn matcher_reuse_kinds() {
+    // Searches that need different amounts of capture information can
+    // share the same matcher.
+    let re = Regexp::new(r"(a)(b)?c").unwrap();
+    let mut matcher = re.matcher();
+    assert_eq!(matcher.captures("xabc").unwrap().pos(2), Some((2, 3)));
+    assert_eq!(matcher.find("xxac").unwrap().range(), (2, 4));
+    assert!(matcher.captures("xxac").unwrap().pos(2).is_none());
+    assert!(!matcher.is_match("ab"));
+}
+
+#[test]
+fn matcher_grows() {
+    // The room for capture groups grows as searches need it, including in
+    // the memory for the reversed program of a regexp that ends with `$`.
+    let re = Regexp::new(r"(a)(b)?c$").unwrap();
+    let mut matcher = re.matcher();
+    assert!(matcher.is_match("xabc"));
+    assert_eq!(matcher.find("xxac").unwrap().range(), (2, 4));
+    assert_eq!(matcher.captures("xabc").unwrap().pos(2), Some((2, 3)));
+    assert!(!matcher.is_match("abcx"));
+}
+
+macro_rules! rfind(
+    ($name:ident, $re:expr, $text:expr, $($loc:tt)+) => (
+        #[test]
+        fn $name() {
+            let expected: Vec<(uint, uint)> = vec!($($loc)+);
+            let got: Vec<(uint, uint)> =
+                regexp!($re).rfind_iter($text).map(|m| m.range()).collect();
+            assert_eq!(got, expected);
+            let got: Vec<(uint, uint)> = Regexp::new($re).unwrap()
+                .rfind_iter($text).map(|m| m.range()).collect();
+            assert_eq!(got, expected);
+        }
+    );
+)
+
+rfind!(rfind_digits, r"\d+", "1 22 333", (5, 8), (2, 4), (0, 1))
+rfind!(rfind_greedy, "a+", "aaa", (0, 3))
+rfind!(rfind_lazy, "a+?", "aaa", (2, 3), (1, 2), (0, 1))
+rfind!(rfind_empty, "a*", "baa", (1, 3), (0, 0))
+rfind!(rfind_begin, "^a", "aaa", (0, 1))
+rfind!(rfind_end, "a$", "aaa", (2, 3))
+rfind!(rfind_multi, r"(?m)^\w+$", "ab\ncd\nef", (6, 8), (3, 5), (0, 2))
+rfind!(rfind_word, r"\bfoo\b", "foo foobar foo", (11, 14), (0, 3))
+rfind!(rfind_literal, "abc", "abcxabcx", (4, 7), (0, 3))
+rfind!(rfind_literal_nocase, "(?i)abc", "ABCxAbC", (4, 7), (0, 3))
+rfind!(rfind_alt, "ab|b", "abab", (2, 4), (0, 2))
+rfind!(rfind_unicode, "☃+", "x☃☃y☃", (8, 11), (1, 7))
+rfind!(rfind_class, "[a-c]+[0-9]", "a1 bb2 x", (3, 6), (0, 2))
+// Alternatives that start differently can still end at the same position.
+rfind!(rfind_alt_order, "b|ab", "ab", (1, 2))
+rfind!(rfind_alt_order_swapped, "ab|b", "ab", (0, 2))
+rfind!(rfind_alt_order_suffix, "(?:b|ab)$", "ab", (1, 2))
+rfind!(rfind_branch_shared, "xb|b|cb", "cb", (1, 2))
+rfind!(rfind_branch_shared_swapped, "xb|cb|b", "cb", (0, 2))
+rfind!(rfind_branch_nocase, "(?i)ab|cd", "xABcD", (3, 5), (1, 3))
+
+#[test]
+fn rfind_none() {
+    assert_eq!(regexp!("z").rfind("abc"), None);
+    assert_eq!(Regexp::new("z").unwrap().rfind("abc"), None);
+}
+
+#[test]
+fn rfind_reuses_reversed() {
+    // The reversed program is compiled by the first search and shared with
+    // clones of the regexp.
+    let re = Regexp::new(r"\d+").unwrap();
+    let cloned = re.clone();
+    assert_eq!(re.rfind("1 22").map(|m| m.range()), Some((2, 4)));
+    assert_eq!(re.rfind("333 x").map(|m| m.range()), Some((0, 3)));
+    assert_eq!(cloned.rfind("4 5").map(|m| m.range()), Some((2, 3)));
+}
+
+macro_rules! anchored(
+    ($name:ident, $method:ident, $re:expr, $text:expr, $expected:expr) => (
+        #[test]
+        fn $name() {
+            assert_eq!(regexp!($re).$method($text), $expected);
+            assert_eq!(Regexp::new($re).unwrap().$method($text), $expected);
+        }
+    );
+)
+
+anchored!(at_start, is_match_at_start, r"\d+", "12a", true)
+anchored!(at_start_not, is_match_at_start, r"\d+", "a12", false)
+anchored!(at_start_multi, is_match_at_start, r"(?m)^b", "a\nb", false)
+anchored!(full, is_full_match, "a|ab", "ab", true)
+anchored!(full_not, is_full_match, "a|ab", "abc", false)
+anchored!(full_empty, is_full_match, "a*", "", true)
+anchored!(full_multi, is_full_match, "(?m)a$", "a\n", false)
+anchored!(full_suffix, is_full_match, "b$", "ab", false)
+
+macro_rules! anchored_find(
+    ($name:ident, $re:expr, $text:expr, $expected:expr) => (
+        #[test]
+        fn $name() {
+            let expected: Option<(uint, uint)> = $expected;
+            let got = regexp!($re).find_at_start($text).map(|m| m.range());
+            assert_eq!(got, expected);
+            let got = Regexp::new($re).unwrap().find_at_start($text)
+                                              .map(|m| m.range());
+            assert_eq!(got, expected);
+        }
+    );
+)
+
+anchored_find!(at_start_find, r"\d+", "12a3", Some((0, 2)))
+anchored_find!(at_start_find_empty, r"\d*", "a1", Some((0, 0)))
+anchored_find!(at_start_find_prefix, "abc", "xabc", None)
+anchored_find!(at_start_find_suffix, "b$", "ab", None)
+
+#[test]
+fn captures_at_start() {
+    let re = regexp!(r"(\d+)-(\d+)");
+    assert_eq!(positions(re.captures_at_start("1-2 3-4")),
+               Some(vec!(Some((0, 3)), Some((0, 1)), Some((2, 3)))));
+    assert!(re.captures_at_start("x1-2").is_none());
+}
+
+#[test]
+fn captures_full() {
+    let re = regexp!(r"(a|ab)(c|bcd)?");
+    assert_eq!(positions(re.captures_full("abcd")),
+               Some(vec!(Some((0, 4)), Some((0, 1)), Some((1, 4)))));
+    // The first alternative only matches a prefix of `abc`.
+    assert_eq!(positions(re.captures_full("abc")),
+               Some(vec!(Some((0, 3)), Some((0, 2)), Some((2, 3)))));
+    assert!(re.captures_full("abcdx").is_none());
+}
+
+macro_rules! find_at(
+    ($name:ident, $re:expr, $text:expr, $pos:expr, $expected:expr) => (
+        #[test]
+        fn $name() {
+            let expected: Option<(uint, uint)> = $expected;
+            let got = regexp!($re).find_at($text, $pos).map(|m| m.range());
+            assert_eq!(got, expected);
+            let got = Regexp::new($re).unwrap().find_at($text, $pos)
+                                              .map(|m| m.range());
+            assert_eq!(got, expected);
+            assert_eq!(regexp!($re).is_match_at($text, $pos),
+                       expected.is_some());
+        }
+    );
+)
+
+find_at!(find_at_start, r"\d+", "12 34", 0, Some((0, 2)))
+find_at!(find_at_offset, r"\d+", "12 34", 2, Some((3, 5)))
+find_at!(find_at_middle, r"\d+", "12 34", 1, Some((1, 2)))
+find_at!(find_at_word_context, r"\b\w+", "foo bar", 1, Some((4, 7)))
+find_at!(find_at_begin_context, r"^a", "aa", 1, None)
+find_at!(find_at_multi_context, r"(?m)^a", "b\na", 2, Some((2, 3)))
+find_at!(find_at_end, r"a*", "aa", 2, Some((2, 2)))
+find_at!(find_at_past_end, r"a*", "aa", 3, None)
+find_at!(find_at_suffix_anchor, r"a$", "a", 1, None)
+find_at!(find_at_prefix, "ab", "abab", 1, Some((2, 4)))
+
+#[test]
+fn captures_at() {
+    let re = regexp!(r"(\w)=(\d)");
+    let caps = re.captures_at("a=1 b=2", 1).unwrap();
+    assert_eq!(caps.pos(0), Some((4, 7)));
+    assert_eq!(caps.at(1), "b");
+    assert_eq!(caps.at(2), "2");
+    assert!(re.captures_at("a=1 b=2", 5).is_none());
+}
+
+fn read_all(re: &Regexp, text: &str) -> Vec<Vec<Option<(uint, uint)>>> {
+    let mut locs = re.capture_locations();
+    let mut matcher = re.matcher();
+    let mut matcher_locs = re.capture_locations();
+    let mut all = vec!();
+    let mut start = 0;
+    loop {
+        let m = re.read_captures_at(&mut locs, text, start);
+        let mm = matcher.read_captures_at(&mut matcher_locs, text, start);
+        assert_eq!(m, mm);
+        let got: Vec<Option<(uint, uint)>> =
+            range(0, locs.len()).map(|i| locs.pos(i)).collect();
+        let matcher_got: Vec<Option<(uint, uint)>> =
+            range(0, matcher_locs.len()).map(|i| matcher_locs.pos(i)).collect();
+        assert_eq!(got, matcher_got);
+        match m {
+            None => {
+                assert!(got.iter().all(|pos| pos.is_none()));
+                return all
+            }
+            Some(m) => {
+                assert_eq!(got.get(0), &Some(m.range()));
+                all.push(got);
+                start = m.end();
+            }
+        }
+    }
+}
+
+#[test]
+fn read_captures_at() {
+    // The second match has to clear the group set by the first one.
+    let expected = vec!(vec!(Some((0, 4)), Some((0, 1)), Some((2, 4))),
+                        vec!(Some((5, 7)), Some((5, 6)), None));
+    assert_eq!(read_all(&regexp!(r"(\w)=(\d\d)?"), "b=22 a=1"), expected);
+    let re = Regexp::new(r"(\w)=(\d\d)?").unwrap();
+    assert_eq!(read_all(&re, "b=22 a=1"), expected);
+}
+
+#[test]
+#[should_fail]
+fn read_captures_at_other_locations() {
+    let mut locs = regexp!(r"(a)").capture_locations();
+    regexp!(r"(a)(b)").read_captures_at(&mut locs, "ab", 0);
+}
+
+#[test]
+#[should_fail]
+fn matcher_read_captures_at_other_locations() {
+    let re = Regexp::new(r"(a)(b)").unwrap();
+    let mut locs = Regexp::new(r"(a)").unwrap().capture_locations();
+    re.matcher().read_captures_at(&mut locs, "ab", 0);
+}
+
+#[test]
+fn capture_locations_len() {
+    assert_eq!(regexp!(r"a").capture_locations().len(), 1);
+    assert_eq!(regexp!(r"(a)(?P<b>b)?").capture_locations().len(), 3);
+    assert_eq!(Regexp::new(r"(a)((b))").unwrap().capture_locations().len(), 4);
+}
+
+macro_rules! shortest(
+    ($name:ident, $re:expr, $text:expr, $expected:expr) => (
+        #[test]
+        fn $name() {
+            let expected: Option<uint> = $expected;
+            assert_eq!(regexp!($re).shortest_match($text), expected);
+            assert_eq!(Regexp::new($re).unwrap().shortest_match($text),
+                       expected);
+        }
+    );
+)
+
+shortest!(shortest_plus, r"a+", "xaaa", Some(2))
+shortest!(shortest_alt, r"ab|a", "ab", Some(1))
+shortest!(shortest_ends_first, r"abc|b", "abc", Some(2))
+shortest!(shortest_empty, r"a*", "aaa", Some(0))
+shortest!(shortest_empty_text, r"", "", Some(0))
+shortest!(shortest_suffix_anchor, r"a+$", "baa", Some(3))
+shortest!(shortest_word_boundary, r"\w+\b", "foo bar", Some(3))
+shortest!(shortest_none, r"a+", "bbb", None)
+
+#[test]
+fn scanner_tokens() {
+    let (ident, num, op, space) = (regexp!(r"[a-z]+"), regexp!(r"\d+"),
+                                   regexp!(r"[-+=]"), regexp!(r"\s+"));
+    let mut scanner = Scanner::new("x = 12 + y");
+    let mut tokens = vec!();
+    while !scanner.is_eos() {
+        scanner.scan(&space);
+        let token = [&ident, &num, &op].iter()
+                                       .filter_map(|re| scanner.scan(*re))
+                                       .next();
+        match token {
+            Some(token) => tokens.push(token),
+            None => fail!("no token at {}", scanner.pos()),
+        }
+    }
+    assert_eq!(tokens, vec!("x", "=", "12", "+", "y"));
+}
+
+#[test]
+fn scanner_anchored() {
+    let mut scanner = Scanner::new("ab12");
+    assert_eq!(scanner.scan(&regexp!(r"\d+")), None);
+    assert_eq!(scanner.pos(), 0);
+    assert_eq!(scanner.check(&regexp!(r"\w")), Some("a"));
+    assert_eq!(scanner.pos(), 0);
+    assert_eq!(scanner.scan(&regexp!(r"[a-z]*")), Some("ab"));
+    assert_eq!(scanner.rest(), "12");
+    // Assertions see the text that was already scanned.
+    assert_eq!(scanner.scan(&regexp!(r"\b\d")), None);
+    assert_eq!(scanner.scan(&regexp!(r"^\d")), None);
+    assert_eq!(scanner.scan(&regexp!(r"\d+$")), Some("12"));
+    assert!(scanner.is_eos());
+    assert_eq!(scanner.scan(&regexp!(r"x*")), Some(""));
+}
+
+#[test]
+fn scanner_skip_until() {
+    let mut scanner = Scanner::new("key: value; rest");
+    assert_eq!(scanner.skip_until(&regexp!(r":\s*")), Some(5));
+    assert_eq!(scanner.scan(&regexp!(r"[^;]*")), Some("value"));
+    assert_eq!(scanner.skip_until(&regexp!(r"z")), None);
+    assert_eq!(scanner.pos(), 10);
+    assert_eq!(scanner.skip_until(&regexp!(r"\s")), Some(2));
+    assert_eq!(scanner.rest(), "rest");
+}
+
+fn lex(tokens: &[(&'static str, &str)], text: &str)
+      -> Vec<(Lexeme<&'static str>, (uint, uint))> {
+    Lexer::new(tokens).unwrap().tokens(text).collect()
+}
+
+#[test]
+fn lexer_maximal_munch() {
+    let tokens = [("if", "if"), ("ident", "[a-z]+"), ("eq", "="),
+                  ("eqeq", "=="), ("space", r"\s+")];
+    assert_eq!(lex(tokens, "if iffy==x"), vec!(
+        (Token("if"), (0, 2)), (Token("space"), (2, 3)),
+        (Token("ident"), (3, 7)), (Token("eqeq"), (7, 9)),
+        (Token("ident"), (9, 10))));
+}
+
+#[test]
+fn lexer_groups() {
+    // Capture groups in the patterns don't get mixed up with the groups
+    // that tell the patterns apart.
+    let tokens = [("str", r#""(\w*)""#), ("num", r"(\d)+"), ("ident", r"\w+"),
+                  ("space", " ")];
+    assert_eq!(lex(tokens, r#""ab" 12 1x"#), vec!(
+        (Token("str"), (0, 4)), (Token("space"), (4, 5)),
+        (Token("num"), (5, 7)), (Token("space"), (7, 8)),
+        (Token("ident"), (8, 10))));
+}
+
+#[test]
+fn lexer_same_group_names() {
+    // Group names only have to be unique within each pattern, and counted
+    // repetitions of groups don't add groups.
+    let tokens = [("num", r"(?P<x>\d){2}"), ("word", r"(?P<x>[a-z])+"),
+                  ("space", " ")];
+    assert_eq!(lex(tokens, "12 ab"), vec!(
+        (Token("num"), (0, 2)), (Token("space"), (2, 3)),
+        (Token("word"), (3, 5))));
+}
+
+#[test]
+fn lexer_no_token() {
+    let tokens = [("word", "[a-z]+"), ("maybe", "x*")];
+    assert_eq!(lex(tokens, "a?☃b"), vec!(
+        (Token("word"), (0, 1)), (NoToken, (1, 2)), (NoToken, (2, 5)),
+        (Token("word"), (5, 6))));
+    assert_eq!(lex([], "ab"), vec!((NoToken, (0, 1)), (NoToken, (1, 2))));
+    assert_eq!(lex(tokens, ""), vec!());
+}
+
+#[test]
+fn lexer_context() {
+    // Assertions see the text before each token.
+    let tokens = [("first", r"^\w+"), ("word", r"\w+"), ("space", " ")];
+    assert_eq!(lex(tokens, "ab cd"), vec!(
+        (Token("first"), (0, 2)), (Token("space"), (2, 3)),
+        (Token("word"), (3, 5))));
+}
+
+#[test]
+fn lexer_error() {
+    match Lexer::new([("ok", "a"), ("bad", "b)")]) {
+        Ok(_) => fail!("a pattern with an unopened group should fail"),
+        Err(err) => assert_eq!(err.pos, 1),
+    }
+}
+
 // A whole mess of tests from Glenn Fowler's regexp test suite.
 // Generated by the 'src/etc/regexp-match-tests' program.
 mod matches;
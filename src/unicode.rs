@@ -5535,3 +5535,2001 @@ pub static PERLW: Class = &[
     ('\U0002f800', '\U0002fa1d')
 ];
 
+/// The values of the Grapheme_Cluster_Break property from UAX#29.
+#[allow(non_camel_case_types)]
+#[deriving(Show, Eq)]
+pub enum GraphemeCat {
+    GC_CR,
+    GC_LF,
+    GC_Control,
+    GC_Extend,
+    GC_RegionalIndicator,
+    GC_Prepend,
+    GC_SpacingMark,
+    GC_L,
+    GC_V,
+    GC_T,
+    GC_LV,
+    GC_LVT,
+    GC_Any,
+}
+
+/// Returns the Grapheme_Cluster_Break property value of `c`.
+pub fn grapheme_category(c: char) -> GraphemeCat {
+    match GRAPHEME_CAT_TABLE.bsearch(|&(s, e, _)| range_cmp(c, s, e)) {
+        None => GC_Any,
+        Some(i) => {
+            let (_, _, cat) = GRAPHEME_CAT_TABLE[i];
+            cat
+        }
+    }
+}
+
+fn range_cmp(c: char, start: char, end: char) -> Ordering {
+    if c >= start && c <= end {
+        Equal
+    } else if start > c {
+        Greater
+    } else {
+        Less
+    }
+}
+
+static GRAPHEME_CAT_TABLE: &'static [(char, char, GraphemeCat)] = &[
+    ('\U00000000', '\U00000009', GC_Control),
+    ('\U0000000a', '\U0000000a', GC_LF),
+    ('\U0000000b', '\U0000000c', GC_Control),
+    ('\U0000000d', '\U0000000d', GC_CR),
+    ('\U0000000e', '\U0000001f', GC_Control),
+    ('\U0000007f', '\U0000009f', GC_Control),
+    ('\U000000ad', '\U000000ad', GC_Control),
+    ('\U00000300', '\U0000036f', GC_Extend),
+    ('\U00000483', '\U00000489', GC_Extend),
+    ('\U00000591', '\U000005bd', GC_Extend),
+    ('\U000005bf', '\U000005bf', GC_Extend),
+    ('\U000005c1', '\U000005c2', GC_Extend),
+    ('\U000005c4', '\U000005c5', GC_Extend),
+    ('\U000005c7', '\U000005c7', GC_Extend),
+    ('\U00000600', '\U00000604', GC_Control),
+    ('\U00000610', '\U0000061a', GC_Extend),
+    ('\U0000061c', '\U0000061c', GC_Control),
+    ('\U0000064b', '\U0000065f', GC_Extend),
+    ('\U00000670', '\U00000670', GC_Extend),
+    ('\U000006d6', '\U000006dc', GC_Extend),
+    ('\U000006dd', '\U000006dd', GC_Control),
+    ('\U000006df', '\U000006e4', GC_Extend),
+    ('\U000006e7', '\U000006e8', GC_Extend),
+    ('\U000006ea', '\U000006ed', GC_Extend),
+    ('\U0000070f', '\U0000070f', GC_Control),
+    ('\U00000711', '\U00000711', GC_Extend),
+    ('\U00000730', '\U0000074a', GC_Extend),
+    ('\U000007a6', '\U000007b0', GC_Extend),
+    ('\U000007eb', '\U000007f3', GC_Extend),
+    ('\U00000816', '\U00000819', GC_Extend),
+    ('\U0000081b', '\U00000823', GC_Extend),
+    ('\U00000825', '\U00000827', GC_Extend),
+    ('\U00000829', '\U0000082d', GC_Extend),
+    ('\U00000859', '\U0000085b', GC_Extend),
+    ('\U000008e4', '\U000008fe', GC_Extend),
+    ('\U00000900', '\U00000902', GC_Extend),
+    ('\U00000903', '\U00000903', GC_SpacingMark),
+    ('\U0000093a', '\U0000093a', GC_Extend),
+    ('\U0000093b', '\U0000093b', GC_SpacingMark),
+    ('\U0000093c', '\U0000093c', GC_Extend),
+    ('\U0000093e', '\U00000940', GC_SpacingMark),
+    ('\U00000941', '\U00000948', GC_Extend),
+    ('\U00000949', '\U0000094c', GC_SpacingMark),
+    ('\U0000094d', '\U0000094d', GC_Extend),
+    ('\U0000094e', '\U0000094f', GC_SpacingMark),
+    ('\U00000951', '\U00000957', GC_Extend),
+    ('\U00000962', '\U00000963', GC_Extend),
+    ('\U00000981', '\U00000981', GC_Extend),
+    ('\U00000982', '\U00000983', GC_SpacingMark),
+    ('\U000009bc', '\U000009bc', GC_Extend),
+    ('\U000009be', '\U000009be', GC_Extend),
+    ('\U000009bf', '\U000009c0', GC_SpacingMark),
+    ('\U000009c1', '\U000009c4', GC_Extend),
+    ('\U000009c7', '\U000009c8', GC_SpacingMark),
+    ('\U000009cb', '\U000009cc', GC_SpacingMark),
+    ('\U000009cd', '\U000009cd', GC_Extend),
+    ('\U000009d7', '\U000009d7', GC_Extend),
+    ('\U000009e2', '\U000009e3', GC_Extend),
+    ('\U00000a01', '\U00000a02', GC_Extend),
+    ('\U00000a03', '\U00000a03', GC_SpacingMark),
+    ('\U00000a3c', '\U00000a3c', GC_Extend),
+    ('\U00000a3e', '\U00000a40', GC_SpacingMark),
+    ('\U00000a41', '\U00000a42', GC_Extend),
+    ('\U00000a47', '\U00000a48', GC_Extend),
+    ('\U00000a4b', '\U00000a4d', GC_Extend),
+    ('\U00000a51', '\U00000a51', GC_Extend),
+    ('\U00000a70', '\U00000a71', GC_Extend),
+    ('\U00000a75', '\U00000a75', GC_Extend),
+    ('\U00000a81', '\U00000a82', GC_Extend),
+    ('\U00000a83', '\U00000a83', GC_SpacingMark),
+    ('\U00000abc', '\U00000abc', GC_Extend),
+    ('\U00000abe', '\U00000ac0', GC_SpacingMark),
+    ('\U00000ac1', '\U00000ac5', GC_Extend),
+    ('\U00000ac7', '\U00000ac8', GC_Extend),
+    ('\U00000ac9', '\U00000ac9', GC_SpacingMark),
+    ('\U00000acb', '\U00000acc', GC_SpacingMark),
+    ('\U00000acd', '\U00000acd', GC_Extend),
+    ('\U00000ae2', '\U00000ae3', GC_Extend),
+    ('\U00000b01', '\U00000b01', GC_Extend),
+    ('\U00000b02', '\U00000b03', GC_SpacingMark),
+    ('\U00000b3c', '\U00000b3c', GC_Extend),
+    ('\U00000b3e', '\U00000b3f', GC_Extend),
+    ('\U00000b40', '\U00000b40', GC_SpacingMark),
+    ('\U00000b41', '\U00000b44', GC_Extend),
+    ('\U00000b47', '\U00000b48', GC_SpacingMark),
+    ('\U00000b4b', '\U00000b4c', GC_SpacingMark),
+    ('\U00000b4d', '\U00000b4d', GC_Extend),
+    ('\U00000b56', '\U00000b57', GC_Extend),
+    ('\U00000b62', '\U00000b63', GC_Extend),
+    ('\U00000b82', '\U00000b82', GC_Extend),
+    ('\U00000bbe', '\U00000bbe', GC_Extend),
+    ('\U00000bbf', '\U00000bbf', GC_SpacingMark),
+    ('\U00000bc0', '\U00000bc0', GC_Extend),
+    ('\U00000bc1', '\U00000bc2', GC_SpacingMark),
+    ('\U00000bc6', '\U00000bc8', GC_SpacingMark),
+    ('\U00000bca', '\U00000bcc', GC_SpacingMark),
+    ('\U00000bcd', '\U00000bcd', GC_Extend),
+    ('\U00000bd7', '\U00000bd7', GC_Extend),
+    ('\U00000c01', '\U00000c03', GC_SpacingMark),
+    ('\U00000c3e', '\U00000c40', GC_Extend),
+    ('\U00000c41', '\U00000c44', GC_SpacingMark),
+    ('\U00000c46', '\U00000c48', GC_Extend),
+    ('\U00000c4a', '\U00000c4d', GC_Extend),
+    ('\U00000c55', '\U00000c56', GC_Extend),
+    ('\U00000c62', '\U00000c63', GC_Extend),
+    ('\U00000c82', '\U00000c83', GC_SpacingMark),
+    ('\U00000cbc', '\U00000cbc', GC_Extend),
+    ('\U00000cbe', '\U00000cbe', GC_SpacingMark),
+    ('\U00000cbf', '\U00000cbf', GC_Extend),
+    ('\U00000cc0', '\U00000cc1', GC_SpacingMark),
+    ('\U00000cc2', '\U00000cc2', GC_Extend),
+    ('\U00000cc3', '\U00000cc4', GC_SpacingMark),
+    ('\U00000cc6', '\U00000cc6', GC_Extend),
+    ('\U00000cc7', '\U00000cc8', GC_SpacingMark),
+    ('\U00000cca', '\U00000ccb', GC_SpacingMark),
+    ('\U00000ccc', '\U00000ccd', GC_Extend),
+    ('\U00000cd5', '\U00000cd6', GC_Extend),
+    ('\U00000ce2', '\U00000ce3', GC_Extend),
+    ('\U00000d02', '\U00000d03', GC_SpacingMark),
+    ('\U00000d3e', '\U00000d3e', GC_Extend),
+    ('\U00000d3f', '\U00000d40', GC_SpacingMark),
+    ('\U00000d41', '\U00000d44', GC_Extend),
+    ('\U00000d46', '\U00000d48', GC_SpacingMark),
+    ('\U00000d4a', '\U00000d4c', GC_SpacingMark),
+    ('\U00000d4d', '\U00000d4d', GC_Extend),
+    ('\U00000d57', '\U00000d57', GC_Extend),
+    ('\U00000d62', '\U00000d63', GC_Extend),
+    ('\U00000d82', '\U00000d83', GC_SpacingMark),
+    ('\U00000dca', '\U00000dca', GC_Extend),
+    ('\U00000dcf', '\U00000dcf', GC_Extend),
+    ('\U00000dd0', '\U00000dd1', GC_SpacingMark),
+    ('\U00000dd2', '\U00000dd4', GC_Extend),
+    ('\U00000dd6', '\U00000dd6', GC_Extend),
+    ('\U00000dd8', '\U00000dde', GC_SpacingMark),
+    ('\U00000ddf', '\U00000ddf', GC_Extend),
+    ('\U00000df2', '\U00000df3', GC_SpacingMark),
+    ('\U00000e31', '\U00000e31', GC_Extend),
+    ('\U00000e33', '\U00000e33', GC_SpacingMark),
+    ('\U00000e34', '\U00000e3a', GC_Extend),
+    ('\U00000e47', '\U00000e4e', GC_Extend),
+    ('\U00000eb1', '\U00000eb1', GC_Extend),
+    ('\U00000eb3', '\U00000eb3', GC_SpacingMark),
+    ('\U00000eb4', '\U00000eb9', GC_Extend),
+    ('\U00000ebb', '\U00000ebc', GC_Extend),
+    ('\U00000ec8', '\U00000ecd', GC_Extend),
+    ('\U00000f18', '\U00000f19', GC_Extend),
+    ('\U00000f35', '\U00000f35', GC_Extend),
+    ('\U00000f37', '\U00000f37', GC_Extend),
+    ('\U00000f39', '\U00000f39', GC_Extend),
+    ('\U00000f3e', '\U00000f3f', GC_SpacingMark),
+    ('\U00000f71', '\U00000f7e', GC_Extend),
+    ('\U00000f7f', '\U00000f7f', GC_SpacingMark),
+    ('\U00000f80', '\U00000f84', GC_Extend),
+    ('\U00000f86', '\U00000f87', GC_Extend),
+    ('\U00000f8d', '\U00000f97', GC_Extend),
+    ('\U00000f99', '\U00000fbc', GC_Extend),
+    ('\U00000fc6', '\U00000fc6', GC_Extend),
+    ('\U0000102d', '\U00001030', GC_Extend),
+    ('\U00001031', '\U00001031', GC_SpacingMark),
+    ('\U00001032', '\U00001037', GC_Extend),
+    ('\U00001039', '\U0000103a', GC_Extend),
+    ('\U0000103b', '\U0000103c', GC_SpacingMark),
+    ('\U0000103d', '\U0000103e', GC_Extend),
+    ('\U00001056', '\U00001057', GC_SpacingMark),
+    ('\U00001058', '\U00001059', GC_Extend),
+    ('\U0000105e', '\U00001060', GC_Extend),
+    ('\U00001071', '\U00001074', GC_Extend),
+    ('\U00001082', '\U00001082', GC_Extend),
+    ('\U00001084', '\U00001084', GC_SpacingMark),
+    ('\U00001085', '\U00001086', GC_Extend),
+    ('\U0000108d', '\U0000108d', GC_Extend),
+    ('\U0000109d', '\U0000109d', GC_Extend),
+    ('\U00001100', '\U0000115f', GC_L),
+    ('\U00001160', '\U000011a7', GC_V),
+    ('\U000011a8', '\U000011ff', GC_T),
+    ('\U0000135d', '\U0000135f', GC_Extend),
+    ('\U00001712', '\U00001714', GC_Extend),
+    ('\U00001732', '\U00001734', GC_Extend),
+    ('\U00001752', '\U00001753', GC_Extend),
+    ('\U00001772', '\U00001773', GC_Extend),
+    ('\U000017b4', '\U000017b5', GC_Extend),
+    ('\U000017b6', '\U000017b6', GC_SpacingMark),
+    ('\U000017b7', '\U000017bd', GC_Extend),
+    ('\U000017be', '\U000017c5', GC_SpacingMark),
+    ('\U000017c6', '\U000017c6', GC_Extend),
+    ('\U000017c7', '\U000017c8', GC_SpacingMark),
+    ('\U000017c9', '\U000017d3', GC_Extend),
+    ('\U000017dd', '\U000017dd', GC_Extend),
+    ('\U0000180b', '\U0000180d', GC_Extend),
+    ('\U0000180e', '\U0000180e', GC_Control),
+    ('\U000018a9', '\U000018a9', GC_Extend),
+    ('\U00001920', '\U00001922', GC_Extend),
+    ('\U00001923', '\U00001926', GC_SpacingMark),
+    ('\U00001927', '\U00001928', GC_Extend),
+    ('\U00001929', '\U0000192b', GC_SpacingMark),
+    ('\U00001930', '\U00001931', GC_SpacingMark),
+    ('\U00001932', '\U00001932', GC_Extend),
+    ('\U00001933', '\U00001938', GC_SpacingMark),
+    ('\U00001939', '\U0000193b', GC_Extend),
+    ('\U000019b0', '\U000019c0', GC_SpacingMark),
+    ('\U000019c8', '\U000019c9', GC_SpacingMark),
+    ('\U00001a17', '\U00001a18', GC_Extend),
+    ('\U00001a19', '\U00001a1a', GC_SpacingMark),
+    ('\U00001a1b', '\U00001a1b', GC_Extend),
+    ('\U00001a55', '\U00001a55', GC_SpacingMark),
+    ('\U00001a56', '\U00001a56', GC_Extend),
+    ('\U00001a57', '\U00001a57', GC_SpacingMark),
+    ('\U00001a58', '\U00001a5e', GC_Extend),
+    ('\U00001a60', '\U00001a60', GC_Extend),
+    ('\U00001a62', '\U00001a62', GC_Extend),
+    ('\U00001a65', '\U00001a6c', GC_Extend),
+    ('\U00001a6d', '\U00001a72', GC_SpacingMark),
+    ('\U00001a73', '\U00001a7c', GC_Extend),
+    ('\U00001a7f', '\U00001a7f', GC_Extend),
+    ('\U00001b00', '\U00001b03', GC_Extend),
+    ('\U00001b04', '\U00001b04', GC_SpacingMark),
+    ('\U00001b34', '\U00001b34', GC_Extend),
+    ('\U00001b35', '\U00001b35', GC_SpacingMark),
+    ('\U00001b36', '\U00001b3a', GC_Extend),
+    ('\U00001b3b', '\U00001b3b', GC_SpacingMark),
+    ('\U00001b3c', '\U00001b3c', GC_Extend),
+    ('\U00001b3d', '\U00001b41', GC_SpacingMark),
+    ('\U00001b42', '\U00001b42', GC_Extend),
+    ('\U00001b43', '\U00001b44', GC_SpacingMark),
+    ('\U00001b6b', '\U00001b73', GC_Extend),
+    ('\U00001b80', '\U00001b81', GC_Extend),
+    ('\U00001b82', '\U00001b82', GC_SpacingMark),
+    ('\U00001ba1', '\U00001ba1', GC_SpacingMark),
+    ('\U00001ba2', '\U00001ba5', GC_Extend),
+    ('\U00001ba6', '\U00001ba7', GC_SpacingMark),
+    ('\U00001ba8', '\U00001ba9', GC_Extend),
+    ('\U00001baa', '\U00001baa', GC_SpacingMark),
+    ('\U00001bab', '\U00001bab', GC_Extend),
+    ('\U00001bac', '\U00001bad', GC_SpacingMark),
+    ('\U00001be6', '\U00001be6', GC_Extend),
+    ('\U00001be7', '\U00001be7', GC_SpacingMark),
+    ('\U00001be8', '\U00001be9', GC_Extend),
+    ('\U00001bea', '\U00001bec', GC_SpacingMark),
+    ('\U00001bed', '\U00001bed', GC_Extend),
+    ('\U00001bee', '\U00001bee', GC_SpacingMark),
+    ('\U00001bef', '\U00001bf1', GC_Extend),
+    ('\U00001bf2', '\U00001bf3', GC_SpacingMark),
+    ('\U00001c24', '\U00001c2b', GC_SpacingMark),
+    ('\U00001c2c', '\U00001c33', GC_Extend),
+    ('\U00001c34', '\U00001c35', GC_SpacingMark),
+    ('\U00001c36', '\U00001c37', GC_Extend),
+    ('\U00001cd0', '\U00001cd2', GC_Extend),
+    ('\U00001cd4', '\U00001ce0', GC_Extend),
+    ('\U00001ce1', '\U00001ce1', GC_SpacingMark),
+    ('\U00001ce2', '\U00001ce8', GC_Extend),
+    ('\U00001ced', '\U00001ced', GC_Extend),
+    ('\U00001cf2', '\U00001cf3', GC_SpacingMark),
+    ('\U00001cf4', '\U00001cf4', GC_Extend),
+    ('\U00001dc0', '\U00001de6', GC_Extend),
+    ('\U00001dfc', '\U00001dff', GC_Extend),
+    ('\U0000200b', '\U0000200b', GC_Control),
+    ('\U0000200c', '\U0000200d', GC_Extend),
+    ('\U0000200e', '\U0000200f', GC_Control),
+    ('\U00002028', '\U0000202e', GC_Control),
+    ('\U00002060', '\U0000206f', GC_Control),
+    ('\U000020d0', '\U000020f0', GC_Extend),
+    ('\U00002cef', '\U00002cf1', GC_Extend),
+    ('\U00002d7f', '\U00002d7f', GC_Extend),
+    ('\U00002de0', '\U00002dff', GC_Extend),
+    ('\U0000302a', '\U0000302f', GC_Extend),
+    ('\U00003099', '\U0000309a', GC_Extend),
+    ('\U0000a66f', '\U0000a672', GC_Extend),
+    ('\U0000a674', '\U0000a67d', GC_Extend),
+    ('\U0000a69f', '\U0000a69f', GC_Extend),
+    ('\U0000a6f0', '\U0000a6f1', GC_Extend),
+    ('\U0000a802', '\U0000a802', GC_Extend),
+    ('\U0000a806', '\U0000a806', GC_Extend),
+    ('\U0000a80b', '\U0000a80b', GC_Extend),
+    ('\U0000a823', '\U0000a824', GC_SpacingMark),
+    ('\U0000a825', '\U0000a826', GC_Extend),
+    ('\U0000a827', '\U0000a827', GC_SpacingMark),
+    ('\U0000a880', '\U0000a881', GC_SpacingMark),
+    ('\U0000a8b4', '\U0000a8c3', GC_SpacingMark),
+    ('\U0000a8c4', '\U0000a8c4', GC_Extend),
+    ('\U0000a8e0', '\U0000a8f1', GC_Extend),
+    ('\U0000a926', '\U0000a92d', GC_Extend),
+    ('\U0000a947', '\U0000a951', GC_Extend),
+    ('\U0000a952', '\U0000a953', GC_SpacingMark),
+    ('\U0000a960', '\U0000a97c', GC_L),
+    ('\U0000a980', '\U0000a982', GC_Extend),
+    ('\U0000a983', '\U0000a983', GC_SpacingMark),
+    ('\U0000a9b3', '\U0000a9b3', GC_Extend),
+    ('\U0000a9b4', '\U0000a9b5', GC_SpacingMark),
+    ('\U0000a9b6', '\U0000a9b9', GC_Extend),
+    ('\U0000a9ba', '\U0000a9bb', GC_SpacingMark),
+    ('\U0000a9bc', '\U0000a9bc', GC_Extend),
+    ('\U0000a9bd', '\U0000a9c0', GC_SpacingMark),
+    ('\U0000aa29', '\U0000aa2e', GC_Extend),
+    ('\U0000aa2f', '\U0000aa30', GC_SpacingMark),
+    ('\U0000aa31', '\U0000aa32', GC_Extend),
+    ('\U0000aa33', '\U0000aa34', GC_SpacingMark),
+    ('\U0000aa35', '\U0000aa36', GC_Extend),
+    ('\U0000aa43', '\U0000aa43', GC_Extend),
+    ('\U0000aa4c', '\U0000aa4c', GC_Extend),
+    ('\U0000aa4d', '\U0000aa4d', GC_SpacingMark),
+    ('\U0000aab0', '\U0000aab0', GC_Extend),
+    ('\U0000aab2', '\U0000aab4', GC_Extend),
+    ('\U0000aab7', '\U0000aab8', GC_Extend),
+    ('\U0000aabe', '\U0000aabf', GC_Extend),
+    ('\U0000aac1', '\U0000aac1', GC_Extend),
+    ('\U0000aaeb', '\U0000aaeb', GC_SpacingMark),
+    ('\U0000aaec', '\U0000aaed', GC_Extend),
+    ('\U0000aaee', '\U0000aaef', GC_SpacingMark),
+    ('\U0000aaf5', '\U0000aaf5', GC_SpacingMark),
+    ('\U0000aaf6', '\U0000aaf6', GC_Extend),
+    ('\U0000abe3', '\U0000abe4', GC_SpacingMark),
+    ('\U0000abe5', '\U0000abe5', GC_Extend),
+    ('\U0000abe6', '\U0000abe7', GC_SpacingMark),
+    ('\U0000abe8', '\U0000abe8', GC_Extend),
+    ('\U0000abe9', '\U0000abea', GC_SpacingMark),
+    ('\U0000abec', '\U0000abec', GC_SpacingMark),
+    ('\U0000abed', '\U0000abed', GC_Extend),
+    ('\U0000ac00', '\U0000ac00', GC_LV),
+    ('\U0000ac01', '\U0000ac1b', GC_LVT),
+    ('\U0000ac1c', '\U0000ac1c', GC_LV),
+    ('\U0000ac1d', '\U0000ac37', GC_LVT),
+    ('\U0000ac38', '\U0000ac38', GC_LV),
+    ('\U0000ac39', '\U0000ac53', GC_LVT),
+    ('\U0000ac54', '\U0000ac54', GC_LV),
+    ('\U0000ac55', '\U0000ac6f', GC_LVT),
+    ('\U0000ac70', '\U0000ac70', GC_LV),
+    ('\U0000ac71', '\U0000ac8b', GC_LVT),
+    ('\U0000ac8c', '\U0000ac8c', GC_LV),
+    ('\U0000ac8d', '\U0000aca7', GC_LVT),
+    ('\U0000aca8', '\U0000aca8', GC_LV),
+    ('\U0000aca9', '\U0000acc3', GC_LVT),
+    ('\U0000acc4', '\U0000acc4', GC_LV),
+    ('\U0000acc5', '\U0000acdf', GC_LVT),
+    ('\U0000ace0', '\U0000ace0', GC_LV),
+    ('\U0000ace1', '\U0000acfb', GC_LVT),
+    ('\U0000acfc', '\U0000acfc', GC_LV),
+    ('\U0000acfd', '\U0000ad17', GC_LVT),
+    ('\U0000ad18', '\U0000ad18', GC_LV),
+    ('\U0000ad19', '\U0000ad33', GC_LVT),
+    ('\U0000ad34', '\U0000ad34', GC_LV),
+    ('\U0000ad35', '\U0000ad4f', GC_LVT),
+    ('\U0000ad50', '\U0000ad50', GC_LV),
+    ('\U0000ad51', '\U0000ad6b', GC_LVT),
+    ('\U0000ad6c', '\U0000ad6c', GC_LV),
+    ('\U0000ad6d', '\U0000ad87', GC_LVT),
+    ('\U0000ad88', '\U0000ad88', GC_LV),
+    ('\U0000ad89', '\U0000ada3', GC_LVT),
+    ('\U0000ada4', '\U0000ada4', GC_LV),
+    ('\U0000ada5', '\U0000adbf', GC_LVT),
+    ('\U0000adc0', '\U0000adc0', GC_LV),
+    ('\U0000adc1', '\U0000addb', GC_LVT),
+    ('\U0000addc', '\U0000addc', GC_LV),
+    ('\U0000addd', '\U0000adf7', GC_LVT),
+    ('\U0000adf8', '\U0000adf8', GC_LV),
+    ('\U0000adf9', '\U0000ae13', GC_LVT),
+    ('\U0000ae14', '\U0000ae14', GC_LV),
+    ('\U0000ae15', '\U0000ae2f', GC_LVT),
+    ('\U0000ae30', '\U0000ae30', GC_LV),
+    ('\U0000ae31', '\U0000ae4b', GC_LVT),
+    ('\U0000ae4c', '\U0000ae4c', GC_LV),
+    ('\U0000ae4d', '\U0000ae67', GC_LVT),
+    ('\U0000ae68', '\U0000ae68', GC_LV),
+    ('\U0000ae69', '\U0000ae83', GC_LVT),
+    ('\U0000ae84', '\U0000ae84', GC_LV),
+    ('\U0000ae85', '\U0000ae9f', GC_LVT),
+    ('\U0000aea0', '\U0000aea0', GC_LV),
+    ('\U0000aea1', '\U0000aebb', GC_LVT),
+    ('\U0000aebc', '\U0000aebc', GC_LV),
+    ('\U0000aebd', '\U0000aed7', GC_LVT),
+    ('\U0000aed8', '\U0000aed8', GC_LV),
+    ('\U0000aed9', '\U0000aef3', GC_LVT),
+    ('\U0000aef4', '\U0000aef4', GC_LV),
+    ('\U0000aef5', '\U0000af0f', GC_LVT),
+    ('\U0000af10', '\U0000af10', GC_LV),
+    ('\U0000af11', '\U0000af2b', GC_LVT),
+    ('\U0000af2c', '\U0000af2c', GC_LV),
+    ('\U0000af2d', '\U0000af47', GC_LVT),
+    ('\U0000af48', '\U0000af48', GC_LV),
+    ('\U0000af49', '\U0000af63', GC_LVT),
+    ('\U0000af64', '\U0000af64', GC_LV),
+    ('\U0000af65', '\U0000af7f', GC_LVT),
+    ('\U0000af80', '\U0000af80', GC_LV),
+    ('\U0000af81', '\U0000af9b', GC_LVT),
+    ('\U0000af9c', '\U0000af9c', GC_LV),
+    ('\U0000af9d', '\U0000afb7', GC_LVT),
+    ('\U0000afb8', '\U0000afb8', GC_LV),
+    ('\U0000afb9', '\U0000afd3', GC_LVT),
+    ('\U0000afd4', '\U0000afd4', GC_LV),
+    ('\U0000afd5', '\U0000afef', GC_LVT),
+    ('\U0000aff0', '\U0000aff0', GC_LV),
+    ('\U0000aff1', '\U0000b00b', GC_LVT),
+    ('\U0000b00c', '\U0000b00c', GC_LV),
+    ('\U0000b00d', '\U0000b027', GC_LVT),
+    ('\U0000b028', '\U0000b028', GC_LV),
+    ('\U0000b029', '\U0000b043', GC_LVT),
+    ('\U0000b044', '\U0000b044', GC_LV),
+    ('\U0000b045', '\U0000b05f', GC_LVT),
+    ('\U0000b060', '\U0000b060', GC_LV),
+    ('\U0000b061', '\U0000b07b', GC_LVT),
+    ('\U0000b07c', '\U0000b07c', GC_LV),
+    ('\U0000b07d', '\U0000b097', GC_LVT),
+    ('\U0000b098', '\U0000b098', GC_LV),
+    ('\U0000b099', '\U0000b0b3', GC_LVT),
+    ('\U0000b0b4', '\U0000b0b4', GC_LV),
+    ('\U0000b0b5', '\U0000b0cf', GC_LVT),
+    ('\U0000b0d0', '\U0000b0d0', GC_LV),
+    ('\U0000b0d1', '\U0000b0eb', GC_LVT),
+    ('\U0000b0ec', '\U0000b0ec', GC_LV),
+    ('\U0000b0ed', '\U0000b107', GC_LVT),
+    ('\U0000b108', '\U0000b108', GC_LV),
+    ('\U0000b109', '\U0000b123', GC_LVT),
+    ('\U0000b124', '\U0000b124', GC_LV),
+    ('\U0000b125', '\U0000b13f', GC_LVT),
+    ('\U0000b140', '\U0000b140', GC_LV),
+    ('\U0000b141', '\U0000b15b', GC_LVT),
+    ('\U0000b15c', '\U0000b15c', GC_LV),
+    ('\U0000b15d', '\U0000b177', GC_LVT),
+    ('\U0000b178', '\U0000b178', GC_LV),
+    ('\U0000b179', '\U0000b193', GC_LVT),
+    ('\U0000b194', '\U0000b194', GC_LV),
+    ('\U0000b195', '\U0000b1af', GC_LVT),
+    ('\U0000b1b0', '\U0000b1b0', GC_LV),
+    ('\U0000b1b1', '\U0000b1cb', GC_LVT),
+    ('\U0000b1cc', '\U0000b1cc', GC_LV),
+    ('\U0000b1cd', '\U0000b1e7', GC_LVT),
+    ('\U0000b1e8', '\U0000b1e8', GC_LV),
+    ('\U0000b1e9', '\U0000b203', GC_LVT),
+    ('\U0000b204', '\U0000b204', GC_LV),
+    ('\U0000b205', '\U0000b21f', GC_LVT),
+    ('\U0000b220', '\U0000b220', GC_LV),
+    ('\U0000b221', '\U0000b23b', GC_LVT),
+    ('\U0000b23c', '\U0000b23c', GC_LV),
+    ('\U0000b23d', '\U0000b257', GC_LVT),
+    ('\U0000b258', '\U0000b258', GC_LV),
+    ('\U0000b259', '\U0000b273', GC_LVT),
+    ('\U0000b274', '\U0000b274', GC_LV),
+    ('\U0000b275', '\U0000b28f', GC_LVT),
+    ('\U0000b290', '\U0000b290', GC_LV),
+    ('\U0000b291', '\U0000b2ab', GC_LVT),
+    ('\U0000b2ac', '\U0000b2ac', GC_LV),
+    ('\U0000b2ad', '\U0000b2c7', GC_LVT),
+    ('\U0000b2c8', '\U0000b2c8', GC_LV),
+    ('\U0000b2c9', '\U0000b2e3', GC_LVT),
+    ('\U0000b2e4', '\U0000b2e4', GC_LV),
+    ('\U0000b2e5', '\U0000b2ff', GC_LVT),
+    ('\U0000b300', '\U0000b300', GC_LV),
+    ('\U0000b301', '\U0000b31b', GC_LVT),
+    ('\U0000b31c', '\U0000b31c', GC_LV),
+    ('\U0000b31d', '\U0000b337', GC_LVT),
+    ('\U0000b338', '\U0000b338', GC_LV),
+    ('\U0000b339', '\U0000b353', GC_LVT),
+    ('\U0000b354', '\U0000b354', GC_LV),
+    ('\U0000b355', '\U0000b36f', GC_LVT),
+    ('\U0000b370', '\U0000b370', GC_LV),
+    ('\U0000b371', '\U0000b38b', GC_LVT),
+    ('\U0000b38c', '\U0000b38c', GC_LV),
+    ('\U0000b38d', '\U0000b3a7', GC_LVT),
+    ('\U0000b3a8', '\U0000b3a8', GC_LV),
+    ('\U0000b3a9', '\U0000b3c3', GC_LVT),
+    ('\U0000b3c4', '\U0000b3c4', GC_LV),
+    ('\U0000b3c5', '\U0000b3df', GC_LVT),
+    ('\U0000b3e0', '\U0000b3e0', GC_LV),
+    ('\U0000b3e1', '\U0000b3fb', GC_LVT),
+    ('\U0000b3fc', '\U0000b3fc', GC_LV),
+    ('\U0000b3fd', '\U0000b417', GC_LVT),
+    ('\U0000b418', '\U0000b418', GC_LV),
+    ('\U0000b419', '\U0000b433', GC_LVT),
+    ('\U0000b434', '\U0000b434', GC_LV),
+    ('\U0000b435', '\U0000b44f', GC_LVT),
+    ('\U0000b450', '\U0000b450', GC_LV),
+    ('\U0000b451', '\U0000b46b', GC_LVT),
+    ('\U0000b46c', '\U0000b46c', GC_LV),
+    ('\U0000b46d', '\U0000b487', GC_LVT),
+    ('\U0000b488', '\U0000b488', GC_LV),
+    ('\U0000b489', '\U0000b4a3', GC_LVT),
+    ('\U0000b4a4', '\U0000b4a4', GC_LV),
+    ('\U0000b4a5', '\U0000b4bf', GC_LVT),
+    ('\U0000b4c0', '\U0000b4c0', GC_LV),
+    ('\U0000b4c1', '\U0000b4db', GC_LVT),
+    ('\U0000b4dc', '\U0000b4dc', GC_LV),
+    ('\U0000b4dd', '\U0000b4f7', GC_LVT),
+    ('\U0000b4f8', '\U0000b4f8', GC_LV),
+    ('\U0000b4f9', '\U0000b513', GC_LVT),
+    ('\U0000b514', '\U0000b514', GC_LV),
+    ('\U0000b515', '\U0000b52f', GC_LVT),
+    ('\U0000b530', '\U0000b530', GC_LV),
+    ('\U0000b531', '\U0000b54b', GC_LVT),
+    ('\U0000b54c', '\U0000b54c', GC_LV),
+    ('\U0000b54d', '\U0000b567', GC_LVT),
+    ('\U0000b568', '\U0000b568', GC_LV),
+    ('\U0000b569', '\U0000b583', GC_LVT),
+    ('\U0000b584', '\U0000b584', GC_LV),
+    ('\U0000b585', '\U0000b59f', GC_LVT),
+    ('\U0000b5a0', '\U0000b5a0', GC_LV),
+    ('\U0000b5a1', '\U0000b5bb', GC_LVT),
+    ('\U0000b5bc', '\U0000b5bc', GC_LV),
+    ('\U0000b5bd', '\U0000b5d7', GC_LVT),
+    ('\U0000b5d8', '\U0000b5d8', GC_LV),
+    ('\U0000b5d9', '\U0000b5f3', GC_LVT),
+    ('\U0000b5f4', '\U0000b5f4', GC_LV),
+    ('\U0000b5f5', '\U0000b60f', GC_LVT),
+    ('\U0000b610', '\U0000b610', GC_LV),
+    ('\U0000b611', '\U0000b62b', GC_LVT),
+    ('\U0000b62c', '\U0000b62c', GC_LV),
+    ('\U0000b62d', '\U0000b647', GC_LVT),
+    ('\U0000b648', '\U0000b648', GC_LV),
+    ('\U0000b649', '\U0000b663', GC_LVT),
+    ('\U0000b664', '\U0000b664', GC_LV),
+    ('\U0000b665', '\U0000b67f', GC_LVT),
+    ('\U0000b680', '\U0000b680', GC_LV),
+    ('\U0000b681', '\U0000b69b', GC_LVT),
+    ('\U0000b69c', '\U0000b69c', GC_LV),
+    ('\U0000b69d', '\U0000b6b7', GC_LVT),
+    ('\U0000b6b8', '\U0000b6b8', GC_LV),
+    ('\U0000b6b9', '\U0000b6d3', GC_LVT),
+    ('\U0000b6d4', '\U0000b6d4', GC_LV),
+    ('\U0000b6d5', '\U0000b6ef', GC_LVT),
+    ('\U0000b6f0', '\U0000b6f0', GC_LV),
+    ('\U0000b6f1', '\U0000b70b', GC_LVT),
+    ('\U0000b70c', '\U0000b70c', GC_LV),
+    ('\U0000b70d', '\U0000b727', GC_LVT),
+    ('\U0000b728', '\U0000b728', GC_LV),
+    ('\U0000b729', '\U0000b743', GC_LVT),
+    ('\U0000b744', '\U0000b744', GC_LV),
+    ('\U0000b745', '\U0000b75f', GC_LVT),
+    ('\U0000b760', '\U0000b760', GC_LV),
+    ('\U0000b761', '\U0000b77b', GC_LVT),
+    ('\U0000b77c', '\U0000b77c', GC_LV),
+    ('\U0000b77d', '\U0000b797', GC_LVT),
+    ('\U0000b798', '\U0000b798', GC_LV),
+    ('\U0000b799', '\U0000b7b3', GC_LVT),
+    ('\U0000b7b4', '\U0000b7b4', GC_LV),
+    ('\U0000b7b5', '\U0000b7cf', GC_LVT),
+    ('\U0000b7d0', '\U0000b7d0', GC_LV),
+    ('\U0000b7d1', '\U0000b7eb', GC_LVT),
+    ('\U0000b7ec', '\U0000b7ec', GC_LV),
+    ('\U0000b7ed', '\U0000b807', GC_LVT),
+    ('\U0000b808', '\U0000b808', GC_LV),
+    ('\U0000b809', '\U0000b823', GC_LVT),
+    ('\U0000b824', '\U0000b824', GC_LV),
+    ('\U0000b825', '\U0000b83f', GC_LVT),
+    ('\U0000b840', '\U0000b840', GC_LV),
+    ('\U0000b841', '\U0000b85b', GC_LVT),
+    ('\U0000b85c', '\U0000b85c', GC_LV),
+    ('\U0000b85d', '\U0000b877', GC_LVT),
+    ('\U0000b878', '\U0000b878', GC_LV),
+    ('\U0000b879', '\U0000b893', GC_LVT),
+    ('\U0000b894', '\U0000b894', GC_LV),
+    ('\U0000b895', '\U0000b8af', GC_LVT),
+    ('\U0000b8b0', '\U0000b8b0', GC_LV),
+    ('\U0000b8b1', '\U0000b8cb', GC_LVT),
+    ('\U0000b8cc', '\U0000b8cc', GC_LV),
+    ('\U0000b8cd', '\U0000b8e7', GC_LVT),
+    ('\U0000b8e8', '\U0000b8e8', GC_LV),
+    ('\U0000b8e9', '\U0000b903', GC_LVT),
+    ('\U0000b904', '\U0000b904', GC_LV),
+    ('\U0000b905', '\U0000b91f', GC_LVT),
+    ('\U0000b920', '\U0000b920', GC_LV),
+    ('\U0000b921', '\U0000b93b', GC_LVT),
+    ('\U0000b93c', '\U0000b93c', GC_LV),
+    ('\U0000b93d', '\U0000b957', GC_LVT),
+    ('\U0000b958', '\U0000b958', GC_LV),
+    ('\U0000b959', '\U0000b973', GC_LVT),
+    ('\U0000b974', '\U0000b974', GC_LV),
+    ('\U0000b975', '\U0000b98f', GC_LVT),
+    ('\U0000b990', '\U0000b990', GC_LV),
+    ('\U0000b991', '\U0000b9ab', GC_LVT),
+    ('\U0000b9ac', '\U0000b9ac', GC_LV),
+    ('\U0000b9ad', '\U0000b9c7', GC_LVT),
+    ('\U0000b9c8', '\U0000b9c8', GC_LV),
+    ('\U0000b9c9', '\U0000b9e3', GC_LVT),
+    ('\U0000b9e4', '\U0000b9e4', GC_LV),
+    ('\U0000b9e5', '\U0000b9ff', GC_LVT),
+    ('\U0000ba00', '\U0000ba00', GC_LV),
+    ('\U0000ba01', '\U0000ba1b', GC_LVT),
+    ('\U0000ba1c', '\U0000ba1c', GC_LV),
+    ('\U0000ba1d', '\U0000ba37', GC_LVT),
+    ('\U0000ba38', '\U0000ba38', GC_LV),
+    ('\U0000ba39', '\U0000ba53', GC_LVT),
+    ('\U0000ba54', '\U0000ba54', GC_LV),
+    ('\U0000ba55', '\U0000ba6f', GC_LVT),
+    ('\U0000ba70', '\U0000ba70', GC_LV),
+    ('\U0000ba71', '\U0000ba8b', GC_LVT),
+    ('\U0000ba8c', '\U0000ba8c', GC_LV),
+    ('\U0000ba8d', '\U0000baa7', GC_LVT),
+    ('\U0000baa8', '\U0000baa8', GC_LV),
+    ('\U0000baa9', '\U0000bac3', GC_LVT),
+    ('\U0000bac4', '\U0000bac4', GC_LV),
+    ('\U0000bac5', '\U0000badf', GC_LVT),
+    ('\U0000bae0', '\U0000bae0', GC_LV),
+    ('\U0000bae1', '\U0000bafb', GC_LVT),
+    ('\U0000bafc', '\U0000bafc', GC_LV),
+    ('\U0000bafd', '\U0000bb17', GC_LVT),
+    ('\U0000bb18', '\U0000bb18', GC_LV),
+    ('\U0000bb19', '\U0000bb33', GC_LVT),
+    ('\U0000bb34', '\U0000bb34', GC_LV),
+    ('\U0000bb35', '\U0000bb4f', GC_LVT),
+    ('\U0000bb50', '\U0000bb50', GC_LV),
+    ('\U0000bb51', '\U0000bb6b', GC_LVT),
+    ('\U0000bb6c', '\U0000bb6c', GC_LV),
+    ('\U0000bb6d', '\U0000bb87', GC_LVT),
+    ('\U0000bb88', '\U0000bb88', GC_LV),
+    ('\U0000bb89', '\U0000bba3', GC_LVT),
+    ('\U0000bba4', '\U0000bba4', GC_LV),
+    ('\U0000bba5', '\U0000bbbf', GC_LVT),
+    ('\U0000bbc0', '\U0000bbc0', GC_LV),
+    ('\U0000bbc1', '\U0000bbdb', GC_LVT),
+    ('\U0000bbdc', '\U0000bbdc', GC_LV),
+    ('\U0000bbdd', '\U0000bbf7', GC_LVT),
+    ('\U0000bbf8', '\U0000bbf8', GC_LV),
+    ('\U0000bbf9', '\U0000bc13', GC_LVT),
+    ('\U0000bc14', '\U0000bc14', GC_LV),
+    ('\U0000bc15', '\U0000bc2f', GC_LVT),
+    ('\U0000bc30', '\U0000bc30', GC_LV),
+    ('\U0000bc31', '\U0000bc4b', GC_LVT),
+    ('\U0000bc4c', '\U0000bc4c', GC_LV),
+    ('\U0000bc4d', '\U0000bc67', GC_LVT),
+    ('\U0000bc68', '\U0000bc68', GC_LV),
+    ('\U0000bc69', '\U0000bc83', GC_LVT),
+    ('\U0000bc84', '\U0000bc84', GC_LV),
+    ('\U0000bc85', '\U0000bc9f', GC_LVT),
+    ('\U0000bca0', '\U0000bca0', GC_LV),
+    ('\U0000bca1', '\U0000bcbb', GC_LVT),
+    ('\U0000bcbc', '\U0000bcbc', GC_LV),
+    ('\U0000bcbd', '\U0000bcd7', GC_LVT),
+    ('\U0000bcd8', '\U0000bcd8', GC_LV),
+    ('\U0000bcd9', '\U0000bcf3', GC_LVT),
+    ('\U0000bcf4', '\U0000bcf4', GC_LV),
+    ('\U0000bcf5', '\U0000bd0f', GC_LVT),
+    ('\U0000bd10', '\U0000bd10', GC_LV),
+    ('\U0000bd11', '\U0000bd2b', GC_LVT),
+    ('\U0000bd2c', '\U0000bd2c', GC_LV),
+    ('\U0000bd2d', '\U0000bd47', GC_LVT),
+    ('\U0000bd48', '\U0000bd48', GC_LV),
+    ('\U0000bd49', '\U0000bd63', GC_LVT),
+    ('\U0000bd64', '\U0000bd64', GC_LV),
+    ('\U0000bd65', '\U0000bd7f', GC_LVT),
+    ('\U0000bd80', '\U0000bd80', GC_LV),
+    ('\U0000bd81', '\U0000bd9b', GC_LVT),
+    ('\U0000bd9c', '\U0000bd9c', GC_LV),
+    ('\U0000bd9d', '\U0000bdb7', GC_LVT),
+    ('\U0000bdb8', '\U0000bdb8', GC_LV),
+    ('\U0000bdb9', '\U0000bdd3', GC_LVT),
+    ('\U0000bdd4', '\U0000bdd4', GC_LV),
+    ('\U0000bdd5', '\U0000bdef', GC_LVT),
+    ('\U0000bdf0', '\U0000bdf0', GC_LV),
+    ('\U0000bdf1', '\U0000be0b', GC_LVT),
+    ('\U0000be0c', '\U0000be0c', GC_LV),
+    ('\U0000be0d', '\U0000be27', GC_LVT),
+    ('\U0000be28', '\U0000be28', GC_LV),
+    ('\U0000be29', '\U0000be43', GC_LVT),
+    ('\U0000be44', '\U0000be44', GC_LV),
+    ('\U0000be45', '\U0000be5f', GC_LVT),
+    ('\U0000be60', '\U0000be60', GC_LV),
+    ('\U0000be61', '\U0000be7b', GC_LVT),
+    ('\U0000be7c', '\U0000be7c', GC_LV),
+    ('\U0000be7d', '\U0000be97', GC_LVT),
+    ('\U0000be98', '\U0000be98', GC_LV),
+    ('\U0000be99', '\U0000beb3', GC_LVT),
+    ('\U0000beb4', '\U0000beb4', GC_LV),
+    ('\U0000beb5', '\U0000becf', GC_LVT),
+    ('\U0000bed0', '\U0000bed0', GC_LV),
+    ('\U0000bed1', '\U0000beeb', GC_LVT),
+    ('\U0000beec', '\U0000beec', GC_LV),
+    ('\U0000beed', '\U0000bf07', GC_LVT),
+    ('\U0000bf08', '\U0000bf08', GC_LV),
+    ('\U0000bf09', '\U0000bf23', GC_LVT),
+    ('\U0000bf24', '\U0000bf24', GC_LV),
+    ('\U0000bf25', '\U0000bf3f', GC_LVT),
+    ('\U0000bf40', '\U0000bf40', GC_LV),
+    ('\U0000bf41', '\U0000bf5b', GC_LVT),
+    ('\U0000bf5c', '\U0000bf5c', GC_LV),
+    ('\U0000bf5d', '\U0000bf77', GC_LVT),
+    ('\U0000bf78', '\U0000bf78', GC_LV),
+    ('\U0000bf79', '\U0000bf93', GC_LVT),
+    ('\U0000bf94', '\U0000bf94', GC_LV),
+    ('\U0000bf95', '\U0000bfaf', GC_LVT),
+    ('\U0000bfb0', '\U0000bfb0', GC_LV),
+    ('\U0000bfb1', '\U0000bfcb', GC_LVT),
+    ('\U0000bfcc', '\U0000bfcc', GC_LV),
+    ('\U0000bfcd', '\U0000bfe7', GC_LVT),
+    ('\U0000bfe8', '\U0000bfe8', GC_LV),
+    ('\U0000bfe9', '\U0000c003', GC_LVT),
+    ('\U0000c004', '\U0000c004', GC_LV),
+    ('\U0000c005', '\U0000c01f', GC_LVT),
+    ('\U0000c020', '\U0000c020', GC_LV),
+    ('\U0000c021', '\U0000c03b', GC_LVT),
+    ('\U0000c03c', '\U0000c03c', GC_LV),
+    ('\U0000c03d', '\U0000c057', GC_LVT),
+    ('\U0000c058', '\U0000c058', GC_LV),
+    ('\U0000c059', '\U0000c073', GC_LVT),
+    ('\U0000c074', '\U0000c074', GC_LV),
+    ('\U0000c075', '\U0000c08f', GC_LVT),
+    ('\U0000c090', '\U0000c090', GC_LV),
+    ('\U0000c091', '\U0000c0ab', GC_LVT),
+    ('\U0000c0ac', '\U0000c0ac', GC_LV),
+    ('\U0000c0ad', '\U0000c0c7', GC_LVT),
+    ('\U0000c0c8', '\U0000c0c8', GC_LV),
+    ('\U0000c0c9', '\U0000c0e3', GC_LVT),
+    ('\U0000c0e4', '\U0000c0e4', GC_LV),
+    ('\U0000c0e5', '\U0000c0ff', GC_LVT),
+    ('\U0000c100', '\U0000c100', GC_LV),
+    ('\U0000c101', '\U0000c11b', GC_LVT),
+    ('\U0000c11c', '\U0000c11c', GC_LV),
+    ('\U0000c11d', '\U0000c137', GC_LVT),
+    ('\U0000c138', '\U0000c138', GC_LV),
+    ('\U0000c139', '\U0000c153', GC_LVT),
+    ('\U0000c154', '\U0000c154', GC_LV),
+    ('\U0000c155', '\U0000c16f', GC_LVT),
+    ('\U0000c170', '\U0000c170', GC_LV),
+    ('\U0000c171', '\U0000c18b', GC_LVT),
+    ('\U0000c18c', '\U0000c18c', GC_LV),
+    ('\U0000c18d', '\U0000c1a7', GC_LVT),
+    ('\U0000c1a8', '\U0000c1a8', GC_LV),
+    ('\U0000c1a9', '\U0000c1c3', GC_LVT),
+    ('\U0000c1c4', '\U0000c1c4', GC_LV),
+    ('\U0000c1c5', '\U0000c1df', GC_LVT),
+    ('\U0000c1e0', '\U0000c1e0', GC_LV),
+    ('\U0000c1e1', '\U0000c1fb', GC_LVT),
+    ('\U0000c1fc', '\U0000c1fc', GC_LV),
+    ('\U0000c1fd', '\U0000c217', GC_LVT),
+    ('\U0000c218', '\U0000c218', GC_LV),
+    ('\U0000c219', '\U0000c233', GC_LVT),
+    ('\U0000c234', '\U0000c234', GC_LV),
+    ('\U0000c235', '\U0000c24f', GC_LVT),
+    ('\U0000c250', '\U0000c250', GC_LV),
+    ('\U0000c251', '\U0000c26b', GC_LVT),
+    ('\U0000c26c', '\U0000c26c', GC_LV),
+    ('\U0000c26d', '\U0000c287', GC_LVT),
+    ('\U0000c288', '\U0000c288', GC_LV),
+    ('\U0000c289', '\U0000c2a3', GC_LVT),
+    ('\U0000c2a4', '\U0000c2a4', GC_LV),
+    ('\U0000c2a5', '\U0000c2bf', GC_LVT),
+    ('\U0000c2c0', '\U0000c2c0', GC_LV),
+    ('\U0000c2c1', '\U0000c2db', GC_LVT),
+    ('\U0000c2dc', '\U0000c2dc', GC_LV),
+    ('\U0000c2dd', '\U0000c2f7', GC_LVT),
+    ('\U0000c2f8', '\U0000c2f8', GC_LV),
+    ('\U0000c2f9', '\U0000c313', GC_LVT),
+    ('\U0000c314', '\U0000c314', GC_LV),
+    ('\U0000c315', '\U0000c32f', GC_LVT),
+    ('\U0000c330', '\U0000c330', GC_LV),
+    ('\U0000c331', '\U0000c34b', GC_LVT),
+    ('\U0000c34c', '\U0000c34c', GC_LV),
+    ('\U0000c34d', '\U0000c367', GC_LVT),
+    ('\U0000c368', '\U0000c368', GC_LV),
+    ('\U0000c369', '\U0000c383', GC_LVT),
+    ('\U0000c384', '\U0000c384', GC_LV),
+    ('\U0000c385', '\U0000c39f', GC_LVT),
+    ('\U0000c3a0', '\U0000c3a0', GC_LV),
+    ('\U0000c3a1', '\U0000c3bb', GC_LVT),
+    ('\U0000c3bc', '\U0000c3bc', GC_LV),
+    ('\U0000c3bd', '\U0000c3d7', GC_LVT),
+    ('\U0000c3d8', '\U0000c3d8', GC_LV),
+    ('\U0000c3d9', '\U0000c3f3', GC_LVT),
+    ('\U0000c3f4', '\U0000c3f4', GC_LV),
+    ('\U0000c3f5', '\U0000c40f', GC_LVT),
+    ('\U0000c410', '\U0000c410', GC_LV),
+    ('\U0000c411', '\U0000c42b', GC_LVT),
+    ('\U0000c42c', '\U0000c42c', GC_LV),
+    ('\U0000c42d', '\U0000c447', GC_LVT),
+    ('\U0000c448', '\U0000c448', GC_LV),
+    ('\U0000c449', '\U0000c463', GC_LVT),
+    ('\U0000c464', '\U0000c464', GC_LV),
+    ('\U0000c465', '\U0000c47f', GC_LVT),
+    ('\U0000c480', '\U0000c480', GC_LV),
+    ('\U0000c481', '\U0000c49b', GC_LVT),
+    ('\U0000c49c', '\U0000c49c', GC_LV),
+    ('\U0000c49d', '\U0000c4b7', GC_LVT),
+    ('\U0000c4b8', '\U0000c4b8', GC_LV),
+    ('\U0000c4b9', '\U0000c4d3', GC_LVT),
+    ('\U0000c4d4', '\U0000c4d4', GC_LV),
+    ('\U0000c4d5', '\U0000c4ef', GC_LVT),
+    ('\U0000c4f0', '\U0000c4f0', GC_LV),
+    ('\U0000c4f1', '\U0000c50b', GC_LVT),
+    ('\U0000c50c', '\U0000c50c', GC_LV),
+    ('\U0000c50d', '\U0000c527', GC_LVT),
+    ('\U0000c528', '\U0000c528', GC_LV),
+    ('\U0000c529', '\U0000c543', GC_LVT),
+    ('\U0000c544', '\U0000c544', GC_LV),
+    ('\U0000c545', '\U0000c55f', GC_LVT),
+    ('\U0000c560', '\U0000c560', GC_LV),
+    ('\U0000c561', '\U0000c57b', GC_LVT),
+    ('\U0000c57c', '\U0000c57c', GC_LV),
+    ('\U0000c57d', '\U0000c597', GC_LVT),
+    ('\U0000c598', '\U0000c598', GC_LV),
+    ('\U0000c599', '\U0000c5b3', GC_LVT),
+    ('\U0000c5b4', '\U0000c5b4', GC_LV),
+    ('\U0000c5b5', '\U0000c5cf', GC_LVT),
+    ('\U0000c5d0', '\U0000c5d0', GC_LV),
+    ('\U0000c5d1', '\U0000c5eb', GC_LVT),
+    ('\U0000c5ec', '\U0000c5ec', GC_LV),
+    ('\U0000c5ed', '\U0000c607', GC_LVT),
+    ('\U0000c608', '\U0000c608', GC_LV),
+    ('\U0000c609', '\U0000c623', GC_LVT),
+    ('\U0000c624', '\U0000c624', GC_LV),
+    ('\U0000c625', '\U0000c63f', GC_LVT),
+    ('\U0000c640', '\U0000c640', GC_LV),
+    ('\U0000c641', '\U0000c65b', GC_LVT),
+    ('\U0000c65c', '\U0000c65c', GC_LV),
+    ('\U0000c65d', '\U0000c677', GC_LVT),
+    ('\U0000c678', '\U0000c678', GC_LV),
+    ('\U0000c679', '\U0000c693', GC_LVT),
+    ('\U0000c694', '\U0000c694', GC_LV),
+    ('\U0000c695', '\U0000c6af', GC_LVT),
+    ('\U0000c6b0', '\U0000c6b0', GC_LV),
+    ('\U0000c6b1', '\U0000c6cb', GC_LVT),
+    ('\U0000c6cc', '\U0000c6cc', GC_LV),
+    ('\U0000c6cd', '\U0000c6e7', GC_LVT),
+    ('\U0000c6e8', '\U0000c6e8', GC_LV),
+    ('\U0000c6e9', '\U0000c703', GC_LVT),
+    ('\U0000c704', '\U0000c704', GC_LV),
+    ('\U0000c705', '\U0000c71f', GC_LVT),
+    ('\U0000c720', '\U0000c720', GC_LV),
+    ('\U0000c721', '\U0000c73b', GC_LVT),
+    ('\U0000c73c', '\U0000c73c', GC_LV),
+    ('\U0000c73d', '\U0000c757', GC_LVT),
+    ('\U0000c758', '\U0000c758', GC_LV),
+    ('\U0000c759', '\U0000c773', GC_LVT),
+    ('\U0000c774', '\U0000c774', GC_LV),
+    ('\U0000c775', '\U0000c78f', GC_LVT),
+    ('\U0000c790', '\U0000c790', GC_LV),
+    ('\U0000c791', '\U0000c7ab', GC_LVT),
+    ('\U0000c7ac', '\U0000c7ac', GC_LV),
+    ('\U0000c7ad', '\U0000c7c7', GC_LVT),
+    ('\U0000c7c8', '\U0000c7c8', GC_LV),
+    ('\U0000c7c9', '\U0000c7e3', GC_LVT),
+    ('\U0000c7e4', '\U0000c7e4', GC_LV),
+    ('\U0000c7e5', '\U0000c7ff', GC_LVT),
+    ('\U0000c800', '\U0000c800', GC_LV),
+    ('\U0000c801', '\U0000c81b', GC_LVT),
+    ('\U0000c81c', '\U0000c81c', GC_LV),
+    ('\U0000c81d', '\U0000c837', GC_LVT),
+    ('\U0000c838', '\U0000c838', GC_LV),
+    ('\U0000c839', '\U0000c853', GC_LVT),
+    ('\U0000c854', '\U0000c854', GC_LV),
+    ('\U0000c855', '\U0000c86f', GC_LVT),
+    ('\U0000c870', '\U0000c870', GC_LV),
+    ('\U0000c871', '\U0000c88b', GC_LVT),
+    ('\U0000c88c', '\U0000c88c', GC_LV),
+    ('\U0000c88d', '\U0000c8a7', GC_LVT),
+    ('\U0000c8a8', '\U0000c8a8', GC_LV),
+    ('\U0000c8a9', '\U0000c8c3', GC_LVT),
+    ('\U0000c8c4', '\U0000c8c4', GC_LV),
+    ('\U0000c8c5', '\U0000c8df', GC_LVT),
+    ('\U0000c8e0', '\U0000c8e0', GC_LV),
+    ('\U0000c8e1', '\U0000c8fb', GC_LVT),
+    ('\U0000c8fc', '\U0000c8fc', GC_LV),
+    ('\U0000c8fd', '\U0000c917', GC_LVT),
+    ('\U0000c918', '\U0000c918', GC_LV),
+    ('\U0000c919', '\U0000c933', GC_LVT),
+    ('\U0000c934', '\U0000c934', GC_LV),
+    ('\U0000c935', '\U0000c94f', GC_LVT),
+    ('\U0000c950', '\U0000c950', GC_LV),
+    ('\U0000c951', '\U0000c96b', GC_LVT),
+    ('\U0000c96c', '\U0000c96c', GC_LV),
+    ('\U0000c96d', '\U0000c987', GC_LVT),
+    ('\U0000c988', '\U0000c988', GC_LV),
+    ('\U0000c989', '\U0000c9a3', GC_LVT),
+    ('\U0000c9a4', '\U0000c9a4', GC_LV),
+    ('\U0000c9a5', '\U0000c9bf', GC_LVT),
+    ('\U0000c9c0', '\U0000c9c0', GC_LV),
+    ('\U0000c9c1', '\U0000c9db', GC_LVT),
+    ('\U0000c9dc', '\U0000c9dc', GC_LV),
+    ('\U0000c9dd', '\U0000c9f7', GC_LVT),
+    ('\U0000c9f8', '\U0000c9f8', GC_LV),
+    ('\U0000c9f9', '\U0000ca13', GC_LVT),
+    ('\U0000ca14', '\U0000ca14', GC_LV),
+    ('\U0000ca15', '\U0000ca2f', GC_LVT),
+    ('\U0000ca30', '\U0000ca30', GC_LV),
+    ('\U0000ca31', '\U0000ca4b', GC_LVT),
+    ('\U0000ca4c', '\U0000ca4c', GC_LV),
+    ('\U0000ca4d', '\U0000ca67', GC_LVT),
+    ('\U0000ca68', '\U0000ca68', GC_LV),
+    ('\U0000ca69', '\U0000ca83', GC_LVT),
+    ('\U0000ca84', '\U0000ca84', GC_LV),
+    ('\U0000ca85', '\U0000ca9f', GC_LVT),
+    ('\U0000caa0', '\U0000caa0', GC_LV),
+    ('\U0000caa1', '\U0000cabb', GC_LVT),
+    ('\U0000cabc', '\U0000cabc', GC_LV),
+    ('\U0000cabd', '\U0000cad7', GC_LVT),
+    ('\U0000cad8', '\U0000cad8', GC_LV),
+    ('\U0000cad9', '\U0000caf3', GC_LVT),
+    ('\U0000caf4', '\U0000caf4', GC_LV),
+    ('\U0000caf5', '\U0000cb0f', GC_LVT),
+    ('\U0000cb10', '\U0000cb10', GC_LV),
+    ('\U0000cb11', '\U0000cb2b', GC_LVT),
+    ('\U0000cb2c', '\U0000cb2c', GC_LV),
+    ('\U0000cb2d', '\U0000cb47', GC_LVT),
+    ('\U0000cb48', '\U0000cb48', GC_LV),
+    ('\U0000cb49', '\U0000cb63', GC_LVT),
+    ('\U0000cb64', '\U0000cb64', GC_LV),
+    ('\U0000cb65', '\U0000cb7f', GC_LVT),
+    ('\U0000cb80', '\U0000cb80', GC_LV),
+    ('\U0000cb81', '\U0000cb9b', GC_LVT),
+    ('\U0000cb9c', '\U0000cb9c', GC_LV),
+    ('\U0000cb9d', '\U0000cbb7', GC_LVT),
+    ('\U0000cbb8', '\U0000cbb8', GC_LV),
+    ('\U0000cbb9', '\U0000cbd3', GC_LVT),
+    ('\U0000cbd4', '\U0000cbd4', GC_LV),
+    ('\U0000cbd5', '\U0000cbef', GC_LVT),
+    ('\U0000cbf0', '\U0000cbf0', GC_LV),
+    ('\U0000cbf1', '\U0000cc0b', GC_LVT),
+    ('\U0000cc0c', '\U0000cc0c', GC_LV),
+    ('\U0000cc0d', '\U0000cc27', GC_LVT),
+    ('\U0000cc28', '\U0000cc28', GC_LV),
+    ('\U0000cc29', '\U0000cc43', GC_LVT),
+    ('\U0000cc44', '\U0000cc44', GC_LV),
+    ('\U0000cc45', '\U0000cc5f', GC_LVT),
+    ('\U0000cc60', '\U0000cc60', GC_LV),
+    ('\U0000cc61', '\U0000cc7b', GC_LVT),
+    ('\U0000cc7c', '\U0000cc7c', GC_LV),
+    ('\U0000cc7d', '\U0000cc97', GC_LVT),
+    ('\U0000cc98', '\U0000cc98', GC_LV),
+    ('\U0000cc99', '\U0000ccb3', GC_LVT),
+    ('\U0000ccb4', '\U0000ccb4', GC_LV),
+    ('\U0000ccb5', '\U0000cccf', GC_LVT),
+    ('\U0000ccd0', '\U0000ccd0', GC_LV),
+    ('\U0000ccd1', '\U0000cceb', GC_LVT),
+    ('\U0000ccec', '\U0000ccec', GC_LV),
+    ('\U0000cced', '\U0000cd07', GC_LVT),
+    ('\U0000cd08', '\U0000cd08', GC_LV),
+    ('\U0000cd09', '\U0000cd23', GC_LVT),
+    ('\U0000cd24', '\U0000cd24', GC_LV),
+    ('\U0000cd25', '\U0000cd3f', GC_LVT),
+    ('\U0000cd40', '\U0000cd40', GC_LV),
+    ('\U0000cd41', '\U0000cd5b', GC_LVT),
+    ('\U0000cd5c', '\U0000cd5c', GC_LV),
+    ('\U0000cd5d', '\U0000cd77', GC_LVT),
+    ('\U0000cd78', '\U0000cd78', GC_LV),
+    ('\U0000cd79', '\U0000cd93', GC_LVT),
+    ('\U0000cd94', '\U0000cd94', GC_LV),
+    ('\U0000cd95', '\U0000cdaf', GC_LVT),
+    ('\U0000cdb0', '\U0000cdb0', GC_LV),
+    ('\U0000cdb1', '\U0000cdcb', GC_LVT),
+    ('\U0000cdcc', '\U0000cdcc', GC_LV),
+    ('\U0000cdcd', '\U0000cde7', GC_LVT),
+    ('\U0000cde8', '\U0000cde8', GC_LV),
+    ('\U0000cde9', '\U0000ce03', GC_LVT),
+    ('\U0000ce04', '\U0000ce04', GC_LV),
+    ('\U0000ce05', '\U0000ce1f', GC_LVT),
+    ('\U0000ce20', '\U0000ce20', GC_LV),
+    ('\U0000ce21', '\U0000ce3b', GC_LVT),
+    ('\U0000ce3c', '\U0000ce3c', GC_LV),
+    ('\U0000ce3d', '\U0000ce57', GC_LVT),
+    ('\U0000ce58', '\U0000ce58', GC_LV),
+    ('\U0000ce59', '\U0000ce73', GC_LVT),
+    ('\U0000ce74', '\U0000ce74', GC_LV),
+    ('\U0000ce75', '\U0000ce8f', GC_LVT),
+    ('\U0000ce90', '\U0000ce90', GC_LV),
+    ('\U0000ce91', '\U0000ceab', GC_LVT),
+    ('\U0000ceac', '\U0000ceac', GC_LV),
+    ('\U0000cead', '\U0000cec7', GC_LVT),
+    ('\U0000cec8', '\U0000cec8', GC_LV),
+    ('\U0000cec9', '\U0000cee3', GC_LVT),
+    ('\U0000cee4', '\U0000cee4', GC_LV),
+    ('\U0000cee5', '\U0000ceff', GC_LVT),
+    ('\U0000cf00', '\U0000cf00', GC_LV),
+    ('\U0000cf01', '\U0000cf1b', GC_LVT),
+    ('\U0000cf1c', '\U0000cf1c', GC_LV),
+    ('\U0000cf1d', '\U0000cf37', GC_LVT),
+    ('\U0000cf38', '\U0000cf38', GC_LV),
+    ('\U0000cf39', '\U0000cf53', GC_LVT),
+    ('\U0000cf54', '\U0000cf54', GC_LV),
+    ('\U0000cf55', '\U0000cf6f', GC_LVT),
+    ('\U0000cf70', '\U0000cf70', GC_LV),
+    ('\U0000cf71', '\U0000cf8b', GC_LVT),
+    ('\U0000cf8c', '\U0000cf8c', GC_LV),
+    ('\U0000cf8d', '\U0000cfa7', GC_LVT),
+    ('\U0000cfa8', '\U0000cfa8', GC_LV),
+    ('\U0000cfa9', '\U0000cfc3', GC_LVT),
+    ('\U0000cfc4', '\U0000cfc4', GC_LV),
+    ('\U0000cfc5', '\U0000cfdf', GC_LVT),
+    ('\U0000cfe0', '\U0000cfe0', GC_LV),
+    ('\U0000cfe1', '\U0000cffb', GC_LVT),
+    ('\U0000cffc', '\U0000cffc', GC_LV),
+    ('\U0000cffd', '\U0000d017', GC_LVT),
+    ('\U0000d018', '\U0000d018', GC_LV),
+    ('\U0000d019', '\U0000d033', GC_LVT),
+    ('\U0000d034', '\U0000d034', GC_LV),
+    ('\U0000d035', '\U0000d04f', GC_LVT),
+    ('\U0000d050', '\U0000d050', GC_LV),
+    ('\U0000d051', '\U0000d06b', GC_LVT),
+    ('\U0000d06c', '\U0000d06c', GC_LV),
+    ('\U0000d06d', '\U0000d087', GC_LVT),
+    ('\U0000d088', '\U0000d088', GC_LV),
+    ('\U0000d089', '\U0000d0a3', GC_LVT),
+    ('\U0000d0a4', '\U0000d0a4', GC_LV),
+    ('\U0000d0a5', '\U0000d0bf', GC_LVT),
+    ('\U0000d0c0', '\U0000d0c0', GC_LV),
+    ('\U0000d0c1', '\U0000d0db', GC_LVT),
+    ('\U0000d0dc', '\U0000d0dc', GC_LV),
+    ('\U0000d0dd', '\U0000d0f7', GC_LVT),
+    ('\U0000d0f8', '\U0000d0f8', GC_LV),
+    ('\U0000d0f9', '\U0000d113', GC_LVT),
+    ('\U0000d114', '\U0000d114', GC_LV),
+    ('\U0000d115', '\U0000d12f', GC_LVT),
+    ('\U0000d130', '\U0000d130', GC_LV),
+    ('\U0000d131', '\U0000d14b', GC_LVT),
+    ('\U0000d14c', '\U0000d14c', GC_LV),
+    ('\U0000d14d', '\U0000d167', GC_LVT),
+    ('\U0000d168', '\U0000d168', GC_LV),
+    ('\U0000d169', '\U0000d183', GC_LVT),
+    ('\U0000d184', '\U0000d184', GC_LV),
+    ('\U0000d185', '\U0000d19f', GC_LVT),
+    ('\U0000d1a0', '\U0000d1a0', GC_LV),
+    ('\U0000d1a1', '\U0000d1bb', GC_LVT),
+    ('\U0000d1bc', '\U0000d1bc', GC_LV),
+    ('\U0000d1bd', '\U0000d1d7', GC_LVT),
+    ('\U0000d1d8', '\U0000d1d8', GC_LV),
+    ('\U0000d1d9', '\U0000d1f3', GC_LVT),
+    ('\U0000d1f4', '\U0000d1f4', GC_LV),
+    ('\U0000d1f5', '\U0000d20f', GC_LVT),
+    ('\U0000d210', '\U0000d210', GC_LV),
+    ('\U0000d211', '\U0000d22b', GC_LVT),
+    ('\U0000d22c', '\U0000d22c', GC_LV),
+    ('\U0000d22d', '\U0000d247', GC_LVT),
+    ('\U0000d248', '\U0000d248', GC_LV),
+    ('\U0000d249', '\U0000d263', GC_LVT),
+    ('\U0000d264', '\U0000d264', GC_LV),
+    ('\U0000d265', '\U0000d27f', GC_LVT),
+    ('\U0000d280', '\U0000d280', GC_LV),
+    ('\U0000d281', '\U0000d29b', GC_LVT),
+    ('\U0000d29c', '\U0000d29c', GC_LV),
+    ('\U0000d29d', '\U0000d2b7', GC_LVT),
+    ('\U0000d2b8', '\U0000d2b8', GC_LV),
+    ('\U0000d2b9', '\U0000d2d3', GC_LVT),
+    ('\U0000d2d4', '\U0000d2d4', GC_LV),
+    ('\U0000d2d5', '\U0000d2ef', GC_LVT),
+    ('\U0000d2f0', '\U0000d2f0', GC_LV),
+    ('\U0000d2f1', '\U0000d30b', GC_LVT),
+    ('\U0000d30c', '\U0000d30c', GC_LV),
+    ('\U0000d30d', '\U0000d327', GC_LVT),
+    ('\U0000d328', '\U0000d328', GC_LV),
+    ('\U0000d329', '\U0000d343', GC_LVT),
+    ('\U0000d344', '\U0000d344', GC_LV),
+    ('\U0000d345', '\U0000d35f', GC_LVT),
+    ('\U0000d360', '\U0000d360', GC_LV),
+    ('\U0000d361', '\U0000d37b', GC_LVT),
+    ('\U0000d37c', '\U0000d37c', GC_LV),
+    ('\U0000d37d', '\U0000d397', GC_LVT),
+    ('\U0000d398', '\U0000d398', GC_LV),
+    ('\U0000d399', '\U0000d3b3', GC_LVT),
+    ('\U0000d3b4', '\U0000d3b4', GC_LV),
+    ('\U0000d3b5', '\U0000d3cf', GC_LVT),
+    ('\U0000d3d0', '\U0000d3d0', GC_LV),
+    ('\U0000d3d1', '\U0000d3eb', GC_LVT),
+    ('\U0000d3ec', '\U0000d3ec', GC_LV),
+    ('\U0000d3ed', '\U0000d407', GC_LVT),
+    ('\U0000d408', '\U0000d408', GC_LV),
+    ('\U0000d409', '\U0000d423', GC_LVT),
+    ('\U0000d424', '\U0000d424', GC_LV),
+    ('\U0000d425', '\U0000d43f', GC_LVT),
+    ('\U0000d440', '\U0000d440', GC_LV),
+    ('\U0000d441', '\U0000d45b', GC_LVT),
+    ('\U0000d45c', '\U0000d45c', GC_LV),
+    ('\U0000d45d', '\U0000d477', GC_LVT),
+    ('\U0000d478', '\U0000d478', GC_LV),
+    ('\U0000d479', '\U0000d493', GC_LVT),
+    ('\U0000d494', '\U0000d494', GC_LV),
+    ('\U0000d495', '\U0000d4af', GC_LVT),
+    ('\U0000d4b0', '\U0000d4b0', GC_LV),
+    ('\U0000d4b1', '\U0000d4cb', GC_LVT),
+    ('\U0000d4cc', '\U0000d4cc', GC_LV),
+    ('\U0000d4cd', '\U0000d4e7', GC_LVT),
+    ('\U0000d4e8', '\U0000d4e8', GC_LV),
+    ('\U0000d4e9', '\U0000d503', GC_LVT),
+    ('\U0000d504', '\U0000d504', GC_LV),
+    ('\U0000d505', '\U0000d51f', GC_LVT),
+    ('\U0000d520', '\U0000d520', GC_LV),
+    ('\U0000d521', '\U0000d53b', GC_LVT),
+    ('\U0000d53c', '\U0000d53c', GC_LV),
+    ('\U0000d53d', '\U0000d557', GC_LVT),
+    ('\U0000d558', '\U0000d558', GC_LV),
+    ('\U0000d559', '\U0000d573', GC_LVT),
+    ('\U0000d574', '\U0000d574', GC_LV),
+    ('\U0000d575', '\U0000d58f', GC_LVT),
+    ('\U0000d590', '\U0000d590', GC_LV),
+    ('\U0000d591', '\U0000d5ab', GC_LVT),
+    ('\U0000d5ac', '\U0000d5ac', GC_LV),
+    ('\U0000d5ad', '\U0000d5c7', GC_LVT),
+    ('\U0000d5c8', '\U0000d5c8', GC_LV),
+    ('\U0000d5c9', '\U0000d5e3', GC_LVT),
+    ('\U0000d5e4', '\U0000d5e4', GC_LV),
+    ('\U0000d5e5', '\U0000d5ff', GC_LVT),
+    ('\U0000d600', '\U0000d600', GC_LV),
+    ('\U0000d601', '\U0000d61b', GC_LVT),
+    ('\U0000d61c', '\U0000d61c', GC_LV),
+    ('\U0000d61d', '\U0000d637', GC_LVT),
+    ('\U0000d638', '\U0000d638', GC_LV),
+    ('\U0000d639', '\U0000d653', GC_LVT),
+    ('\U0000d654', '\U0000d654', GC_LV),
+    ('\U0000d655', '\U0000d66f', GC_LVT),
+    ('\U0000d670', '\U0000d670', GC_LV),
+    ('\U0000d671', '\U0000d68b', GC_LVT),
+    ('\U0000d68c', '\U0000d68c', GC_LV),
+    ('\U0000d68d', '\U0000d6a7', GC_LVT),
+    ('\U0000d6a8', '\U0000d6a8', GC_LV),
+    ('\U0000d6a9', '\U0000d6c3', GC_LVT),
+    ('\U0000d6c4', '\U0000d6c4', GC_LV),
+    ('\U0000d6c5', '\U0000d6df', GC_LVT),
+    ('\U0000d6e0', '\U0000d6e0', GC_LV),
+    ('\U0000d6e1', '\U0000d6fb', GC_LVT),
+    ('\U0000d6fc', '\U0000d6fc', GC_LV),
+    ('\U0000d6fd', '\U0000d717', GC_LVT),
+    ('\U0000d718', '\U0000d718', GC_LV),
+    ('\U0000d719', '\U0000d733', GC_LVT),
+    ('\U0000d734', '\U0000d734', GC_LV),
+    ('\U0000d735', '\U0000d74f', GC_LVT),
+    ('\U0000d750', '\U0000d750', GC_LV),
+    ('\U0000d751', '\U0000d76b', GC_LVT),
+    ('\U0000d76c', '\U0000d76c', GC_LV),
+    ('\U0000d76d', '\U0000d787', GC_LVT),
+    ('\U0000d788', '\U0000d788', GC_LV),
+    ('\U0000d789', '\U0000d7a3', GC_LVT),
+    ('\U0000d7b0', '\U0000d7c6', GC_V),
+    ('\U0000d7cb', '\U0000d7fb', GC_T),
+    ('\U0000fb1e', '\U0000fb1e', GC_Extend),
+    ('\U0000fe00', '\U0000fe0f', GC_Extend),
+    ('\U0000fe20', '\U0000fe26', GC_Extend),
+    ('\U0000feff', '\U0000feff', GC_Control),
+    ('\U0000ff9e', '\U0000ff9f', GC_Extend),
+    ('\U0000fff0', '\U0000fffb', GC_Control),
+    ('\U000101fd', '\U000101fd', GC_Extend),
+    ('\U00010a01', '\U00010a03', GC_Extend),
+    ('\U00010a05', '\U00010a06', GC_Extend),
+    ('\U00010a0c', '\U00010a0f', GC_Extend),
+    ('\U00010a38', '\U00010a3a', GC_Extend),
+    ('\U00010a3f', '\U00010a3f', GC_Extend),
+    ('\U00011000', '\U00011000', GC_SpacingMark),
+    ('\U00011001', '\U00011001', GC_Extend),
+    ('\U00011002', '\U00011002', GC_SpacingMark),
+    ('\U00011038', '\U00011046', GC_Extend),
+    ('\U00011080', '\U00011081', GC_Extend),
+    ('\U00011082', '\U00011082', GC_SpacingMark),
+    ('\U000110b0', '\U000110b2', GC_SpacingMark),
+    ('\U000110b3', '\U000110b6', GC_Extend),
+    ('\U000110b7', '\U000110b8', GC_SpacingMark),
+    ('\U000110b9', '\U000110ba', GC_Extend),
+    ('\U000110bd', '\U000110bd', GC_Control),
+    ('\U00011100', '\U00011102', GC_Extend),
+    ('\U00011127', '\U0001112b', GC_Extend),
+    ('\U0001112c', '\U0001112c', GC_SpacingMark),
+    ('\U0001112d', '\U00011134', GC_Extend),
+    ('\U00011180', '\U00011181', GC_Extend),
+    ('\U00011182', '\U00011182', GC_SpacingMark),
+    ('\U000111b3', '\U000111b5', GC_SpacingMark),
+    ('\U000111b6', '\U000111be', GC_Extend),
+    ('\U000111bf', '\U000111c0', GC_SpacingMark),
+    ('\U000116ab', '\U000116ab', GC_Extend),
+    ('\U000116ac', '\U000116ac', GC_SpacingMark),
+    ('\U000116ad', '\U000116ad', GC_Extend),
+    ('\U000116ae', '\U000116af', GC_SpacingMark),
+    ('\U000116b0', '\U000116b5', GC_Extend),
+    ('\U000116b6', '\U000116b6', GC_SpacingMark),
+    ('\U000116b7', '\U000116b7', GC_Extend),
+    ('\U00016f51', '\U00016f7e', GC_SpacingMark),
+    ('\U00016f8f', '\U00016f92', GC_Extend),
+    ('\U0001d165', '\U0001d165', GC_Extend),
+    ('\U0001d166', '\U0001d166', GC_SpacingMark),
+    ('\U0001d167', '\U0001d169', GC_Extend),
+    ('\U0001d16d', '\U0001d16d', GC_SpacingMark),
+    ('\U0001d16e', '\U0001d172', GC_Extend),
+    ('\U0001d173', '\U0001d17a', GC_Control),
+    ('\U0001d17b', '\U0001d182', GC_Extend),
+    ('\U0001d185', '\U0001d18b', GC_Extend),
+    ('\U0001d1aa', '\U0001d1ad', GC_Extend),
+    ('\U0001d242', '\U0001d244', GC_Extend),
+    ('\U0001f1e6', '\U0001f1ff', GC_RegionalIndicator),
+    ('\U000e0000', '\U000e00ff', GC_Control),
+    ('\U000e0100', '\U000e01ef', GC_Extend),
+    ('\U000e01f0', '\U000e0fff', GC_Control)
+];
+
+/// The values of the Word_Break property from UAX#29.
+#[allow(non_camel_case_types)]
+#[deriving(Show, Eq)]
+pub enum WordCat {
+    WC_CR,
+    WC_LF,
+    WC_Newline,
+    WC_Extend,
+    WC_RegionalIndicator,
+    WC_Format,
+    WC_Katakana,
+    WC_HebrewLetter,
+    WC_ALetter,
+    WC_SingleQuote,
+    WC_DoubleQuote,
+    WC_MidNumLet,
+    WC_MidLetter,
+    WC_MidNum,
+    WC_Numeric,
+    WC_ExtendNumLet,
+    WC_Any,
+}
+
+/// Returns the Word_Break property value of `c`.
+pub fn word_category(c: char) -> WordCat {
+    match WORD_CAT_TABLE.bsearch(|&(s, e, _)| range_cmp(c, s, e)) {
+        None => WC_Any,
+        Some(i) => {
+            let (_, _, cat) = WORD_CAT_TABLE[i];
+            cat
+        }
+    }
+}
+
+static WORD_CAT_TABLE: &'static [(char, char, WordCat)] = &[
+    ('\U0000000a', '\U0000000a', WC_LF),
+    ('\U0000000b', '\U0000000c', WC_Newline),
+    ('\U0000000d', '\U0000000d', WC_CR),
+    ('\U00000022', '\U00000022', WC_DoubleQuote),
+    ('\U00000027', '\U00000027', WC_SingleQuote),
+    ('\U0000002c', '\U0000002c', WC_MidNum),
+    ('\U0000002e', '\U0000002e', WC_MidNumLet),
+    ('\U00000030', '\U00000039', WC_Numeric),
+    ('\U0000003a', '\U0000003a', WC_MidLetter),
+    ('\U0000003b', '\U0000003b', WC_MidNum),
+    ('\U00000041', '\U0000005a', WC_ALetter),
+    ('\U0000005f', '\U0000005f', WC_ExtendNumLet),
+    ('\U00000061', '\U0000007a', WC_ALetter),
+    ('\U00000085', '\U00000085', WC_Newline),
+    ('\U000000aa', '\U000000aa', WC_ALetter),
+    ('\U000000ad', '\U000000ad', WC_Format),
+    ('\U000000b5', '\U000000b5', WC_ALetter),
+    ('\U000000b7', '\U000000b7', WC_MidLetter),
+    ('\U000000b8', '\U000000b8', WC_ALetter),
+    ('\U000000ba', '\U000000ba', WC_ALetter),
+    ('\U000000c0', '\U000000d6', WC_ALetter),
+    ('\U000000d8', '\U000000f6', WC_ALetter),
+    ('\U000000f8', '\U000002d7', WC_ALetter),
+    ('\U000002de', '\U000002ff', WC_ALetter),
+    ('\U00000300', '\U0000036f', WC_Extend),
+    ('\U00000370', '\U00000374', WC_ALetter),
+    ('\U00000376', '\U00000377', WC_ALetter),
+    ('\U0000037a', '\U0000037d', WC_ALetter),
+    ('\U0000037e', '\U0000037e', WC_MidNum),
+    ('\U00000386', '\U00000386', WC_ALetter),
+    ('\U00000387', '\U00000387', WC_MidLetter),
+    ('\U00000388', '\U0000038a', WC_ALetter),
+    ('\U0000038c', '\U0000038c', WC_ALetter),
+    ('\U0000038e', '\U000003a1', WC_ALetter),
+    ('\U000003a3', '\U000003f5', WC_ALetter),
+    ('\U000003f7', '\U00000481', WC_ALetter),
+    ('\U00000483', '\U00000489', WC_Extend),
+    ('\U0000048a', '\U00000527', WC_ALetter),
+    ('\U00000531', '\U00000556', WC_ALetter),
+    ('\U00000559', '\U0000055c', WC_ALetter),
+    ('\U0000055e', '\U0000055e', WC_ALetter),
+    ('\U0000055f', '\U0000055f', WC_MidLetter),
+    ('\U00000561', '\U00000587', WC_ALetter),
+    ('\U00000589', '\U00000589', WC_MidNum),
+    ('\U0000058a', '\U0000058a', WC_ALetter),
+    ('\U00000591', '\U000005bd', WC_Extend),
+    ('\U000005bf', '\U000005bf', WC_Extend),
+    ('\U000005c1', '\U000005c2', WC_Extend),
+    ('\U000005c4', '\U000005c5', WC_Extend),
+    ('\U000005c7', '\U000005c7', WC_Extend),
+    ('\U000005d0', '\U000005ea', WC_HebrewLetter),
+    ('\U000005f0', '\U000005f2', WC_HebrewLetter),
+    ('\U000005f3', '\U000005f3', WC_ALetter),
+    ('\U000005f4', '\U000005f4', WC_MidLetter),
+    ('\U00000600', '\U00000604', WC_Numeric),
+    ('\U0000060c', '\U0000060d', WC_MidNum),
+    ('\U00000610', '\U0000061a', WC_Extend),
+    ('\U0000061c', '\U0000061c', WC_Format),
+    ('\U00000620', '\U0000064a', WC_ALetter),
+    ('\U0000064b', '\U0000065f', WC_Extend),
+    ('\U00000660', '\U00000669', WC_Numeric),
+    ('\U0000066b', '\U0000066b', WC_Numeric),
+    ('\U0000066c', '\U0000066c', WC_MidNum),
+    ('\U0000066e', '\U0000066f', WC_ALetter),
+    ('\U00000670', '\U00000670', WC_Extend),
+    ('\U00000671', '\U000006d3', WC_ALetter),
+    ('\U000006d5', '\U000006d5', WC_ALetter),
+    ('\U000006d6', '\U000006dc', WC_Extend),
+    ('\U000006dd', '\U000006dd', WC_Numeric),
+    ('\U000006df', '\U000006e4', WC_Extend),
+    ('\U000006e5', '\U000006e6', WC_ALetter),
+    ('\U000006e7', '\U000006e8', WC_Extend),
+    ('\U000006ea', '\U000006ed', WC_Extend),
+    ('\U000006ee', '\U000006ef', WC_ALetter),
+    ('\U000006f0', '\U000006f9', WC_Numeric),
+    ('\U000006fa', '\U000006fc', WC_ALetter),
+    ('\U000006ff', '\U000006ff', WC_ALetter),
+    ('\U0000070f', '\U00000710', WC_ALetter),
+    ('\U00000711', '\U00000711', WC_Extend),
+    ('\U00000712', '\U0000072f', WC_ALetter),
+    ('\U00000730', '\U0000074a', WC_Extend),
+    ('\U0000074d', '\U000007a5', WC_ALetter),
+    ('\U000007a6', '\U000007b0', WC_Extend),
+    ('\U000007b1', '\U000007b1', WC_ALetter),
+    ('\U000007c0', '\U000007c9', WC_Numeric),
+    ('\U000007ca', '\U000007ea', WC_ALetter),
+    ('\U000007eb', '\U000007f3', WC_Extend),
+    ('\U000007f4', '\U000007f5', WC_ALetter),
+    ('\U000007f8', '\U000007f8', WC_MidNum),
+    ('\U000007fa', '\U000007fa', WC_ALetter),
+    ('\U00000800', '\U00000815', WC_ALetter),
+    ('\U00000816', '\U00000819', WC_Extend),
+    ('\U0000081a', '\U0000081a', WC_ALetter),
+    ('\U0000081b', '\U00000823', WC_Extend),
+    ('\U00000824', '\U00000824', WC_ALetter),
+    ('\U00000825', '\U00000827', WC_Extend),
+    ('\U00000828', '\U00000828', WC_ALetter),
+    ('\U00000829', '\U0000082d', WC_Extend),
+    ('\U00000840', '\U00000858', WC_ALetter),
+    ('\U00000859', '\U0000085b', WC_Extend),
+    ('\U000008a0', '\U000008a0', WC_ALetter),
+    ('\U000008a2', '\U000008ac', WC_ALetter),
+    ('\U000008e4', '\U000008fe', WC_Extend),
+    ('\U00000900', '\U00000903', WC_Extend),
+    ('\U00000904', '\U00000939', WC_ALetter),
+    ('\U0000093a', '\U0000093c', WC_Extend),
+    ('\U0000093d', '\U0000093d', WC_ALetter),
+    ('\U0000093e', '\U0000094f', WC_Extend),
+    ('\U00000950', '\U00000950', WC_ALetter),
+    ('\U00000951', '\U00000957', WC_Extend),
+    ('\U00000958', '\U00000961', WC_ALetter),
+    ('\U00000962', '\U00000963', WC_Extend),
+    ('\U00000966', '\U0000096f', WC_Numeric),
+    ('\U00000971', '\U00000977', WC_ALetter),
+    ('\U00000979', '\U0000097f', WC_ALetter),
+    ('\U00000981', '\U00000983', WC_Extend),
+    ('\U00000985', '\U0000098c', WC_ALetter),
+    ('\U0000098f', '\U00000990', WC_ALetter),
+    ('\U00000993', '\U000009a8', WC_ALetter),
+    ('\U000009aa', '\U000009b0', WC_ALetter),
+    ('\U000009b2', '\U000009b2', WC_ALetter),
+    ('\U000009b6', '\U000009b9', WC_ALetter),
+    ('\U000009bc', '\U000009bc', WC_Extend),
+    ('\U000009bd', '\U000009bd', WC_ALetter),
+    ('\U000009be', '\U000009c4', WC_Extend),
+    ('\U000009c7', '\U000009c8', WC_Extend),
+    ('\U000009cb', '\U000009cd', WC_Extend),
+    ('\U000009ce', '\U000009ce', WC_ALetter),
+    ('\U000009d7', '\U000009d7', WC_Extend),
+    ('\U000009dc', '\U000009dd', WC_ALetter),
+    ('\U000009df', '\U000009e1', WC_ALetter),
+    ('\U000009e2', '\U000009e3', WC_Extend),
+    ('\U000009e6', '\U000009ef', WC_Numeric),
+    ('\U000009f0', '\U000009f1', WC_ALetter),
+    ('\U00000a01', '\U00000a03', WC_Extend),
+    ('\U00000a05', '\U00000a0a', WC_ALetter),
+    ('\U00000a0f', '\U00000a10', WC_ALetter),
+    ('\U00000a13', '\U00000a28', WC_ALetter),
+    ('\U00000a2a', '\U00000a30', WC_ALetter),
+    ('\U00000a32', '\U00000a33', WC_ALetter),
+    ('\U00000a35', '\U00000a36', WC_ALetter),
+    ('\U00000a38', '\U00000a39', WC_ALetter),
+    ('\U00000a3c', '\U00000a3c', WC_Extend),
+    ('\U00000a3e', '\U00000a42', WC_Extend),
+    ('\U00000a47', '\U00000a48', WC_Extend),
+    ('\U00000a4b', '\U00000a4d', WC_Extend),
+    ('\U00000a51', '\U00000a51', WC_Extend),
+    ('\U00000a59', '\U00000a5c', WC_ALetter),
+    ('\U00000a5e', '\U00000a5e', WC_ALetter),
+    ('\U00000a66', '\U00000a6f', WC_Numeric),
+    ('\U00000a70', '\U00000a71', WC_Extend),
+    ('\U00000a72', '\U00000a74', WC_ALetter),
+    ('\U00000a75', '\U00000a75', WC_Extend),
+    ('\U00000a81', '\U00000a83', WC_Extend),
+    ('\U00000a85', '\U00000a8d', WC_ALetter),
+    ('\U00000a8f', '\U00000a91', WC_ALetter),
+    ('\U00000a93', '\U00000aa8', WC_ALetter),
+    ('\U00000aaa', '\U00000ab0', WC_ALetter),
+    ('\U00000ab2', '\U00000ab3', WC_ALetter),
+    ('\U00000ab5', '\U00000ab9', WC_ALetter),
+    ('\U00000abc', '\U00000abc', WC_Extend),
+    ('\U00000abd', '\U00000abd', WC_ALetter),
+    ('\U00000abe', '\U00000ac5', WC_Extend),
+    ('\U00000ac7', '\U00000ac9', WC_Extend),
+    ('\U00000acb', '\U00000acd', WC_Extend),
+    ('\U00000ad0', '\U00000ad0', WC_ALetter),
+    ('\U00000ae0', '\U00000ae1', WC_ALetter),
+    ('\U00000ae2', '\U00000ae3', WC_Extend),
+    ('\U00000ae6', '\U00000aef', WC_Numeric),
+    ('\U00000b01', '\U00000b03', WC_Extend),
+    ('\U00000b05', '\U00000b0c', WC_ALetter),
+    ('\U00000b0f', '\U00000b10', WC_ALetter),
+    ('\U00000b13', '\U00000b28', WC_ALetter),
+    ('\U00000b2a', '\U00000b30', WC_ALetter),
+    ('\U00000b32', '\U00000b33', WC_ALetter),
+    ('\U00000b35', '\U00000b39', WC_ALetter),
+    ('\U00000b3c', '\U00000b3c', WC_Extend),
+    ('\U00000b3d', '\U00000b3d', WC_ALetter),
+    ('\U00000b3e', '\U00000b44', WC_Extend),
+    ('\U00000b47', '\U00000b48', WC_Extend),
+    ('\U00000b4b', '\U00000b4d', WC_Extend),
+    ('\U00000b56', '\U00000b57', WC_Extend),
+    ('\U00000b5c', '\U00000b5d', WC_ALetter),
+    ('\U00000b5f', '\U00000b61', WC_ALetter),
+    ('\U00000b62', '\U00000b63', WC_Extend),
+    ('\U00000b66', '\U00000b6f', WC_Numeric),
+    ('\U00000b71', '\U00000b71', WC_ALetter),
+    ('\U00000b82', '\U00000b82', WC_Extend),
+    ('\U00000b83', '\U00000b83', WC_ALetter),
+    ('\U00000b85', '\U00000b8a', WC_ALetter),
+    ('\U00000b8e', '\U00000b90', WC_ALetter),
+    ('\U00000b92', '\U00000b95', WC_ALetter),
+    ('\U00000b99', '\U00000b9a', WC_ALetter),
+    ('\U00000b9c', '\U00000b9c', WC_ALetter),
+    ('\U00000b9e', '\U00000b9f', WC_ALetter),
+    ('\U00000ba3', '\U00000ba4', WC_ALetter),
+    ('\U00000ba8', '\U00000baa', WC_ALetter),
+    ('\U00000bae', '\U00000bb9', WC_ALetter),
+    ('\U00000bbe', '\U00000bc2', WC_Extend),
+    ('\U00000bc6', '\U00000bc8', WC_Extend),
+    ('\U00000bca', '\U00000bcd', WC_Extend),
+    ('\U00000bd0', '\U00000bd0', WC_ALetter),
+    ('\U00000bd7', '\U00000bd7', WC_Extend),
+    ('\U00000be6', '\U00000bef', WC_Numeric),
+    ('\U00000c01', '\U00000c03', WC_Extend),
+    ('\U00000c05', '\U00000c0c', WC_ALetter),
+    ('\U00000c0e', '\U00000c10', WC_ALetter),
+    ('\U00000c12', '\U00000c28', WC_ALetter),
+    ('\U00000c2a', '\U00000c33', WC_ALetter),
+    ('\U00000c35', '\U00000c39', WC_ALetter),
+    ('\U00000c3d', '\U00000c3d', WC_ALetter),
+    ('\U00000c3e', '\U00000c44', WC_Extend),
+    ('\U00000c46', '\U00000c48', WC_Extend),
+    ('\U00000c4a', '\U00000c4d', WC_Extend),
+    ('\U00000c55', '\U00000c56', WC_Extend),
+    ('\U00000c58', '\U00000c59', WC_ALetter),
+    ('\U00000c60', '\U00000c61', WC_ALetter),
+    ('\U00000c62', '\U00000c63', WC_Extend),
+    ('\U00000c66', '\U00000c6f', WC_Numeric),
+    ('\U00000c82', '\U00000c83', WC_Extend),
+    ('\U00000c85', '\U00000c8c', WC_ALetter),
+    ('\U00000c8e', '\U00000c90', WC_ALetter),
+    ('\U00000c92', '\U00000ca8', WC_ALetter),
+    ('\U00000caa', '\U00000cb3', WC_ALetter),
+    ('\U00000cb5', '\U00000cb9', WC_ALetter),
+    ('\U00000cbc', '\U00000cbc', WC_Extend),
+    ('\U00000cbd', '\U00000cbd', WC_ALetter),
+    ('\U00000cbe', '\U00000cc4', WC_Extend),
+    ('\U00000cc6', '\U00000cc8', WC_Extend),
+    ('\U00000cca', '\U00000ccd', WC_Extend),
+    ('\U00000cd5', '\U00000cd6', WC_Extend),
+    ('\U00000cde', '\U00000cde', WC_ALetter),
+    ('\U00000ce0', '\U00000ce1', WC_ALetter),
+    ('\U00000ce2', '\U00000ce3', WC_Extend),
+    ('\U00000ce6', '\U00000cef', WC_Numeric),
+    ('\U00000cf1', '\U00000cf2', WC_ALetter),
+    ('\U00000d02', '\U00000d03', WC_Extend),
+    ('\U00000d05', '\U00000d0c', WC_ALetter),
+    ('\U00000d0e', '\U00000d10', WC_ALetter),
+    ('\U00000d12', '\U00000d3a', WC_ALetter),
+    ('\U00000d3d', '\U00000d3d', WC_ALetter),
+    ('\U00000d3e', '\U00000d44', WC_Extend),
+    ('\U00000d46', '\U00000d48', WC_Extend),
+    ('\U00000d4a', '\U00000d4d', WC_Extend),
+    ('\U00000d4e', '\U00000d4e', WC_ALetter),
+    ('\U00000d57', '\U00000d57', WC_Extend),
+    ('\U00000d60', '\U00000d61', WC_ALetter),
+    ('\U00000d62', '\U00000d63', WC_Extend),
+    ('\U00000d66', '\U00000d6f', WC_Numeric),
+    ('\U00000d7a', '\U00000d7f', WC_ALetter),
+    ('\U00000d82', '\U00000d83', WC_Extend),
+    ('\U00000d85', '\U00000d96', WC_ALetter),
+    ('\U00000d9a', '\U00000db1', WC_ALetter),
+    ('\U00000db3', '\U00000dbb', WC_ALetter),
+    ('\U00000dbd', '\U00000dbd', WC_ALetter),
+    ('\U00000dc0', '\U00000dc6', WC_ALetter),
+    ('\U00000dca', '\U00000dca', WC_Extend),
+    ('\U00000dcf', '\U00000dd4', WC_Extend),
+    ('\U00000dd6', '\U00000dd6', WC_Extend),
+    ('\U00000dd8', '\U00000ddf', WC_Extend),
+    ('\U00000df2', '\U00000df3', WC_Extend),
+    ('\U00000e31', '\U00000e31', WC_Extend),
+    ('\U00000e34', '\U00000e3a', WC_Extend),
+    ('\U00000e47', '\U00000e4e', WC_Extend),
+    ('\U00000e50', '\U00000e59', WC_Numeric),
+    ('\U00000eb1', '\U00000eb1', WC_Extend),
+    ('\U00000eb4', '\U00000eb9', WC_Extend),
+    ('\U00000ebb', '\U00000ebc', WC_Extend),
+    ('\U00000ec8', '\U00000ecd', WC_Extend),
+    ('\U00000ed0', '\U00000ed9', WC_Numeric),
+    ('\U00000f00', '\U00000f00', WC_ALetter),
+    ('\U00000f18', '\U00000f19', WC_Extend),
+    ('\U00000f20', '\U00000f29', WC_Numeric),
+    ('\U00000f35', '\U00000f35', WC_Extend),
+    ('\U00000f37', '\U00000f37', WC_Extend),
+    ('\U00000f39', '\U00000f39', WC_Extend),
+    ('\U00000f3e', '\U00000f3f', WC_Extend),
+    ('\U00000f40', '\U00000f47', WC_ALetter),
+    ('\U00000f49', '\U00000f6c', WC_ALetter),
+    ('\U00000f71', '\U00000f84', WC_Extend),
+    ('\U00000f86', '\U00000f87', WC_Extend),
+    ('\U00000f88', '\U00000f8c', WC_ALetter),
+    ('\U00000f8d', '\U00000f97', WC_Extend),
+    ('\U00000f99', '\U00000fbc', WC_Extend),
+    ('\U00000fc6', '\U00000fc6', WC_Extend),
+    ('\U0000102b', '\U0000103e', WC_Extend),
+    ('\U00001040', '\U00001049', WC_Numeric),
+    ('\U00001056', '\U00001059', WC_Extend),
+    ('\U0000105e', '\U00001060', WC_Extend),
+    ('\U00001062', '\U00001064', WC_Extend),
+    ('\U00001067', '\U0000106d', WC_Extend),
+    ('\U00001071', '\U00001074', WC_Extend),
+    ('\U00001082', '\U0000108d', WC_Extend),
+    ('\U0000108f', '\U0000108f', WC_Extend),
+    ('\U00001090', '\U00001099', WC_Numeric),
+    ('\U0000109a', '\U0000109d', WC_Extend),
+    ('\U000010a0', '\U000010c5', WC_ALetter),
+    ('\U000010c7', '\U000010c7', WC_ALetter),
+    ('\U000010cd', '\U000010cd', WC_ALetter),
+    ('\U000010d0', '\U000010fa', WC_ALetter),
+    ('\U000010fc', '\U00001248', WC_ALetter),
+    ('\U0000124a', '\U0000124d', WC_ALetter),
+    ('\U00001250', '\U00001256', WC_ALetter),
+    ('\U00001258', '\U00001258', WC_ALetter),
+    ('\U0000125a', '\U0000125d', WC_ALetter),
+    ('\U00001260', '\U00001288', WC_ALetter),
+    ('\U0000128a', '\U0000128d', WC_ALetter),
+    ('\U00001290', '\U000012b0', WC_ALetter),
+    ('\U000012b2', '\U000012b5', WC_ALetter),
+    ('\U000012b8', '\U000012be', WC_ALetter),
+    ('\U000012c0', '\U000012c0', WC_ALetter),
+    ('\U000012c2', '\U000012c5', WC_ALetter),
+    ('\U000012c8', '\U000012d6', WC_ALetter),
+    ('\U000012d8', '\U00001310', WC_ALetter),
+    ('\U00001312', '\U00001315', WC_ALetter),
+    ('\U00001318', '\U0000135a', WC_ALetter),
+    ('\U0000135d', '\U0000135f', WC_Extend),
+    ('\U00001380', '\U0000138f', WC_ALetter),
+    ('\U000013a0', '\U000013f4', WC_ALetter),
+    ('\U00001401', '\U0000166c', WC_ALetter),
+    ('\U0000166f', '\U0000167f', WC_ALetter),
+    ('\U00001681', '\U0000169a', WC_ALetter),
+    ('\U000016a0', '\U000016ea', WC_ALetter),
+    ('\U000016ee', '\U000016f0', WC_ALetter),
+    ('\U00001700', '\U0000170c', WC_ALetter),
+    ('\U0000170e', '\U00001711', WC_ALetter),
+    ('\U00001712', '\U00001714', WC_Extend),
+    ('\U00001720', '\U00001731', WC_ALetter),
+    ('\U00001732', '\U00001734', WC_Extend),
+    ('\U00001740', '\U00001751', WC_ALetter),
+    ('\U00001752', '\U00001753', WC_Extend),
+    ('\U00001760', '\U0000176c', WC_ALetter),
+    ('\U0000176e', '\U00001770', WC_ALetter),
+    ('\U00001772', '\U00001773', WC_Extend),
+    ('\U000017b4', '\U000017d3', WC_Extend),
+    ('\U000017dd', '\U000017dd', WC_Extend),
+    ('\U000017e0', '\U000017e9', WC_Numeric),
+    ('\U0000180b', '\U0000180d', WC_Extend),
+    ('\U0000180e', '\U0000180e', WC_Format),
+    ('\U00001810', '\U00001819', WC_Numeric),
+    ('\U00001820', '\U00001877', WC_ALetter),
+    ('\U00001880', '\U00001884', WC_ALetter),
+    ('\U00001885', '\U00001886', WC_Extend),
+    ('\U00001887', '\U000018a8', WC_ALetter),
+    ('\U000018a9', '\U000018a9', WC_Extend),
+    ('\U000018aa', '\U000018aa', WC_ALetter),
+    ('\U000018b0', '\U000018f5', WC_ALetter),
+    ('\U00001900', '\U0000191c', WC_ALetter),
+    ('\U00001920', '\U0000192b', WC_Extend),
+    ('\U00001930', '\U0000193b', WC_Extend),
+    ('\U00001946', '\U0000194f', WC_Numeric),
+    ('\U000019d0', '\U000019da', WC_Numeric),
+    ('\U00001a00', '\U00001a16', WC_ALetter),
+    ('\U00001a17', '\U00001a1b', WC_Extend),
+    ('\U00001a55', '\U00001a5e', WC_Extend),
+    ('\U00001a60', '\U00001a7c', WC_Extend),
+    ('\U00001a7f', '\U00001a7f', WC_Extend),
+    ('\U00001a80', '\U00001a89', WC_Numeric),
+    ('\U00001a90', '\U00001a99', WC_Numeric),
+    ('\U00001b00', '\U00001b04', WC_Extend),
+    ('\U00001b05', '\U00001b33', WC_ALetter),
+    ('\U00001b34', '\U00001b44', WC_Extend),
+    ('\U00001b45', '\U00001b4b', WC_ALetter),
+    ('\U00001b50', '\U00001b59', WC_Numeric),
+    ('\U00001b6b', '\U00001b73', WC_Extend),
+    ('\U00001b80', '\U00001b82', WC_Extend),
+    ('\U00001b83', '\U00001ba0', WC_ALetter),
+    ('\U00001ba1', '\U00001bad', WC_Extend),
+    ('\U00001bae', '\U00001baf', WC_ALetter),
+    ('\U00001bb0', '\U00001bb9', WC_Numeric),
+    ('\U00001bba', '\U00001be5', WC_ALetter),
+    ('\U00001be6', '\U00001bf3', WC_Extend),
+    ('\U00001c00', '\U00001c23', WC_ALetter),
+    ('\U00001c24', '\U00001c37', WC_Extend),
+    ('\U00001c40', '\U00001c49', WC_Numeric),
+    ('\U00001c4d', '\U00001c4f', WC_ALetter),
+    ('\U00001c50', '\U00001c59', WC_Numeric),
+    ('\U00001c5a', '\U00001c7d', WC_ALetter),
+    ('\U00001cd0', '\U00001cd2', WC_Extend),
+    ('\U00001cd4', '\U00001ce8', WC_Extend),
+    ('\U00001ce9', '\U00001cec', WC_ALetter),
+    ('\U00001ced', '\U00001ced', WC_Extend),
+    ('\U00001cee', '\U00001cf3', WC_ALetter),
+    ('\U00001cf4', '\U00001cf4', WC_Extend),
+    ('\U00001cf5', '\U00001cf6', WC_ALetter),
+    ('\U00001d00', '\U00001dbf', WC_ALetter),
+    ('\U00001dc0', '\U00001de6', WC_Extend),
+    ('\U00001dfc', '\U00001dff', WC_Extend),
+    ('\U00001e00', '\U00001f15', WC_ALetter),
+    ('\U00001f18', '\U00001f1d', WC_ALetter),
+    ('\U00001f20', '\U00001f45', WC_ALetter),
+    ('\U00001f48', '\U00001f4d', WC_ALetter),
+    ('\U00001f50', '\U00001f57', WC_ALetter),
+    ('\U00001f59', '\U00001f59', WC_ALetter),
+    ('\U00001f5b', '\U00001f5b', WC_ALetter),
+    ('\U00001f5d', '\U00001f5d', WC_ALetter),
+    ('\U00001f5f', '\U00001f7d', WC_ALetter),
+    ('\U00001f80', '\U00001fb4', WC_ALetter),
+    ('\U00001fb6', '\U00001fbc', WC_ALetter),
+    ('\U00001fbe', '\U00001fbe', WC_ALetter),
+    ('\U00001fc2', '\U00001fc4', WC_ALetter),
+    ('\U00001fc6', '\U00001fcc', WC_ALetter),
+    ('\U00001fd0', '\U00001fd3', WC_ALetter),
+    ('\U00001fd6', '\U00001fdb', WC_ALetter),
+    ('\U00001fe0', '\U00001fec', WC_ALetter),
+    ('\U00001ff2', '\U00001ff4', WC_ALetter),
+    ('\U00001ff6', '\U00001ffc', WC_ALetter),
+    ('\U0000200c', '\U0000200d', WC_Extend),
+    ('\U0000200e', '\U0000200f', WC_Format),
+    ('\U00002018', '\U00002019', WC_MidNumLet),
+    ('\U00002024', '\U00002024', WC_MidNumLet),
+    ('\U00002027', '\U00002027', WC_MidLetter),
+    ('\U00002028', '\U00002029', WC_Newline),
+    ('\U0000202a', '\U0000202e', WC_Format),
+    ('\U0000202f', '\U0000202f', WC_ExtendNumLet),
+    ('\U0000203f', '\U00002040', WC_ExtendNumLet),
+    ('\U00002044', '\U00002044', WC_MidNum),
+    ('\U00002054', '\U00002054', WC_ExtendNumLet),
+    ('\U00002060', '\U00002064', WC_Format),
+    ('\U00002066', '\U0000206f', WC_Format),
+    ('\U00002071', '\U00002071', WC_ALetter),
+    ('\U0000207f', '\U0000207f', WC_ALetter),
+    ('\U00002090', '\U0000209c', WC_ALetter),
+    ('\U000020d0', '\U000020f0', WC_Extend),
+    ('\U00002102', '\U00002102', WC_ALetter),
+    ('\U00002107', '\U00002107', WC_ALetter),
+    ('\U0000210a', '\U00002113', WC_ALetter),
+    ('\U00002115', '\U00002115', WC_ALetter),
+    ('\U00002119', '\U0000211d', WC_ALetter),
+    ('\U00002124', '\U00002124', WC_ALetter),
+    ('\U00002126', '\U00002126', WC_ALetter),
+    ('\U00002128', '\U00002128', WC_ALetter),
+    ('\U0000212a', '\U0000212d', WC_ALetter),
+    ('\U0000212f', '\U00002139', WC_ALetter),
+    ('\U0000213c', '\U0000213f', WC_ALetter),
+    ('\U00002145', '\U00002149', WC_ALetter),
+    ('\U0000214e', '\U0000214e', WC_ALetter),
+    ('\U00002160', '\U00002188', WC_ALetter),
+    ('\U000024b6', '\U000024e9', WC_ALetter),
+    ('\U00002c00', '\U00002c2e', WC_ALetter),
+    ('\U00002c30', '\U00002c5e', WC_ALetter),
+    ('\U00002c60', '\U00002ce4', WC_ALetter),
+    ('\U00002ceb', '\U00002cee', WC_ALetter),
+    ('\U00002cef', '\U00002cf1', WC_Extend),
+    ('\U00002cf2', '\U00002cf3', WC_ALetter),
+    ('\U00002d00', '\U00002d25', WC_ALetter),
+    ('\U00002d27', '\U00002d27', WC_ALetter),
+    ('\U00002d2d', '\U00002d2d', WC_ALetter),
+    ('\U00002d30', '\U00002d67', WC_ALetter),
+    ('\U00002d6f', '\U00002d6f', WC_ALetter),
+    ('\U00002d7f', '\U00002d7f', WC_Extend),
+    ('\U00002d80', '\U00002d96', WC_ALetter),
+    ('\U00002da0', '\U00002da6', WC_ALetter),
+    ('\U00002da8', '\U00002dae', WC_ALetter),
+    ('\U00002db0', '\U00002db6', WC_ALetter),
+    ('\U00002db8', '\U00002dbe', WC_ALetter),
+    ('\U00002dc0', '\U00002dc6', WC_ALetter),
+    ('\U00002dc8', '\U00002dce', WC_ALetter),
+    ('\U00002dd0', '\U00002dd6', WC_ALetter),
+    ('\U00002dd8', '\U00002dde', WC_ALetter),
+    ('\U00002de0', '\U00002dff', WC_Extend),
+    ('\U00002e2f', '\U00002e2f', WC_ALetter),
+    ('\U00003005', '\U00003005', WC_ALetter),
+    ('\U0000302a', '\U0000302f', WC_Extend),
+    ('\U00003031', '\U00003035', WC_Katakana),
+    ('\U0000303b', '\U0000303c', WC_ALetter),
+    ('\U00003099', '\U0000309a', WC_Extend),
+    ('\U0000309b', '\U0000309c', WC_Katakana),
+    ('\U000030a0', '\U000030fa', WC_Katakana),
+    ('\U000030fc', '\U000030ff', WC_Katakana),
+    ('\U00003105', '\U0000312d', WC_ALetter),
+    ('\U00003131', '\U0000318e', WC_ALetter),
+    ('\U000031a0', '\U000031ba', WC_ALetter),
+    ('\U000031f0', '\U000031ff', WC_Katakana),
+    ('\U000032d0', '\U000032fe', WC_Katakana),
+    ('\U00003300', '\U00003357', WC_Katakana),
+    ('\U0000a000', '\U0000a48c', WC_ALetter),
+    ('\U0000a4d0', '\U0000a4fd', WC_ALetter),
+    ('\U0000a500', '\U0000a60c', WC_ALetter),
+    ('\U0000a610', '\U0000a61f', WC_ALetter),
+    ('\U0000a620', '\U0000a629', WC_Numeric),
+    ('\U0000a62a', '\U0000a62b', WC_ALetter),
+    ('\U0000a640', '\U0000a66e', WC_ALetter),
+    ('\U0000a66f', '\U0000a672', WC_Extend),
+    ('\U0000a674', '\U0000a67d', WC_Extend),
+    ('\U0000a67f', '\U0000a697', WC_ALetter),
+    ('\U0000a69f', '\U0000a69f', WC_Extend),
+    ('\U0000a6a0', '\U0000a6ef', WC_ALetter),
+    ('\U0000a6f0', '\U0000a6f1', WC_Extend),
+    ('\U0000a708', '\U0000a78e', WC_ALetter),
+    ('\U0000a790', '\U0000a793', WC_ALetter),
+    ('\U0000a7a0', '\U0000a7aa', WC_ALetter),
+    ('\U0000a7f8', '\U0000a801', WC_ALetter),
+    ('\U0000a802', '\U0000a802', WC_Extend),
+    ('\U0000a803', '\U0000a805', WC_ALetter),
+    ('\U0000a806', '\U0000a806', WC_Extend),
+    ('\U0000a807', '\U0000a80a', WC_ALetter),
+    ('\U0000a80b', '\U0000a80b', WC_Extend),
+    ('\U0000a80c', '\U0000a822', WC_ALetter),
+    ('\U0000a823', '\U0000a827', WC_Extend),
+    ('\U0000a840', '\U0000a873', WC_ALetter),
+    ('\U0000a880', '\U0000a881', WC_Extend),
+    ('\U0000a882', '\U0000a8b3', WC_ALetter),
+    ('\U0000a8b4', '\U0000a8c4', WC_Extend),
+    ('\U0000a8d0', '\U0000a8d9', WC_Numeric),
+    ('\U0000a8e0', '\U0000a8f1', WC_Extend),
+    ('\U0000a8f2', '\U0000a8f7', WC_ALetter),
+    ('\U0000a8fb', '\U0000a8fb', WC_ALetter),
+    ('\U0000a900', '\U0000a909', WC_Numeric),
+    ('\U0000a90a', '\U0000a925', WC_ALetter),
+    ('\U0000a926', '\U0000a92d', WC_Extend),
+    ('\U0000a930', '\U0000a946', WC_ALetter),
+    ('\U0000a947', '\U0000a953', WC_Extend),
+    ('\U0000a960', '\U0000a97c', WC_ALetter),
+    ('\U0000a980', '\U0000a983', WC_Extend),
+    ('\U0000a984', '\U0000a9b2', WC_ALetter),
+    ('\U0000a9b3', '\U0000a9c0', WC_Extend),
+    ('\U0000a9cf', '\U0000a9cf', WC_ALetter),
+    ('\U0000a9d0', '\U0000a9d9', WC_Numeric),
+    ('\U0000aa00', '\U0000aa28', WC_ALetter),
+    ('\U0000aa29', '\U0000aa36', WC_Extend),
+    ('\U0000aa40', '\U0000aa42', WC_ALetter),
+    ('\U0000aa43', '\U0000aa43', WC_Extend),
+    ('\U0000aa44', '\U0000aa4b', WC_ALetter),
+    ('\U0000aa4c', '\U0000aa4d', WC_Extend),
+    ('\U0000aa50', '\U0000aa59', WC_Numeric),
+    ('\U0000aa7b', '\U0000aa7b', WC_Extend),
+    ('\U0000aab0', '\U0000aab0', WC_Extend),
+    ('\U0000aab2', '\U0000aab4', WC_Extend),
+    ('\U0000aab7', '\U0000aab8', WC_Extend),
+    ('\U0000aabe', '\U0000aabf', WC_Extend),
+    ('\U0000aac1', '\U0000aac1', WC_Extend),
+    ('\U0000aae0', '\U0000aaea', WC_ALetter),
+    ('\U0000aaeb', '\U0000aaef', WC_Extend),
+    ('\U0000aaf2', '\U0000aaf4', WC_ALetter),
+    ('\U0000aaf5', '\U0000aaf6', WC_Extend),
+    ('\U0000ab01', '\U0000ab06', WC_ALetter),
+    ('\U0000ab09', '\U0000ab0e', WC_ALetter),
+    ('\U0000ab11', '\U0000ab16', WC_ALetter),
+    ('\U0000ab20', '\U0000ab26', WC_ALetter),
+    ('\U0000ab28', '\U0000ab2e', WC_ALetter),
+    ('\U0000abc0', '\U0000abe2', WC_ALetter),
+    ('\U0000abe3', '\U0000abea', WC_Extend),
+    ('\U0000abec', '\U0000abed', WC_Extend),
+    ('\U0000abf0', '\U0000abf9', WC_Numeric),
+    ('\U0000ac00', '\U0000d7a3', WC_ALetter),
+    ('\U0000d7b0', '\U0000d7c6', WC_ALetter),
+    ('\U0000d7cb', '\U0000d7fb', WC_ALetter),
+    ('\U0000fb00', '\U0000fb06', WC_ALetter),
+    ('\U0000fb13', '\U0000fb17', WC_ALetter),
+    ('\U0000fb1d', '\U0000fb1d', WC_HebrewLetter),
+    ('\U0000fb1e', '\U0000fb1e', WC_Extend),
+    ('\U0000fb1f', '\U0000fb28', WC_HebrewLetter),
+    ('\U0000fb2a', '\U0000fb36', WC_HebrewLetter),
+    ('\U0000fb38', '\U0000fb3c', WC_HebrewLetter),
+    ('\U0000fb3e', '\U0000fb3e', WC_HebrewLetter),
+    ('\U0000fb40', '\U0000fb41', WC_HebrewLetter),
+    ('\U0000fb43', '\U0000fb44', WC_HebrewLetter),
+    ('\U0000fb46', '\U0000fb4f', WC_HebrewLetter),
+    ('\U0000fb50', '\U0000fbb1', WC_ALetter),
+    ('\U0000fbd3', '\U0000fd3d', WC_ALetter),
+    ('\U0000fd50', '\U0000fd8f', WC_ALetter),
+    ('\U0000fd92', '\U0000fdc7', WC_ALetter),
+    ('\U0000fdf0', '\U0000fdfb', WC_ALetter),
+    ('\U0000fe00', '\U0000fe0f', WC_Extend),
+    ('\U0000fe13', '\U0000fe13', WC_MidLetter),
+    ('\U0000fe20', '\U0000fe26', WC_Extend),
+    ('\U0000fe33', '\U0000fe34', WC_ExtendNumLet),
+    ('\U0000fe4d', '\U0000fe4f', WC_ExtendNumLet),
+    ('\U0000fe50', '\U0000fe50', WC_MidNum),
+    ('\U0000fe52', '\U0000fe52', WC_MidNumLet),
+    ('\U0000fe54', '\U0000fe54', WC_MidNum),
+    ('\U0000fe55', '\U0000fe55', WC_MidLetter),
+    ('\U0000fe70', '\U0000fe74', WC_ALetter),
+    ('\U0000fe76', '\U0000fefc', WC_ALetter),
+    ('\U0000feff', '\U0000feff', WC_Format),
+    ('\U0000ff07', '\U0000ff07', WC_MidNumLet),
+    ('\U0000ff0c', '\U0000ff0c', WC_MidNum),
+    ('\U0000ff0e', '\U0000ff0e', WC_MidNumLet),
+    ('\U0000ff10', '\U0000ff19', WC_Numeric),
+    ('\U0000ff1a', '\U0000ff1a', WC_MidLetter),
+    ('\U0000ff1b', '\U0000ff1b', WC_MidNum),
+    ('\U0000ff21', '\U0000ff3a', WC_ALetter),
+    ('\U0000ff3f', '\U0000ff3f', WC_ExtendNumLet),
+    ('\U0000ff41', '\U0000ff5a', WC_ALetter),
+    ('\U0000ff66', '\U0000ff9d', WC_Katakana),
+    ('\U0000ff9e', '\U0000ff9f', WC_Extend),
+    ('\U0000ffa0', '\U0000ffbe', WC_ALetter),
+    ('\U0000ffc2', '\U0000ffc7', WC_ALetter),
+    ('\U0000ffca', '\U0000ffcf', WC_ALetter),
+    ('\U0000ffd2', '\U0000ffd7', WC_ALetter),
+    ('\U0000ffda', '\U0000ffdc', WC_ALetter),
+    ('\U0000fff9', '\U0000fffb', WC_Format),
+    ('\U00010000', '\U0001000b', WC_ALetter),
+    ('\U0001000d', '\U00010026', WC_ALetter),
+    ('\U00010028', '\U0001003a', WC_ALetter),
+    ('\U0001003c', '\U0001003d', WC_ALetter),
+    ('\U0001003f', '\U0001004d', WC_ALetter),
+    ('\U00010050', '\U0001005d', WC_ALetter),
+    ('\U00010080', '\U000100fa', WC_ALetter),
+    ('\U00010140', '\U00010174', WC_ALetter),
+    ('\U000101fd', '\U000101fd', WC_Extend),
+    ('\U00010280', '\U0001029c', WC_ALetter),
+    ('\U000102a0', '\U000102d0', WC_ALetter),
+    ('\U00010300', '\U0001031e', WC_ALetter),
+    ('\U00010330', '\U0001034a', WC_ALetter),
+    ('\U00010380', '\U0001039d', WC_ALetter),
+    ('\U000103a0', '\U000103c3', WC_ALetter),
+    ('\U000103c8', '\U000103cf', WC_ALetter),
+    ('\U000103d1', '\U000103d5', WC_ALetter),
+    ('\U00010400', '\U0001049d', WC_ALetter),
+    ('\U000104a0', '\U000104a9', WC_Numeric),
+    ('\U00010800', '\U00010805', WC_ALetter),
+    ('\U00010808', '\U00010808', WC_ALetter),
+    ('\U0001080a', '\U00010835', WC_ALetter),
+    ('\U00010837', '\U00010838', WC_ALetter),
+    ('\U0001083c', '\U0001083c', WC_ALetter),
+    ('\U0001083f', '\U00010855', WC_ALetter),
+    ('\U00010900', '\U00010915', WC_ALetter),
+    ('\U00010920', '\U00010939', WC_ALetter),
+    ('\U00010980', '\U000109b7', WC_ALetter),
+    ('\U000109be', '\U000109bf', WC_ALetter),
+    ('\U00010a00', '\U00010a00', WC_ALetter),
+    ('\U00010a01', '\U00010a03', WC_Extend),
+    ('\U00010a05', '\U00010a06', WC_Extend),
+    ('\U00010a0c', '\U00010a0f', WC_Extend),
+    ('\U00010a10', '\U00010a13', WC_ALetter),
+    ('\U00010a15', '\U00010a17', WC_ALetter),
+    ('\U00010a19', '\U00010a33', WC_ALetter),
+    ('\U00010a38', '\U00010a3a', WC_Extend),
+    ('\U00010a3f', '\U00010a3f', WC_Extend),
+    ('\U00010a60', '\U00010a7c', WC_ALetter),
+    ('\U00010b00', '\U00010b35', WC_ALetter),
+    ('\U00010b40', '\U00010b55', WC_ALetter),
+    ('\U00010b60', '\U00010b72', WC_ALetter),
+    ('\U00010c00', '\U00010c48', WC_ALetter),
+    ('\U00011000', '\U00011002', WC_Extend),
+    ('\U00011003', '\U00011037', WC_ALetter),
+    ('\U00011038', '\U00011046', WC_Extend),
+    ('\U00011066', '\U0001106f', WC_Numeric),
+    ('\U00011080', '\U00011082', WC_Extend),
+    ('\U00011083', '\U000110af', WC_ALetter),
+    ('\U000110b0', '\U000110ba', WC_Extend),
+    ('\U000110bd', '\U000110bd', WC_Numeric),
+    ('\U000110d0', '\U000110e8', WC_ALetter),
+    ('\U000110f0', '\U000110f9', WC_Numeric),
+    ('\U00011100', '\U00011102', WC_Extend),
+    ('\U00011103', '\U00011126', WC_ALetter),
+    ('\U00011127', '\U00011134', WC_Extend),
+    ('\U00011136', '\U0001113f', WC_Numeric),
+    ('\U00011180', '\U00011182', WC_Extend),
+    ('\U00011183', '\U000111b2', WC_ALetter),
+    ('\U000111b3', '\U000111c0', WC_Extend),
+    ('\U000111c1', '\U000111c4', WC_ALetter),
+    ('\U000111d0', '\U000111d9', WC_Numeric),
+    ('\U00011680', '\U000116aa', WC_ALetter),
+    ('\U000116ab', '\U000116b7', WC_Extend),
+    ('\U000116c0', '\U000116c9', WC_Numeric),
+    ('\U00012000', '\U0001236e', WC_ALetter),
+    ('\U00012400', '\U00012462', WC_ALetter),
+    ('\U00013000', '\U0001342e', WC_ALetter),
+    ('\U00016800', '\U00016a38', WC_ALetter),
+    ('\U00016f00', '\U00016f44', WC_ALetter),
+    ('\U00016f50', '\U00016f50', WC_ALetter),
+    ('\U00016f51', '\U00016f7e', WC_Extend),
+    ('\U00016f8f', '\U00016f92', WC_Extend),
+    ('\U00016f93', '\U00016f9f', WC_ALetter),
+    ('\U0001b000', '\U0001b000', WC_Katakana),
+    ('\U0001d165', '\U0001d169', WC_Extend),
+    ('\U0001d16d', '\U0001d172', WC_Extend),
+    ('\U0001d173', '\U0001d17a', WC_Format),
+    ('\U0001d17b', '\U0001d182', WC_Extend),
+    ('\U0001d185', '\U0001d18b', WC_Extend),
+    ('\U0001d1aa', '\U0001d1ad', WC_Extend),
+    ('\U0001d242', '\U0001d244', WC_Extend),
+    ('\U0001d400', '\U0001d454', WC_ALetter),
+    ('\U0001d456', '\U0001d49c', WC_ALetter),
+    ('\U0001d49e', '\U0001d49f', WC_ALetter),
+    ('\U0001d4a2', '\U0001d4a2', WC_ALetter),
+    ('\U0001d4a5', '\U0001d4a6', WC_ALetter),
+    ('\U0001d4a9', '\U0001d4ac', WC_ALetter),
+    ('\U0001d4ae', '\U0001d4b9', WC_ALetter),
+    ('\U0001d4bb', '\U0001d4bb', WC_ALetter),
+    ('\U0001d4bd', '\U0001d4c3', WC_ALetter),
+    ('\U0001d4c5', '\U0001d505', WC_ALetter),
+    ('\U0001d507', '\U0001d50a', WC_ALetter),
+    ('\U0001d50d', '\U0001d514', WC_ALetter),
+    ('\U0001d516', '\U0001d51c', WC_ALetter),
+    ('\U0001d51e', '\U0001d539', WC_ALetter),
+    ('\U0001d53b', '\U0001d53e', WC_ALetter),
+    ('\U0001d540', '\U0001d544', WC_ALetter),
+    ('\U0001d546', '\U0001d546', WC_ALetter),
+    ('\U0001d54a', '\U0001d550', WC_ALetter),
+    ('\U0001d552', '\U0001d6a5', WC_ALetter),
+    ('\U0001d6a8', '\U0001d6c0', WC_ALetter),
+    ('\U0001d6c2', '\U0001d6da', WC_ALetter),
+    ('\U0001d6dc', '\U0001d6fa', WC_ALetter),
+    ('\U0001d6fc', '\U0001d714', WC_ALetter),
+    ('\U0001d716', '\U0001d734', WC_ALetter),
+    ('\U0001d736', '\U0001d74e', WC_ALetter),
+    ('\U0001d750', '\U0001d76e', WC_ALetter),
+    ('\U0001d770', '\U0001d788', WC_ALetter),
+    ('\U0001d78a', '\U0001d7a8', WC_ALetter),
+    ('\U0001d7aa', '\U0001d7c2', WC_ALetter),
+    ('\U0001d7c4', '\U0001d7cb', WC_ALetter),
+    ('\U0001d7ce', '\U0001d7ff', WC_Numeric),
+    ('\U0001ee00', '\U0001ee03', WC_ALetter),
+    ('\U0001ee05', '\U0001ee1f', WC_ALetter),
+    ('\U0001ee21', '\U0001ee22', WC_ALetter),
+    ('\U0001ee24', '\U0001ee24', WC_ALetter),
+    ('\U0001ee27', '\U0001ee27', WC_ALetter),
+    ('\U0001ee29', '\U0001ee32', WC_ALetter),
+    ('\U0001ee34', '\U0001ee37', WC_ALetter),
+    ('\U0001ee39', '\U0001ee39', WC_ALetter),
+    ('\U0001ee3b', '\U0001ee3b', WC_ALetter),
+    ('\U0001ee42', '\U0001ee42', WC_ALetter),
+    ('\U0001ee47', '\U0001ee47', WC_ALetter),
+    ('\U0001ee49', '\U0001ee49', WC_ALetter),
+    ('\U0001ee4b', '\U0001ee4b', WC_ALetter),
+    ('\U0001ee4d', '\U0001ee4f', WC_ALetter),
+    ('\U0001ee51', '\U0001ee52', WC_ALetter),
+    ('\U0001ee54', '\U0001ee54', WC_ALetter),
+    ('\U0001ee57', '\U0001ee57', WC_ALetter),
+    ('\U0001ee59', '\U0001ee59', WC_ALetter),
+    ('\U0001ee5b', '\U0001ee5b', WC_ALetter),
+    ('\U0001ee5d', '\U0001ee5d', WC_ALetter),
+    ('\U0001ee5f', '\U0001ee5f', WC_ALetter),
+    ('\U0001ee61', '\U0001ee62', WC_ALetter),
+    ('\U0001ee64', '\U0001ee64', WC_ALetter),
+    ('\U0001ee67', '\U0001ee6a', WC_ALetter),
+    ('\U0001ee6c', '\U0001ee72', WC_ALetter),
+    ('\U0001ee74', '\U0001ee77', WC_ALetter),
+    ('\U0001ee79', '\U0001ee7c', WC_ALetter),
+    ('\U0001ee7e', '\U0001ee7e', WC_ALetter),
+    ('\U0001ee80', '\U0001ee89', WC_ALetter),
+    ('\U0001ee8b', '\U0001ee9b', WC_ALetter),
+    ('\U0001eea1', '\U0001eea3', WC_ALetter),
+    ('\U0001eea5', '\U0001eea9', WC_ALetter),
+    ('\U0001eeab', '\U0001eebb', WC_ALetter),
+    ('\U0001f130', '\U0001f149', WC_ALetter),
+    ('\U0001f150', '\U0001f169', WC_ALetter),
+    ('\U0001f170', '\U0001f189', WC_ALetter),
+    ('\U0001f1e6', '\U0001f1ff', WC_RegionalIndicator),
+    ('\U000e0001', '\U000e0001', WC_Format),
+    ('\U000e0020', '\U000e007f', WC_Extend),
+    ('\U000e0100', '\U000e01ef', WC_Extend)
+];
+
@@ -34,15 +34,30 @@
 // [1] - http://swtch.com/~rsc/regexp/regexp3.html
 
 use std::cmp;
+use std::iter;
 use std::mem;
 use std::slice::MutableVector;
 use compile::{
     Program,
-    Match, OneChar, CharClass, Any, EmptyBegin, EmptyEnd, EmptyWordBoundary,
-    Save, Jump, Split,
+    Match, OneChar, Literal, CharClass, Any, Branch, EmptyBegin, EmptyEnd,
+    EmptyWordBoundary, EmptyGraphemeBoundary, Save, Jump, Split,
+};
+use parse::{
+    FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED, FLAG_UNICODE_WORD,
 };
-use parse::{FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED};
 use parse::unicode::PERLW;
+use parse::unicode::{
+    grapheme_category,
+    GC_CR, GC_LF, GC_Control, GC_Extend, GC_RegionalIndicator, GC_Prepend,
+    GC_SpacingMark, GC_L, GC_V, GC_T, GC_LV, GC_LVT,
+};
+use parse::unicode::{
+    WordCat, word_category,
+    WC_CR, WC_LF, WC_Newline, WC_Extend, WC_RegionalIndicator, WC_Format,
+    WC_Katakana, WC_HebrewLetter, WC_ALetter, WC_SingleQuote, WC_DoubleQuote,
+    WC_MidNumLet, WC_MidLetter, WC_MidNum, WC_Numeric, WC_ExtendNumLet,
+};
+use search::Searcher;
 
 pub type CaptureLocs = Vec<Option<uint>>;
 
@@ -50,6 +65,10 @@ pub type CaptureLocs = Vec<Option<uint>>;
 pub enum MatchKind {
     /// Only checks if a match exists or not. Does not return location.
     Exists,
+    /// Returns the start and end indices of the match that ends first. The
+    /// search stops as soon as any match is found, so it may be shorter
+    /// than (or start after) the match that `Location` returns.
+    Shortest,
     /// Returns the start and end indices of the entire match in the input
     /// given.
     Location,
@@ -57,47 +76,167 @@ pub enum MatchKind {
     Submatches,
 }
 
+/// Indicates where a match must be found in the range searched by the VM.
+pub enum Anchor {
+    /// A match can be anywhere in the range. (This is the same as having an
+    /// implicit `.*?` at the beginning and end of the regexp.)
+    Unanchored,
+    /// A match must start at the start of the range.
+    AnchorStart,
+    /// A match must start at the start of the range and end at its end.
+    AnchorBoth,
+}
+
 /// Runs an NFA simulation on the compiled expression given on the search text
 /// `input`. The search begins at byte index `start` and ends at byte index
 /// `end`. (The range is specified here so that zero-width assertions will work
 /// correctly when searching for successive non-overlapping matches.)
 ///
 /// The `which` parameter indicates what kind of capture information the caller
-/// wants. There are three choices: match existence only, the location of the
-/// entire match or the locations of the entire match in addition to the
-/// locations of each submatch.
-pub fn run<'r, 't>(which: MatchKind, prog: &'r Program, input: &'t str,
-                   start: uint, end: uint) -> CaptureLocs {
+/// wants. There are four choices: match existence only, the location of the
+/// first match to end, the location of the entire match or the locations of
+/// the entire match in addition to the locations of each submatch.
+///
+/// The `anchor` parameter indicates whether the match must start at `start`
+/// (and end at `end`), without having to add anchors to the regexp.
+pub fn run<'r, 't>(which: MatchKind, anchor: Anchor, prog: &'r Program,
+                   input: &'t str, start: uint, end: uint) -> CaptureLocs {
+    let mut scratch = Scratch::for_kind(prog, which);
+    run_with(which, anchor, prog, &mut scratch, input, start, end)
+}
+
+/// Like `run`, but uses the memory in `scratch` instead of allocating it.
+/// `scratch` must have been created for `prog`.
+pub fn run_with<'r, 't>(which: MatchKind, anchor: Anchor, prog: &'r Program,
+                        scratch: &mut Scratch, input: &'t str,
+                        start: uint, end: uint) -> CaptureLocs {
+    let mut locs = Vec::from_elem(num_locs(which, prog), None);
+    run_into(which, anchor, prog, scratch, locs.as_mut_slice(),
+             input, start, end);
+    locs
+}
+
+/// Like `run_with`, but writes the capture locations into `locs` instead of
+/// returning them. Its length must be `num_locs(which, prog)`.
+pub fn run_into<'r, 't>(which: MatchKind, anchor: Anchor, prog: &'r Program,
+                        scratch: &mut Scratch, locs: &mut [Option<uint>],
+                        input: &'t str, start: uint, end: uint) {
     Nfa {
         which: which,
+        anchor: anchor,
         prog: prog,
         input: input,
         start: start,
         end: end,
         ic: 0,
         chars: CharReader::new(input),
-    }.run()
+        rev: false,
+    }.run(scratch, locs)
+}
+
+/// Runs an NFA simulation of a reversed program (see `Program::reversed`).
+/// The search scans `input` from right to left, beginning at byte index `end`
+/// and ending at byte index `start`.
+///
+/// The match found is the first one found while scanning, i.e., the match
+/// that ends last in `input`.
+pub fn run_rev<'r, 't>(which: MatchKind, prog: &'r Program,
+                       scratch: &mut Scratch, input: &'t str,
+                       start: uint, end: uint) -> CaptureLocs {
+    let mut locs = Vec::from_elem(num_locs(which, prog), None);
+    Nfa {
+        which: which,
+        anchor: Unanchored,
+        prog: prog,
+        input: input,
+        start: start,
+        end: end,
+        ic: 0,
+        chars: CharReader::new_rev(input),
+        rev: true,
+    }.run_rev(scratch, locs.as_mut_slice(), false);
+    locs
+}
+
+/// Returns the number of capture locations that a search of `prog` reports
+/// for `which`. There are two for every capture group that it reports, and
+/// `Exists` reports the zeroth group (as `(0, 0)`) if there's a match.
+pub fn num_locs(which: MatchKind, prog: &Program) -> uint {
+    match which {
+        Exists | Shortest | Location => 2,
+        Submatches => prog.num_captures() * 2,
+    }
+}
+
+/// The memory that the VM needs to run a particular program. It can be
+/// reused for any number of searches with that program.
+///
+/// The room for capture groups grows the first time that a search needs
+/// more of it.
+pub struct Scratch {
+    clist: Threads,
+    nlist: Threads,
+    // The memory for running the reversed program, if it's used to search
+    // for matches that end with `$` (see `Program::new`). It's allocated by
+    // the first search that runs it.
+    rev: Option<~Scratch>,
+}
+
+impl Scratch {
+    /// Allocates the memory needed to run `prog`, without any room for
+    /// capture groups.
+    pub fn new(prog: &Program) -> Scratch {
+        Scratch::with_slots(prog, 0)
+    }
+
+    /// Allocates the memory needed to search `prog` for `which`.
+    pub fn for_kind(prog: &Program, which: MatchKind) -> Scratch {
+        Scratch::with_slots(prog, num_slots(which, prog))
+    }
+
+    fn with_slots(prog: &Program, nslots: uint) -> Scratch {
+        let ninsts = prog.insts.len();
+        let nthreads = prog.num_threads();
+        Scratch {
+            clist: Threads::new(ninsts, nthreads, nslots),
+            nlist: Threads::new(ninsts, nthreads, nslots),
+            rev: None,
+        }
+    }
+}
+
+// Returns the number of capture slots that each thread needs in a search
+// of `prog` for `which`.
+fn num_slots(which: MatchKind, prog: &Program) -> uint {
+    match which {
+        Exists => 0,
+        Shortest | Location => 2,
+        Submatches => prog.num_captures() * 2,
+    }
 }
 
 struct Nfa<'r, 't> {
     which: MatchKind,
+    anchor: Anchor,
     prog: &'r Program,
     input: &'t str,
     start: uint,
     end: uint,
     ic: uint,
     chars: CharReader<'t>,
+    rev: bool,
 }
 
 /// Indicates the next action to take after a single non-empty instruction
 /// is processed.
 pub enum StepState {
     /// This is returned if and only if a Match instruction is reached and
-    /// we only care about the existence of a match. It instructs the VM to
-    /// quit early.
+    /// we only care about the existence of a match (or where the first one
+    /// ends). It instructs the VM to quit early.
     StepMatchEarlyReturn,
     /// Indicates that a match was found. Thus, the rest of the states in the
-    /// *current* queue should be dropped (i.e., leftmost-first semantics).
+    /// *current* queue should be dropped (i.e., leftmost-first semantics),
+    /// unless the program looks for the longest match.
     /// States in the "next" queue can still be processed.
     StepMatch,
     /// No match was found. Continue with the next state in the queue.
@@ -105,24 +244,105 @@ pub enum StepState {
 }
 
 impl<'r, 't> Nfa<'r, 't> {
-    fn run(&mut self) -> CaptureLocs {
-        let ncaps = match self.which {
-            Exists => 0,
-            Location => 1,
-            Submatches => self.prog.num_captures(),
+    fn run(&mut self, scratch: &mut Scratch, groups: &mut [Option<uint>]) {
+        for loc in groups.mut_iter() {
+            *loc = None
+        }
+
+        // A search that starts after the end of the input can't match.
+        if self.start > self.end {
+            return
+        }
+
+        // Don't bother running at all if the input doesn't contain the
+        // strings that every match must contain.
+        if !may_match(self.prog.suffixes.as_slice(),
+                      &self.prog.suffix_searcher,
+                      self.prog.required.as_slice(),
+                      &self.prog.required_searcher,
+                      self.input, self.start) {
+            return
+        }
+
+        match self.anchor {
+            Unanchored => {}
+            AnchorStart | AnchorBoth => {
+                return self.run_forward(scratch, groups, true)
+            }
+        }
+
+        // Determine if the expression ends with a '$' so we can avoid
+        // simulating .*? over the whole input: every match ends at the end
+        // of the input, so we can find where the first one starts by
+        // running the reversed program backwards from there.
+        if self.prog.reversed.is_some() {
+            return self.run_suffix(scratch, groups)
+        }
+        self.run_forward(scratch, groups, false)
+    }
+
+    // Finds the first match of a program that ends with `$` (see
+    // `Program::new`) with the reversed program. The forward program is
+    // only run (from the start of the match) if submatches are needed.
+    fn run_suffix(&mut self, scratch: &mut Scratch,
+                  groups: &mut [Option<uint>]) {
+        if self.end < self.input.len() {
+            return
+        }
+        let mut locs = [None, None];
+        let which = match self.which {
+            Exists => Exists,
+            Shortest | Location | Submatches => Location,
         };
-        let mut matched = false;
-        let ninsts = self.prog.insts.len();
-        let mut clist = &mut Threads::new(self.which, ninsts, ncaps);
-        let mut nlist = &mut Threads::new(self.which, ninsts, ncaps);
+        let prog = self.prog;
+        let rev = &**prog.reversed.get_ref();
+        if scratch.rev.is_none() {
+            scratch.rev = Some(~Scratch::for_kind(rev, which));
+        }
+        Nfa {
+            which: which,
+            anchor: Unanchored,
+            prog: rev,
+            input: self.input,
+            start: self.start,
+            end: self.end,
+            ic: 0,
+            chars: CharReader::new_rev(self.input),
+            rev: true,
+        }.run_rev(&mut **scratch.rev.get_mut_ref(), &mut locs, true);
+        match (self.which, locs[0]) {
+            (_, None) => {}
+            (Exists, Some(_)) => self.found(groups),
+            (Shortest, Some(s)) | (Location, Some(s)) => {
+                groups[0] = Some(s);
+                groups[1] = Some(self.input.len());
+            }
+            (Submatches, Some(s)) => {
+                self.start = s;
+                self.run_forward(scratch, groups, true)
+            }
+        }
+    }
 
-        let mut groups = Vec::from_elem(ncaps * 2, None);
+    // Simulates the program from the start of the range to the end of it,
+    // writing the locations of the match found into `groups` (which must be
+    // all `None` to start with).
+    // If `anchored` is true, then a match must start at the start of the
+    // range.
+    fn run_forward(&mut self, scratch: &mut Scratch,
+                   groups: &mut [Option<uint>], anchored: bool) {
+        let mut matched = false;
+        let nslots = num_slots(self.which, self.prog);
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(self.which, nslots);
+        nlist.reset(self.which, nslots);
 
         // Determine if the expression starts with a '^' so we can avoid
         // simulating .*?
         // Make sure multi-line mode isn't enabled for it, otherwise we can't
         // drop the initial .*?
-        let prefix_anchor =
+        let prefix_anchor = anchored ||
             match *self.prog.insts.get(1) {
                 EmptyBegin(flags) if flags & FLAG_MULTI == 0 => true,
                 _ => false,
@@ -138,18 +358,24 @@ impl<'r, 't> Nfa<'r, 't> {
                     break
                 }
 
+                // An anchored search is over once the threads that began at
+                // the start of the range are gone.
+                if anchored && self.ic > self.start {
+                    break
+                }
+
                 // If there are no threads to try, then we'll have to start
                 // over at the beginning of the regex.
-                // BUT, if there's a literal prefix for the program, try to
-                // jump ahead quickly. If it can't be found, then we can bail
-                // out early.
-                if self.prog.prefix.len() > 0 && clist.size == 0 {
-                    let needle = self.prog.prefix.as_slice().as_bytes();
-                    let haystack = self.input.as_bytes().slice_from(self.ic);
-                    match find_prefix(needle, haystack) {
+                // BUT, if every match starts with one of a set of literal
+                // strings, try to jump ahead quickly to where one of them
+                // occurs. If none can be found, then we can bail out early.
+                if self.prog.prefixes.len() > 0 && !anchored {
+                    let prefixes = self.prog.prefixes.as_slice();
+                    match find_any(prefixes, &self.prog.prefix_searcher,
+                                   self.input, self.ic) {
                         None => break,
                         Some(i) => {
-                            self.ic += i;
+                            self.ic = i;
                             next_ic = self.chars.set(self.ic);
                         }
                     }
@@ -160,7 +386,7 @@ impl<'r, 't> Nfa<'r, 't> {
             // a state starting at the current position in the input for the
             // beginning of the program only if we don't already have a match.
             if clist.size == 0 || (!prefix_anchor && !matched) {
-                self.add(clist, 0, groups.as_mut_slice())
+                self.add(clist, 0, groups)
             }
 
             // Now we try to read the next character.
@@ -169,30 +395,138 @@ impl<'r, 't> Nfa<'r, 't> {
             self.ic = next_ic;
             next_ic = self.chars.advance();
 
-            let mut i = 0;
-            while i < clist.size {
-                let pc = clist.pc(i);
-                let step_state = self.step(groups.as_mut_slice(), nlist,
-                                           clist.groups(i), pc);
-                match step_state {
-                    StepMatchEarlyReturn => return vec![Some(0), Some(0)],
-                    StepMatch => { matched = true; clist.empty() },
-                    StepContinue => {},
-                }
-                i += 1;
+            match self.step_threads(groups, clist, nlist, self.prog.longest) {
+                StepMatchEarlyReturn => return self.found(groups),
+                StepMatch => matched = true,
+                StepContinue => {},
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.empty();
+        }
+        if matched {
+            self.found(groups)
+        }
+    }
+
+    // This is the same as `run_forward`, except that the input is read from
+    // right to left. There are no literals to skip ahead with.
+    //
+    // If `anchored` is true, then a match must start at the end of the range
+    // (since it's read backwards), and the longest one is found instead of
+    // the first one.
+    fn run_rev(&mut self, scratch: &mut Scratch,
+               groups: &mut [Option<uint>], anchored: bool) {
+        let mut matched = false;
+        let nslots = num_slots(self.which, self.prog);
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(self.which, nslots);
+        nlist.reset(self.which, nslots);
+
+        self.ic = self.end;
+        let mut next_ic = self.chars.set(self.end);
+        loop {
+            let first = self.ic == self.end;
+            if clist.size == 0 && (matched || (anchored && !first)) {
+                break
+            }
+            if (clist.size == 0 || !matched) && (!anchored || first) {
+                self.add(clist, 0, groups)
+            }
+
+            // Threads still have to be stepped at the start of the range
+            // (so that they can match), but it's the last time.
+            let done = self.ic == self.start;
+            self.ic = next_ic;
+            next_ic = self.chars.advance();
+
+            match self.step_threads(groups, clist, nlist, anchored) {
+                StepMatchEarlyReturn => return self.found(groups),
+                StepMatch => matched = true,
+                StepContinue => {},
             }
             mem::swap(&mut clist, &mut nlist);
             nlist.empty();
+            if done {
+                break
+            }
+        }
+        if matched {
+            self.found(groups)
+        }
+    }
+
+    // Steps every thread in `clist` over the character that was just read,
+    // adding the threads that survive to `nlist`.
+    // If a thread matches, then the lower priority threads after it in
+    // `clist` are dropped (unless `longest` is true, since they might still
+    // find a longer match) and `StepMatch` is returned.
+    //
+    // When `longest` is true, every match found here ends at the same
+    // position, so only the first (highest priority) one is kept. It
+    // replaces the match found by an earlier step, which must be shorter.
+    #[inline(always)]
+    fn step_threads(&self, groups: &mut [Option<uint>], clist: &mut Threads,
+                    nlist: &mut Threads, longest: bool) -> StepState {
+        let mut state = StepContinue;
+        let mut matched = false;
+        let mut i = 0;
+        while i < clist.size {
+            let (pc, off) = (clist.pc(i), clist.off(i));
+            if longest
+               && self.outmatched(groups, clist.groups(i), pc, matched) {
+                i += 1;
+                continue
+            }
+            match self.step(groups, nlist, clist.groups(i), pc, off) {
+                StepMatchEarlyReturn => return StepMatchEarlyReturn,
+                StepMatch => {
+                    state = StepMatch;
+                    matched = true;
+                    if !longest {
+                        clist.empty()
+                    }
+                }
+                StepContinue => {},
+            }
+            i += 1;
         }
+        state
+    }
+
+    // Returns true if a thread at `pc` with the capture groups `caps` can't
+    // be part of the leftmost-longest match: either it's another match at
+    // a position where one was already found in this step, or the match in
+    // `groups` starts before the thread did (and so is further left).
+    // Reversed programs only look for the longest match from a single start
+    // position, so the latter doesn't apply to them.
+    fn outmatched(&self, groups: &[Option<uint>], caps: &[Option<uint>],
+                  pc: uint, matched: bool) -> bool {
+        match *self.prog.insts.get(pc) {
+            Match if matched => return true,
+            _ => {}
+        }
+        !self.rev && groups.len() > 0 && groups[0].is_some()
+        && caps[0] > groups[0]
+    }
+
+    // Records that a match was found in a search that doesn't keep where it
+    // is. (Every other search has already written its locations.)
+    fn found(&self, groups: &mut [Option<uint>]) {
         match self.which {
-            Exists if matched     => vec![Some(0), Some(0)],
-            Exists                => vec![None, None],
-            Location | Submatches => groups,
+            Exists => {
+                groups[0] = Some(0);
+                groups[1] = Some(0);
+            }
+            Shortest | Location | Submatches => {}
         }
     }
 
+    // Steps the thread at `pc` over the character that was just read.
+    // `off` is the byte offset of the next character to match if the thread
+    // is in the middle of a `Literal` instruction (and 0 otherwise).
     fn step(&self, groups: &mut [Option<uint>], nlist: &mut Threads,
-            caps: &mut [Option<uint>], pc: uint)
+            caps: &mut [Option<uint>], pc: uint, off: uint)
            -> StepState {
         match *self.prog.insts.get(pc) {
             Match => {
@@ -200,6 +534,11 @@ impl<'r, 't> Nfa<'r, 't> {
                     Exists => {
                         return StepMatchEarlyReturn
                     }
+                    Shortest => {
+                        groups[0] = caps[0];
+                        groups[1] = caps[1];
+                        return StepMatchEarlyReturn
+                    }
                     Location => {
                         groups[0] = caps[0];
                         groups[1] = caps[1];
@@ -216,6 +555,17 @@ impl<'r, 't> Nfa<'r, 't> {
                     self.add(nlist, pc+1, caps);
                 }
             }
+            Literal(ref lit, flags) => {
+                let next = lit.char_range_at(off);
+                let casei = flags & FLAG_NOCASE > 0;
+                if self.char_eq(casei, self.chars.prev, next.ch) {
+                    if next.next == lit.len() {
+                        self.add(nlist, pc+1, caps);
+                    } else {
+                        nlist.add_literal(pc, next.next, caps);
+                    }
+                }
+            }
             CharClass(ref ranges, flags) => {
                 if self.chars.prev.is_some() {
                     let c = self.chars.prev.unwrap();
@@ -235,8 +585,21 @@ impl<'r, 't> Nfa<'r, 't> {
                     self.add(nlist, pc+1, caps)
                 }
             }
+            Branch(ref targets, flags) => {
+                if self.chars.prev.is_some() {
+                    let mut c = self.chars.prev.unwrap();
+                    if flags & FLAG_NOCASE > 0 {
+                        c = c.to_uppercase();
+                    }
+                    let targets = targets.as_slice();
+                    match targets.bsearch(|&(tc, _)| tc.cmp(&c)) {
+                        Some(i) => self.add(nlist, targets[i].val1(), caps),
+                        None => {}
+                    }
+                }
+            }
             EmptyBegin(_) | EmptyEnd(_) | EmptyWordBoundary(_)
-            | Save(_) | Jump(_) | Split(_, _) => {},
+            | EmptyGraphemeBoundary(_) | Save(_) | Jump(_) | Split(_, _) => {},
         }
         StepContinue
     }
@@ -264,7 +627,7 @@ impl<'r, 't> Nfa<'r, 't> {
                 let multi = flags & FLAG_MULTI > 0;
                 nlist.add(pc, groups, true);
                 if self.chars.is_begin()
-                   || (multi && self.char_is(self.chars.prev, '\n')) {
+                   || (multi && self.char_is(self.chars.before(), '\n')) {
                     self.add(nlist, pc + 1, groups)
                 }
             }
@@ -272,20 +635,33 @@ impl<'r, 't> Nfa<'r, 't> {
                 let multi = flags & FLAG_MULTI > 0;
                 nlist.add(pc, groups, true);
                 if self.chars.is_end()
-                   || (multi && self.char_is(self.chars.cur, '\n')) {
+                   || (multi && self.char_is(self.chars.after(), '\n')) {
                     self.add(nlist, pc + 1, groups)
                 }
             }
             EmptyWordBoundary(flags) => {
                 nlist.add(pc, groups, true);
-                if self.chars.is_word_boundary() == !(flags & FLAG_NEGATED > 0) {
+                let yes =
+                    if flags & FLAG_UNICODE_WORD > 0 {
+                        self.chars.is_unicode_word_boundary()
+                    } else {
+                        self.chars.is_word_boundary()
+                    };
+                if yes == !(flags & FLAG_NEGATED > 0) {
+                    self.add(nlist, pc + 1, groups)
+                }
+            }
+            EmptyGraphemeBoundary(flags) => {
+                nlist.add(pc, groups, true);
+                let negated = flags & FLAG_NEGATED > 0;
+                if self.chars.is_grapheme_boundary() == !negated {
                     self.add(nlist, pc + 1, groups)
                 }
             }
             Save(slot) => {
                 nlist.add(pc, groups, true);
                 match self.which {
-                    Location if slot <= 1 => {
+                    Shortest | Location if slot <= 1 => {
                         let old = groups[slot];
                         groups[slot] = Some(self.ic);
                         self.add(nlist, pc + 1, groups);
@@ -297,7 +673,9 @@ impl<'r, 't> Nfa<'r, 't> {
                         self.add(nlist, pc + 1, groups);
                         groups[slot] = old;
                     }
-                    Exists | Location => self.add(nlist, pc + 1, groups),
+                    Exists | Shortest | Location => {
+                        self.add(nlist, pc + 1, groups)
+                    }
                 }
             }
             Jump(to) => {
@@ -309,7 +687,16 @@ impl<'r, 't> Nfa<'r, 't> {
                 self.add(nlist, x, groups);
                 self.add(nlist, y, groups);
             }
-            Match | OneChar(_, _) | CharClass(_, _) | Any(_) => {
+            Match => {
+                // A match that must end at the end of the range can't end
+                // anywhere else.
+                match self.anchor {
+                    AnchorBoth if self.ic != self.end => {}
+                    _ => nlist.add(pc, groups, false),
+                }
+            }
+            OneChar(_, _) | Literal(_, _) | CharClass(_, _) | Any(_)
+            | Branch(_, _) => {
                 nlist.add(pc, groups, false);
             }
         }
@@ -340,6 +727,10 @@ impl<'r, 't> Nfa<'r, 't> {
 /// CharReader is responsible for maintaining a "previous" and a "current"
 /// character. This one-character lookahead is necessary for assertions that
 /// look one character before or after the current position.
+///
+/// A reversed reader (see `new_rev`) reads the input from right to left, so
+/// its previous character is *after* the current position and its current
+/// character is *before* it.
 pub struct CharReader<'t> {
     /// The previous character read. It is None only when processing the first
     /// character of the input.
@@ -348,6 +739,7 @@ pub struct CharReader<'t> {
     pub cur: Option<char>,
     input: &'t str,
     next: uint,
+    rev: bool,
 }
 
 impl<'t> CharReader<'t> {
@@ -360,9 +752,17 @@ impl<'t> CharReader<'t> {
             cur: None,
             input: input,
             next: 0,
+            rev: false,
        }
     }
 
+    /// Returns a new CharReader that reads the input given backwards.
+    /// The byte indices returned by `set` and `advance` decrease, and `0` is
+    /// returned once there are no more characters to read.
+    pub fn new_rev(input: &'t str) -> CharReader<'t> {
+        CharReader { rev: true, ..CharReader::new(input) }
+    }
+
     /// Sets the previous and current character given any arbitrary byte
     /// index (at a unicode codepoint boundary).
     #[inline(always)]
@@ -371,6 +771,18 @@ impl<'t> CharReader<'t> {
         self.cur = None;
         self.next = 0;
 
+        if self.rev {
+            if ic < self.input.len() {
+                self.prev = Some(self.input.char_at(ic));
+            }
+            if ic > 0 {
+                let cur = self.input.char_range_at_reverse(ic);
+                self.cur = Some(cur.ch);
+                self.next = cur.next;
+            }
+            return self.next
+        }
+
         if self.input.len() == 0 {
             return 1
         }
@@ -394,6 +806,16 @@ impl<'t> CharReader<'t> {
     #[inline(always)]
     pub fn advance(&mut self) -> uint {
         self.prev = self.cur;
+        if self.rev {
+            if self.cur.is_some() && self.next > 0 {
+                let cur = self.input.char_range_at_reverse(self.next);
+                self.cur = Some(cur.ch);
+                self.next = cur.next;
+            } else {
+                self.cur = None;
+            }
+            return self.next
+        }
         if self.next < self.input.len() {
             let cur = self.input.char_range_at(self.next);
             self.cur = Some(cur.ch);
@@ -405,32 +827,58 @@ impl<'t> CharReader<'t> {
         self.next
     }
 
+    /// Returns the character just before the current position.
+    #[inline(always)]
+    pub fn before(&self) -> Option<char> {
+        if self.rev { self.cur } else { self.prev }
+    }
+
+    /// Returns the character just after the current position.
+    #[inline(always)]
+    pub fn after(&self) -> Option<char> {
+        if self.rev { self.prev } else { self.cur }
+    }
+
     /// Returns true if and only if this is the beginning of the input
     /// (ignoring the range of the input to search).
     #[inline(always)]
-    pub fn is_begin(&self) -> bool { self.prev.is_none() }
+    pub fn is_begin(&self) -> bool { self.before().is_none() }
 
     /// Returns true if and only if this is the end of the input
     /// (ignoring the range of the input to search).
     #[inline(always)]
-    pub fn is_end(&self) -> bool { self.cur.is_none() }
+    pub fn is_end(&self) -> bool { self.after().is_none() }
 
     /// Returns true if and only if the current position is a word boundary.
     /// (Ignoring the range of the input to search.)
     pub fn is_word_boundary(&self) -> bool {
-        if self.is_begin() {
-            return is_word(self.cur)
-        }
-        if self.is_end() {
-            return is_word(self.prev)
-        }
-        (is_word(self.cur) && !is_word(self.prev))
-        || (is_word(self.prev) && !is_word(self.cur))
+        is_word(self.before()) != is_word(self.after())
+    }
+
+    /// Returns true if and only if the current position is a word boundary
+    /// as defined by UAX#29. (Ignoring the range of the input to search.)
+    pub fn is_unicode_word_boundary(&self) -> bool {
+        let at = match (self.rev, self.cur) {
+            (false, None) => self.input.len(),
+            (false, Some(c)) => self.next - c.len_utf8_bytes(),
+            (true, None) => 0,
+            (true, Some(c)) => self.next + c.len_utf8_bytes(),
+        };
+        is_word_break(self.input, at)
+    }
+
+    /// Returns true if and only if the current position is an extended
+    /// grapheme cluster boundary. (Ignoring the range of the input to search.)
+    pub fn is_grapheme_boundary(&self) -> bool {
+        is_grapheme_boundary(self.before(), self.after())
     }
 }
 
 struct Thread {
     pc: uint,
+    // The byte offset of the next character to match in a `Literal`
+    // instruction, which is 0 for a thread that hasn't started it.
+    off: uint,
     groups: Vec<Option<uint>>,
 }
 
@@ -439,6 +887,8 @@ struct Threads {
     queue: Vec<Thread>,
     sparse: Vec<uint>,
     size: uint,
+    // The number of capture slots that each thread has room for.
+    nslots: uint,
 }
 
 impl Threads {
@@ -448,24 +898,48 @@ impl Threads {
     // throughout execution. That is, there should be zero allocation during
     // the execution of a VM.
     //
+    // The queue is also kept between searches (see `Scratch`), so its
+    // threads only get more room for capture groups when a search needs it
+    // (see `reset`).
+    //
+    // Only threads that haven't started a `Literal` instruction are in the
+    // sparse set, since the others come from a single thread in the list
+    // before, which makes them unique already. The queue has room for both
+    // (see `Program::num_threads`).
+    //
     // See http://research.swtch.com/sparse for the deets.
-    fn new(which: MatchKind, num_insts: uint, ncaps: uint) -> Threads {
+    fn new(num_insts: uint, num_threads: uint, nslots: uint) -> Threads {
         Threads {
-            which: which,
-            queue: Vec::from_fn(num_insts, |_| {
-                Thread { pc: 0, groups: Vec::from_elem(ncaps * 2, None) }
+            which: Exists,
+            queue: Vec::from_fn(num_threads, |_| {
+                Thread { pc: 0, off: 0, groups: Vec::from_elem(nslots, None) }
             }),
             sparse: Vec::from_elem(num_insts, 0u),
             size: 0,
+            nslots: nslots,
         }
     }
 
     fn add(&mut self, pc: uint, groups: &[Option<uint>], empty: bool) {
+        self.push(pc, 0, groups, empty);
+        *self.sparse.get_mut(pc) = self.size - 1;
+    }
+
+    // Adds a thread that continues the `Literal` instruction at `pc` with
+    // the character at the byte offset `off`.
+    fn add_literal(&mut self, pc: uint, off: uint, groups: &[Option<uint>]) {
+        self.push(pc, off, groups, false)
+    }
+
+    #[inline(always)]
+    fn push(&mut self, pc: uint, off: uint, groups: &[Option<uint>],
+            empty: bool) {
         let t = self.queue.get_mut(self.size);
         t.pc = pc;
+        t.off = off;
         match (empty, self.which) {
             (_, Exists) | (true, _) => {},
-            (false, Location) => {
+            (false, Shortest) | (false, Location) => {
                 *t.groups.get_mut(0) = groups[0];
                 *t.groups.get_mut(1) = groups[1];
             }
@@ -473,14 +947,16 @@ impl Threads {
                 t.groups.as_mut_slice().copy_memory(groups)
             }
         }
-        *self.sparse.get_mut(pc) = self.size;
         self.size += 1;
     }
 
     #[inline(always)]
     fn contains(&self, pc: uint) -> bool {
         let s = *self.sparse.get(pc);
-        s < self.size && self.queue.get(s).pc == pc
+        s < self.size && {
+            let t = self.queue.get(s);
+            t.pc == pc && t.off == 0
+        }
     }
 
     #[inline(always)]
@@ -488,11 +964,29 @@ impl Threads {
         self.size = 0;
     }
 
+    // Prepares the queue for a new search for `which`, in which each thread
+    // needs `nslots` capture slots.
+    fn reset(&mut self, which: MatchKind, nslots: uint) {
+        self.which = which;
+        self.size = 0;
+        if self.nslots < nslots {
+            for t in self.queue.mut_iter() {
+                t.groups.grow(nslots - self.nslots, &None)
+            }
+            self.nslots = nslots;
+        }
+    }
+
     #[inline(always)]
     fn pc(&self, i: uint) -> uint {
         self.queue.get(i).pc
     }
 
+    #[inline(always)]
+    fn off(&self, i: uint) -> uint {
+        self.queue.get(i).off
+    }
+
     #[inline(always)]
     fn groups<'r>(&'r mut self, i: uint) -> &'r mut [Option<uint>] {
         self.queue.get_mut(i).groups.as_mut_slice()
@@ -523,6 +1017,166 @@ pub fn is_word(c: Option<char>) -> bool {
     }
 }
 
+/// Returns true if there is an extended grapheme cluster boundary between
+/// `prev` and `cur`, where `None` indicates the beginning or end of the text.
+///
+/// This implements the rules from UAX#29 (Unicode 6.3), all of which only
+/// need to look at the two characters on either side of a position.
+/// See: http://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+pub fn is_grapheme_boundary(prev: Option<char>, cur: Option<char>) -> bool {
+    let (prev, cur) = match (prev, cur) {
+        (None, _) | (_, None) => return true, // GB1, GB2
+        (Some(p), Some(c)) => (grapheme_category(p), grapheme_category(c)),
+    };
+    match (prev, cur) {
+        (GC_CR, GC_LF) => false, // GB3
+        (GC_Control, _) | (GC_CR, _) | (GC_LF, _) => true, // GB4
+        (_, GC_Control) | (_, GC_CR) | (_, GC_LF) => true, // GB5
+        (GC_L, GC_L) | (GC_L, GC_V)
+        | (GC_L, GC_LV) | (GC_L, GC_LVT) => false, // GB6
+        (GC_LV, GC_V) | (GC_LV, GC_T)
+        | (GC_V, GC_V) | (GC_V, GC_T) => false, // GB7
+        (GC_LVT, GC_T) | (GC_T, GC_T) => false, // GB8
+        (GC_RegionalIndicator, GC_RegionalIndicator) => false, // GB8a
+        (_, GC_Extend) | (_, GC_SpacingMark) => false, // GB9, GB9a
+        (GC_Prepend, _) => false, // GB9b
+        _ => true, // GB10
+    }
+}
+
+/// Returns true if there is a word boundary at the byte index `at` in
+/// `input`, according to the default word boundary rules from UAX#29
+/// (Unicode 6.3).
+///
+/// Unlike grapheme cluster boundaries, some of these rules need to look at
+/// more than one character on either side of `at`, and Extend and Format
+/// characters are skipped over entirely (WB4), so the whole input is needed.
+/// See: http://www.unicode.org/reports/tr29/#Word_Boundary_Rules
+pub fn is_word_break(input: &str, at: uint) -> bool {
+    if input.len() == 0 {
+        return false // the empty text has no boundaries
+    }
+    if at == 0 || at >= input.len() {
+        return true // WB1, WB2
+    }
+    let r = input.char_range_at(at);
+    let cur = word_category(r.ch);
+    match (word_category(input.char_range_at_reverse(at).ch), cur) {
+        (WC_CR, WC_LF) => return false, // WB3
+        (WC_Newline, _) | (WC_CR, _) | (WC_LF, _) => return true, // WB3a
+        (_, WC_Newline) | (_, WC_CR) | (_, WC_LF) => return true, // WB3b
+        (_, WC_Extend) | (_, WC_Format) => return false, // WB4
+        _ => {}
+    }
+
+    // The rest of the rules act as if Extend and Format characters
+    // were not there (WB4).
+    let (prev, previ) = match word_cat_before(input, at) {
+        None => return true,
+        Some((cat, i)) => (Some(cat), i),
+    };
+    let prev2 = word_cat_before(input, previ).map(|(cat, _)| cat);
+    let cur = Some(cur);
+    let next = word_cat_after(input, r.next);
+
+    if is_ahl(prev) && is_ahl(cur) {
+        return false // WB5
+    }
+    if is_ahl(prev) && is_mid_letter(cur) && is_ahl(next) {
+        return false // WB6
+    }
+    if is_ahl(prev2) && is_mid_letter(prev) && is_ahl(cur) {
+        return false // WB7
+    }
+    if prev == Some(WC_HebrewLetter) && cur == Some(WC_SingleQuote) {
+        return false // WB7a
+    }
+    if prev == Some(WC_HebrewLetter) && cur == Some(WC_DoubleQuote)
+       && next == Some(WC_HebrewLetter) {
+        return false // WB7b
+    }
+    if prev2 == Some(WC_HebrewLetter) && prev == Some(WC_DoubleQuote)
+       && cur == Some(WC_HebrewLetter) {
+        return false // WB7c
+    }
+    if (prev == Some(WC_Numeric) || is_ahl(prev)) && cur == Some(WC_Numeric) {
+        return false // WB8, WB9
+    }
+    if prev == Some(WC_Numeric) && is_ahl(cur) {
+        return false // WB10
+    }
+    if prev2 == Some(WC_Numeric) && is_mid_num(prev)
+       && cur == Some(WC_Numeric) {
+        return false // WB11
+    }
+    if prev == Some(WC_Numeric) && is_mid_num(cur)
+       && next == Some(WC_Numeric) {
+        return false // WB12
+    }
+    if prev == Some(WC_Katakana) && cur == Some(WC_Katakana) {
+        return false // WB13
+    }
+    if cur == Some(WC_ExtendNumLet)
+       && (is_ahl(prev) || prev == Some(WC_Numeric)
+           || prev == Some(WC_Katakana) || prev == Some(WC_ExtendNumLet)) {
+        return false // WB13a
+    }
+    if prev == Some(WC_ExtendNumLet)
+       && (is_ahl(cur) || cur == Some(WC_Numeric)
+           || cur == Some(WC_Katakana)) {
+        return false // WB13b
+    }
+    if prev == Some(WC_RegionalIndicator)
+       && cur == Some(WC_RegionalIndicator) {
+        return false // WB13c
+    }
+    true // WB14
+}
+
+// Returns the word break category of the character that ends at `at`,
+// skipping over Extend and Format characters, along with the index at which
+// that character starts.
+fn word_cat_before(input: &str, mut at: uint) -> Option<(WordCat, uint)> {
+    while at > 0 {
+        let r = input.char_range_at_reverse(at);
+        match word_category(r.ch) {
+            WC_Extend | WC_Format => at = r.next,
+            cat => return Some((cat, r.next)),
+        }
+    }
+    None
+}
+
+// Returns the word break category of the character that starts at `at`,
+// skipping over Extend and Format characters.
+fn word_cat_after(input: &str, mut at: uint) -> Option<WordCat> {
+    while at < input.len() {
+        let r = input.char_range_at(at);
+        match word_category(r.ch) {
+            WC_Extend | WC_Format => at = r.next,
+            cat => return Some(cat),
+        }
+    }
+    None
+}
+
+// (ALetter | Hebrew_Letter)
+fn is_ahl(cat: Option<WordCat>) -> bool {
+    cat == Some(WC_ALetter) || cat == Some(WC_HebrewLetter)
+}
+
+// (MidLetter | MidNumLet | Single_Quote)
+fn is_mid_letter(cat: Option<WordCat>) -> bool {
+    cat == Some(WC_MidLetter) || cat == Some(WC_MidNumLet)
+    || cat == Some(WC_SingleQuote)
+}
+
+// (MidNum | MidNumLet | Single_Quote)
+fn is_mid_num(cat: Option<WordCat>) -> bool {
+    cat == Some(WC_MidNum) || cat == Some(WC_MidNumLet)
+    || cat == Some(WC_SingleQuote)
+}
+
 /// Given a character and a single character class range, return an ordering
 /// indicating whether the character is less than the start of the range,
 /// in the range (inclusive) or greater than the end of the range.
@@ -554,30 +1208,52 @@ fn class_cmp(casei: bool, mut textc: char,
     }
 }
 
-/// Returns the starting location of `needle` in `haystack`.
-/// If `needle` is not in `haystack`, then `None` is returned.
+/// Returns the byte index of the first position at or after `at` in `input`
+/// where one of the (non-empty) strings in `lits` occurs.
 ///
-/// Note that this is using a naive substring algorithm.
-#[inline(always)]
-pub fn find_prefix(needle: &[u8], haystack: &[u8]) -> Option<uint> {
-    let (hlen, nlen) = (haystack.len(), needle.len());
-    if nlen > hlen || nlen == 0 {
+/// If there is only one string, then `searcher` should be a searcher built
+/// for it.
+pub fn find_any<S: Str>(lits: &[S], searcher: &Option<Searcher>,
+                        input: &str, at: uint) -> Option<uint> {
+    if at > input.len() {
         return None
     }
-    let mut hayi = 0u;
-    'HAYSTACK: loop {
-        if hayi > hlen - nlen {
-            break
+    let haystack = input.as_bytes().slice_from(at);
+    if lits.len() == 1 {
+        let needle = lits[0].as_slice().as_bytes();
+        let found = match *searcher {
+            Some(ref searcher) => searcher.find(needle, haystack),
+            None => Searcher::new(needle).find(needle, haystack),
+        };
+        return found.map(|i| at + i)
+    }
+
+    // Only look for the strings at the positions where one of them could
+    // start.
+    let mut first = [false, ..256];
+    for lit in lits.iter() {
+        first[lit.as_slice()[0] as uint] = true;
+    }
+    for i in iter::range(0, haystack.len()) {
+        if !first[haystack[i] as uint] {
+            continue
         }
-        let mut nedi = 0;
-        while nedi < nlen {
-            if haystack[hayi+nedi] != needle[nedi] {
-                hayi += 1;
-                continue 'HAYSTACK
-            }
-            nedi += 1;
+        let rest = haystack.slice_from(i);
+        if lits.iter().any(|lit| rest.starts_with(lit.as_slice().as_bytes())) {
+            return Some(at + i)
         }
-        return Some(hayi)
     }
     None
 }
+
+/// Returns false if there can't be a match in `input` at or after `at`,
+/// either because `required` isn't empty and doesn't occur there, or because
+/// there are `suffixes` and none of them occur there.
+pub fn may_match<S: Str>(suffixes: &[S], suffix_searcher: &Option<Searcher>,
+                         required: &str, required_searcher: &Option<Searcher>,
+                         input: &str, at: uint) -> bool {
+    (required.len() == 0
+     || find_any([required], required_searcher, input, at).is_some())
+    && (suffixes.len() == 0
+        || find_any(suffixes, suffix_searcher, input, at).is_some())
+}